@@ -0,0 +1,435 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! ASID and VMID allocation
+//!
+//! [`AsidAllocator`] and [`VmidAllocator`] hand out the IDs that tag TLB entries with the address
+//! space or virtual machine they belong to. Every address space or VM keeps an [`Id`], and asks the
+//! allocator for the value to install when it is switched in on a core:
+//!
+//! ```ignore
+//! use cortex_a::{asid::{AsidAllocator, Id}, cpuid::CpuFeatures, regs::*};
+//!
+//! let mut asids = AsidAllocator::new(&CpuFeatures::detect());
+//! TCR_EL1.modify(asids.tcr_as());
+//!
+//! // Switching to an address space, with the allocator locked:
+//! TTBR0_EL1.set_sync(asids.ttbr(core, &mut space.asid, space.table_addr));
+//! ```
+//!
+//! The allocators are generational: IDs are handed out until the ID space is exhausted, which
+//! starts a new generation. On this rollover, the TLBs of all cores are invalidated and all IDs
+//! become free again, except for the IDs currently installed on a core, which stay reserved for
+//! their owners. An [`Id`] from an older generation is replaced by a fresh one the next time it is
+//! activated, so IDs never need to be freed.
+//!
+//! One allocator is shared by all cores that share TLB entries, indexed by a dense core index, e.g.
+//! from `topology::ClusterLayout`. The allocators are not synchronized; wrap them in a lock and
+//! keep it held until the returned value has been installed. ID 0 is never allocated, so it can be
+//! used for the kernel or the host.
+
+use crate::{cpuid::CpuFeatures, regs::*, tlb};
+use register::FieldValue;
+
+/// Maximum number of cores sharing an allocator.
+///
+/// Kept below the 255 IDs of an 8 bit ID space, so that a rollover always leaves free IDs.
+pub const MAX_CORES: usize = 128;
+
+/// The widest architected ID, used to size the allocation bitmap.
+const MAX_BITS: u32 = 16;
+
+/// An ASID or VMID, tagged with the allocator generation it belongs to.
+///
+/// `Id::default()` was never allocated, and gets a fresh ID on its first activation.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Id(u64);
+
+impl Id {
+    fn new(generation: u64, value: usize) -> Id {
+        Id(generation << MAX_BITS | value as u64)
+    }
+
+    /// Returns the value to program into the `ASID` or `VMID` field.
+    pub fn value(&self) -> u16 {
+        self.0 as u16
+    }
+
+    fn generation(&self) -> u64 {
+        self.0 >> MAX_BITS
+    }
+}
+
+/// The generational allocator behind [`AsidAllocator`] and [`VmidAllocator`].
+struct Allocator {
+    bits: u32,
+    generation: u64,
+    next: usize,
+    used: [u64; (1 << MAX_BITS) / 64],
+
+    /// The ID installed on each core.
+    active: [Id; MAX_CORES],
+
+    /// The IDs that were installed on a core at the last rollover, and are kept by their owners.
+    reserved: [Id; MAX_CORES],
+}
+
+impl Allocator {
+    const fn new(bits: u32) -> Allocator {
+        assert!(bits == 8 || bits == 16, "IDs are 8 or 16 bits wide");
+
+        // ID 0 is never allocated.
+        let mut used = [0; (1 << MAX_BITS) / 64];
+        used[0] = 1;
+
+        Allocator {
+            bits,
+            generation: 1,
+            next: 1,
+            used,
+            active: [Id(0); MAX_CORES],
+            reserved: [Id(0); MAX_CORES],
+        }
+    }
+
+    fn is_used(&self, value: usize) -> bool {
+        self.used[value / 64] & (1 << (value % 64)) != 0
+    }
+
+    fn mark(&mut self, value: usize) {
+        self.used[value / 64] |= 1 << (value % 64);
+    }
+
+    /// Returns the next unused ID of the current generation, if any.
+    fn find_free(&mut self) -> Option<usize> {
+        let count = 1 << self.bits;
+        let value = (self.next..count).find(|&value| !self.is_used(value))?;
+        self.next = value + 1;
+
+        Some(value)
+    }
+
+    /// Starts a new generation, in which only the IDs installed on a core stay allocated, and
+    /// invalidates the TLB entries of all released IDs with `flush`.
+    fn rollover(&mut self, flush: fn()) {
+        self.generation += 1;
+        self.next = 1;
+        self.used = [0; (1 << MAX_BITS) / 64];
+        self.mark(0);
+
+        self.reserved = self.active;
+        for i in 0..MAX_CORES {
+            let reserved = self.reserved[i];
+            if reserved != Id::default() {
+                self.mark(reserved.value() as usize);
+            }
+        }
+
+        flush();
+    }
+
+    fn activate(&mut self, core: usize, id: &mut Id, flush: fn()) -> u16 {
+        assert!(core < MAX_CORES, "core index out of range");
+
+        if *id == Id::default() || id.generation() != self.generation {
+            *id = self.new_id(*id, flush);
+        }
+        self.active[core] = *id;
+
+        id.value()
+    }
+
+    /// Returns an ID of the current generation for the owner of `old`.
+    fn new_id(&mut self, old: Id, flush: fn()) -> Id {
+        if old != Id::default() {
+            let value = old.value() as usize;
+            let new = Id::new(self.generation, value);
+
+            // The ID was installed on a core at the rollover and is still reserved for its owner.
+            if self.reserved.contains(&old) {
+                for reserved in self.reserved.iter_mut() {
+                    if *reserved == old {
+                        *reserved = new;
+                    }
+                }
+                return new;
+            }
+
+            // The ID has not been handed out again in this generation.
+            if !self.is_used(value) {
+                self.mark(value);
+                return new;
+            }
+        }
+
+        let value = match self.find_free() {
+            Some(value) => value,
+            None => {
+                self.rollover(flush);
+                self.find_free().expect("all IDs are installed on a core")
+            }
+        };
+        self.mark(value);
+
+        Id::new(self.generation, value)
+    }
+}
+
+/// Allocates the ASIDs of the EL1&0 translation regime.
+pub struct AsidAllocator(Allocator);
+
+impl AsidAllocator {
+    /// Creates an allocator for the ASID width supported by the executing core.
+    ///
+    /// The allocator is about 10KiB large and is built on the stack. To place it in a static, use
+    /// [`with_bits`](AsidAllocator::with_bits) in the initializer.
+    pub fn new(features: &CpuFeatures) -> AsidAllocator {
+        AsidAllocator::with_bits(features.asid_bits())
+    }
+
+    /// Creates an allocator for `bits` wide ASIDs, 8 or 16.
+    pub const fn with_bits(bits: u32) -> AsidAllocator {
+        AsidAllocator(Allocator::new(bits))
+    }
+
+    /// Returns the ASID width.
+    pub fn bits(&self) -> u32 {
+        self.0.bits
+    }
+
+    /// Returns the `TCR_EL1.AS` setting for the ASID width.
+    pub fn tcr_as(&self) -> FieldValue<u64, TCR_EL1::Register> {
+        if self.0.bits == 16 {
+            TCR_EL1::AS::ASID16Bits
+        } else {
+            TCR_EL1::AS::ASID8Bits
+        }
+    }
+
+    /// Returns the ASID of the address space owning `id` for installing it on `core`, allocating a
+    /// new ASID if needed.
+    ///
+    /// On a rollover, all stage 1 EL1&0 TLB entries of the current VMID are invalidated with
+    /// `TLBI VMALLE1IS`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `core` is not below [`MAX_CORES`].
+    pub fn activate(&mut self, core: usize, id: &mut Id) -> u16 {
+        self.0.activate(core, id, tlb::vmalle1is)
+    }
+
+    /// Activates `id` on `core` and returns the `TTBR0_EL1` or `TTBR1_EL1` value for the
+    /// translation table at `table_addr`. Install it in the register selected by `TCR_EL1.A1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `core` is not below [`MAX_CORES`].
+    pub fn ttbr(&mut self, core: usize, id: &mut Id, table_addr: u64) -> u64 {
+        let asid = self.activate(core, id);
+
+        (TTBR0_EL1::ASID.val(asid as u64) + TTBR0_EL1::BADDR.val(table_addr >> 1)).value
+    }
+}
+
+/// Allocates the VMIDs of the stage 2 translation.
+pub struct VmidAllocator(Allocator);
+
+impl VmidAllocator {
+    /// Creates an allocator for the VMID width supported by the executing core.
+    ///
+    /// The allocator is about 10KiB large and is built on the stack. To place it in a static, use
+    /// [`with_bits`](VmidAllocator::with_bits) in the initializer.
+    pub fn new(features: &CpuFeatures) -> VmidAllocator {
+        VmidAllocator::with_bits(features.vmid_bits())
+    }
+
+    /// Creates an allocator for `bits` wide VMIDs, 8 or 16.
+    pub const fn with_bits(bits: u32) -> VmidAllocator {
+        VmidAllocator(Allocator::new(bits))
+    }
+
+    /// Returns the VMID width.
+    pub fn bits(&self) -> u32 {
+        self.0.bits
+    }
+
+    /// Returns the `VTCR_EL2.VS` setting for the VMID width.
+    pub fn vtcr_vs(&self) -> FieldValue<u32, VTCR_EL2::Register> {
+        if self.0.bits == 16 {
+            VTCR_EL2::VS::VMID16
+        } else {
+            VTCR_EL2::VS::VMID8
+        }
+    }
+
+    /// Returns the VMID of the VM owning `id` for installing it on `core`, allocating a new VMID if
+    /// needed.
+    ///
+    /// On a rollover, all EL1&0 TLB entries of all VMIDs are invalidated with `TLBI ALLE1IS`. Must
+    /// be called at EL2.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `core` is not below [`MAX_CORES`].
+    pub fn activate(&mut self, core: usize, id: &mut Id) -> u16 {
+        self.0.activate(core, id, tlb::alle1is)
+    }
+
+    /// Activates `id` on `core` and returns the `VTTBR_EL2` value for the stage 2 translation
+    /// table at `table_addr`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `core` is not below [`MAX_CORES`].
+    pub fn vttbr(&mut self, core: usize, id: &mut Id, table_addr: u64) -> u64 {
+        let vmid = self.activate(core, id);
+
+        (VTTBR_EL2::VMID.val(vmid as u64) + VTTBR_EL2::BADDR.val(table_addr >> 1)).value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::{cell::Cell, thread_local};
+
+    thread_local! {
+        static FLUSHES: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn flush() {
+        FLUSHES.with(|flushes| flushes.set(flushes.get() + 1));
+    }
+
+    fn flushes() -> usize {
+        FLUSHES.with(Cell::get)
+    }
+
+    /// Activates a new address space on `core`.
+    fn fresh(allocator: &mut Allocator, core: usize) -> Id {
+        let mut id = Id::default();
+        allocator.activate(core, &mut id, flush);
+
+        id
+    }
+
+    /// Allocates the remaining IDs of the current generation on `core`, without a rollover.
+    fn exhaust(allocator: &mut Allocator, core: usize) {
+        let start = flushes();
+        while allocator.find_free_peek().is_some() {
+            fresh(allocator, core);
+        }
+        assert_eq!(flushes(), start);
+    }
+
+    impl Allocator {
+        fn find_free_peek(&self) -> Option<usize> {
+            (self.next..1 << self.bits).find(|&value| !self.is_used(value))
+        }
+    }
+
+    #[test]
+    fn sequential() {
+        let mut allocator = Allocator::new(8);
+
+        for value in 1..=255 {
+            assert_eq!(fresh(&mut allocator, 0).value(), value);
+        }
+        assert_eq!(flushes(), 0);
+
+        // ID 0 is not handed out after a rollover either.
+        assert_eq!(fresh(&mut allocator, 0).value(), 1);
+        assert_eq!(flushes(), 1);
+    }
+
+    #[test]
+    fn rollover() {
+        let mut allocator = Allocator::new(8);
+        let mut old = fresh(&mut allocator, 0);
+        exhaust(&mut allocator, 0);
+
+        let new = fresh(&mut allocator, 0);
+        assert_eq!(flushes(), 1);
+        assert_ne!(new.generation(), old.generation());
+
+        // The value of `old` was handed out again, so it gets another one.
+        assert_eq!(old.value(), new.value());
+        assert_eq!(allocator.activate(1, &mut old, flush), 2);
+        assert_eq!(old.generation(), new.generation());
+        assert_eq!(flushes(), 1);
+    }
+
+    #[test]
+    fn reserved() {
+        let mut allocator = Allocator::new(8);
+        let mut a = fresh(&mut allocator, 0);
+        let mut b = fresh(&mut allocator, 1);
+        exhaust(&mut allocator, 2);
+        let values = (a.value(), b.value());
+
+        // None of the IDs installed on a core at the rollover is handed out again.
+        let mut fresh_values = 0;
+        while flushes() < 2 {
+            let id = fresh(&mut allocator, 2);
+            if flushes() == 1 {
+                assert_ne!(id.value(), values.0);
+                assert_ne!(id.value(), values.1);
+                fresh_values += 1;
+            }
+        }
+        // The last ID installed on core 2 stayed reserved as well.
+        assert_eq!(fresh_values, 255 - 3);
+
+        // Their owners keep them, also after a further rollover.
+        assert_eq!(allocator.activate(0, &mut a, flush), values.0);
+        assert_eq!(allocator.activate(1, &mut b, flush), values.1);
+        assert_eq!(a.generation(), allocator.generation);
+        assert_eq!(b.generation(), allocator.generation);
+    }
+
+    #[test]
+    fn reuse() {
+        let mut allocator = Allocator::new(8);
+        let mut ids = [Id::default(); 255];
+        for id in ids.iter_mut() {
+            allocator.activate(0, id, flush);
+        }
+
+        // The rollover reserves the last ID for core 0, the next ones take the lowest values.
+        let new = [fresh(&mut allocator, 1), fresh(&mut allocator, 1)];
+        assert_eq!(flushes(), 1);
+        assert_eq!([new[0].value(), new[1].value()], [1, 2]);
+
+        // An old ID whose value is still free keeps it.
+        let mut old = ids[99];
+        assert_eq!(allocator.activate(1, &mut old, flush), 100);
+        assert_eq!(old.generation(), allocator.generation);
+
+        // An old ID whose value was handed out again gets the next free one.
+        let mut old = ids[0];
+        assert_eq!(allocator.activate(1, &mut old, flush), 3);
+        assert_eq!(flushes(), 1);
+    }
+
+    #[test]
+    fn const_new() {
+        static ASIDS: AsidAllocator = AsidAllocator::with_bits(16);
+
+        assert_eq!(ASIDS.bits(), 16);
+        assert!(ASIDS.0.is_used(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "core index out of range")]
+    fn core_out_of_range() {
+        let mut allocator = Allocator::new(8);
+        fresh(&mut allocator, MAX_CORES);
+    }
+}
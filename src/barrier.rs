@@ -44,9 +44,11 @@ macro_rules! dmb_dsb {
 pub struct SY;
 pub struct ISH;
 pub struct ISHST;
+pub struct NSH;
 
 dmb_dsb!(ISH);
 dmb_dsb!(ISHST);
+dmb_dsb!(NSH);
 dmb_dsb!(SY);
 
 impl sealed::Isb for SY {
@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Branch Target Identification
+//!
+//! Indirect branches into guarded pages must land on a BTI instruction, or a compatible PACIxSP,
+//! or a Branch Target exception is taken. Pages are guarded by their stage 1 descriptor:
+//!
+//! ```ignore
+//! use cortex_a::bti;
+//!
+//! // When mapping kernel text compiled with BTI landing pads:
+//! let descriptor = bti::guard(descriptor);
+//!
+//! // In the synchronous exception handler:
+//! if let Some(branch) = bti::syndrome(esr) {
+//!     panic!("{:?} to {:#x} hit no landing pad", branch, elr);
+//! }
+//! ```
+//!
+//! Exception handlers that change `ELR_ELx` to skip or emulate an instruction must clear the saved
+//! `BTYPE` with [`clear_btype`].
+
+use crate::{
+    cpuid::CpuFeatures,
+    iss::branch_target::{BranchTargetIss, ISS},
+    regs::*,
+};
+use register::{FieldValue, LocalRegisterCopy};
+
+/// The Guarded Page bit of stage 1 block and page descriptors.
+pub const GUARDED_PAGE: u64 = 1 << 50;
+
+/// Returns the stage 1 block or page descriptor `descriptor` with the Guarded Page bit set.
+#[inline]
+pub fn guard(descriptor: u64) -> u64 {
+    descriptor | GUARDED_PAGE
+}
+
+/// Returns whether the stage 1 block or page descriptor `descriptor` maps a guarded page.
+#[inline]
+pub fn is_guarded(descriptor: u64) -> bool {
+    descriptor & GUARDED_PAGE != 0
+}
+
+/// The kind of indirect branch that caused a Branch Target exception.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BranchType {
+    /// A jump (BR) through X16 or X17, or from a non-guarded page.
+    JumpX16X17,
+
+    /// A call (BLR).
+    Call,
+
+    /// A jump (BR) through any other register from a guarded page.
+    Jump,
+}
+
+/// Decodes `esr`, the raw value of `ESR_EL1` or `ESR_EL2`. Returns `None` if the exception is not a
+/// Branch Target exception.
+pub fn syndrome(esr: u32) -> Option<BranchType> {
+    let esr: LocalRegisterCopy<u32, ESR_EL1::Register> = LocalRegisterCopy::new(esr);
+
+    match esr.read_as_enum(ESR_EL1::EC) {
+        Some(ESR_EL1::EC::Value::BranchTarget) => (),
+        _ => return None,
+    }
+
+    let iss = BranchTargetIss::new(esr.read(ESR_EL1::ISS));
+
+    match iss.read_as_enum(ISS::BTYPE) {
+        Some(ISS::BTYPE::Value::JumpX16X17) => Some(BranchType::JumpX16X17),
+        Some(ISS::BTYPE::Value::Call) => Some(BranchType::Call),
+        Some(ISS::BTYPE::Value::Jump) => Some(BranchType::Jump),
+        None => None,
+    }
+}
+
+/// Clears the saved `PSTATE.BTYPE` in `spsr`, the raw value of `SPSR_EL1` or `SPSR_EL2`, so that
+/// the instruction returned to is not checked as a branch target.
+#[inline]
+pub fn clear_btype(spsr: &mut u64) {
+    *spsr &= !(SPSR_EL1::BTYPE::SET.value as u64);
+}
+
+/// Access token for Branch Target Identification on the executing core.
+pub struct Bti;
+
+impl Bti {
+    /// Returns the token if Branch Target Identification is implemented, `None` otherwise.
+    pub fn probe(features: &CpuFeatures) -> Option<Bti> {
+        if !features.has_bti() {
+            return None;
+        }
+
+        Some(Bti)
+    }
+
+    /// Configures the compatibility of PACIASP and PACIBSP with jumps through any register, as
+    /// selected by `fields`, a combination of the `BT0` and `BT1` fields of `SCTLR_EL1`.
+    ///
+    /// Setting `BT1` requires a BTI landing pad in front of PACIxSP for such jumps at EL1.
+    #[inline(always)]
+    pub fn configure(&self, fields: FieldValue<u64, SCTLR_EL1::Register>) {
+        SCTLR_EL1.modify_sync(fields);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn esr(ec: u32, iss: u32) -> u32 {
+        ec << 26 | 1 << 25 | iss
+    }
+
+    const BRANCH_TARGET: u32 = 0b00_1101;
+
+    #[test]
+    fn syndromes() {
+        let cases = [
+            (0b00, None),
+            (0b01, Some(BranchType::JumpX16X17)),
+            (0b10, Some(BranchType::Call)),
+            (0b11, Some(BranchType::Jump)),
+        ];
+
+        for &(btype, expected) in &cases {
+            assert_eq!(
+                syndrome(esr(BRANCH_TARGET, btype)),
+                expected,
+                "{:#b}",
+                btype
+            );
+        }
+    }
+
+    #[test]
+    fn other_exceptions() {
+        // Unknown, MRRC, Illegal Execution state, SVC and BRK.
+        for &ec in &[0b00_0000, 0b00_1100, 0b00_1110, 0b01_0101, 0b11_1100] {
+            for btype in 0..4 {
+                assert_eq!(syndrome(esr(ec, btype)), None, "{:#b}", ec);
+            }
+        }
+    }
+
+    #[test]
+    fn guard() {
+        let descriptor = 0x0060_0000_4000_0703;
+
+        assert!(!is_guarded(descriptor));
+        assert_eq!(super::guard(descriptor), descriptor | 1 << 50);
+        assert!(is_guarded(super::guard(descriptor)));
+        assert_eq!(
+            super::guard(super::guard(descriptor)),
+            super::guard(descriptor)
+        );
+    }
+
+    #[test]
+    fn clear_btype() {
+        for btype in 0..4 {
+            let other = 0x3C5 | 1 << 21;
+            let mut spsr = other | btype << 10;
+
+            super::clear_btype(&mut spsr);
+            assert_eq!(spsr, other, "{:#b}", btype);
+        }
+    }
+}
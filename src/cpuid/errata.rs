@@ -0,0 +1,218 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Known processor errata
+//!
+//! Descriptions are taken from the respective Arm Software Developer Errata Notices. The registry
+//! only lists errata that are relevant to system software, i.e. those that have a software
+//! workaround.
+
+use super::{CpuModel, Revision};
+
+/// An inclusive range of product revisions.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RevisionRange {
+    pub first: Revision,
+    pub last: Revision,
+}
+
+impl RevisionRange {
+    /// Every revision of a product.
+    pub const ALL: RevisionRange = RevisionRange::new(Revision::new(0, 0), Revision::new(15, 15));
+
+    pub const fn new(first: Revision, last: Revision) -> RevisionRange {
+        RevisionRange { first, last }
+    }
+
+    /// Returns whether `revision` lies within the range.
+    pub fn contains(&self, revision: Revision) -> bool {
+        self.first <= revision && revision <= self.last
+    }
+}
+
+/// A processor erratum.
+#[derive(Copy, Clone, Debug)]
+pub struct Erratum {
+    /// The Arm erratum number.
+    pub id: u32,
+
+    /// The affected processor model.
+    pub model: CpuModel,
+
+    /// The affected revisions of `model`.
+    pub revisions: RevisionRange,
+
+    /// Short description from the errata notice.
+    pub description: &'static str,
+}
+
+const fn erratum(
+    id: u32,
+    model: CpuModel,
+    first: (u8, u8),
+    last: (u8, u8),
+    description: &'static str,
+) -> Erratum {
+    Erratum {
+        id,
+        model,
+        revisions: RevisionRange::new(
+            Revision::new(first.0, first.1),
+            Revision::new(last.0, last.1),
+        ),
+        description,
+    }
+}
+
+/// All errata known to this crate.
+///
+/// An erratum that affects several models has one entry per model.
+#[rustfmt::skip]
+pub static KNOWN_ERRATA: &[Erratum] = &[
+    erratum(819472, CpuModel::CortexA53, (0, 0), (0, 1),
+        "Store exclusive instructions might cause data corruption"),
+    erratum(824069, CpuModel::CortexA53, (0, 0), (0, 2),
+        "Cache line might not be marked as clean after a CleanShared snoop"),
+    erratum(826319, CpuModel::CortexA53, (0, 0), (0, 2),
+        "System might deadlock if a write cannot complete until read data is accepted"),
+    erratum(827319, CpuModel::CortexA53, (0, 0), (0, 2),
+        "Data cache clean instructions might cause overlapping transactions to the interconnect"),
+    erratum(835769, CpuModel::CortexA53, (0, 0), (0, 4),
+        "AArch64 multiply-accumulate instruction might produce incorrect result"),
+    erratum(843419, CpuModel::CortexA53, (0, 0), (0, 4),
+        "A load or store might access an incorrect address"),
+    erratum(845719, CpuModel::CortexA53, (0, 0), (0, 4),
+        "A load might read incorrect data"),
+    erratum(1024718, CpuModel::CortexA55, (0, 0), (2, 0),
+        "Update of DBM/AP bits without break before make might result in incorrect update"),
+    erratum(1530923, CpuModel::CortexA55, (0, 0), (15, 15),
+        "Speculative AT instruction using out-of-context translation regime could cause \
+         subsequent request to generate an incorrect translation"),
+    erratum(832075, CpuModel::CortexA57, (0, 0), (1, 2),
+        "Possible deadlock on mixing exclusive memory accesses with device loads"),
+    erratum(834220, CpuModel::CortexA57, (0, 0), (1, 2),
+        "Stage 2 translation fault might be incorrectly reported in presence of a Stage 1 fault"),
+    erratum(1319537, CpuModel::CortexA57, (0, 0), (15, 15),
+        "Speculative AT instruction using out-of-context translation regime could cause \
+         subsequent request to generate an incorrect translation"),
+    erratum(1319367, CpuModel::CortexA72, (0, 0), (15, 15),
+        "Speculative AT instruction using out-of-context translation regime could cause \
+         subsequent request to generate an incorrect translation"),
+    erratum(858921, CpuModel::CortexA73, (0, 0), (0, 2),
+        "Reading the counter could return an incorrect value"),
+    erratum(1165522, CpuModel::CortexA76, (0, 0), (2, 0),
+        "Speculative AT instruction using out-of-context translation regime could cause \
+         subsequent request to generate an incorrect translation"),
+    erratum(1188873, CpuModel::CortexA76, (0, 0), (2, 0),
+        "MRC read following MRRC read of specific Generic Timer in AArch32 might give incorrect \
+         result"),
+    erratum(1286807, CpuModel::CortexA76, (0, 0), (3, 0),
+        "Modification of the translation table for a virtual address might lead to \
+         read-after-read ordering violation"),
+    erratum(1463225, CpuModel::CortexA76, (0, 0), (3, 1),
+        "Software Step might prevent interrupt recognition"),
+    erratum(1188873, CpuModel::NeoverseN1, (0, 0), (2, 0),
+        "MRC read following MRRC read of specific Generic Timer in AArch32 might give incorrect \
+         result"),
+    erratum(1542419, CpuModel::NeoverseN1, (3, 0), (4, 0),
+        "Mis-ordering of speculative instruction fetches after modification of code"),
+];
+
+/// Returns the known errata that affect the given model and revision.
+pub fn affecting(model: CpuModel, revision: Revision) -> impl Iterator<Item = &'static Erratum> {
+    KNOWN_ERRATA
+        .iter()
+        .filter(move |e| e.model == model && e.revisions.contains(revision))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::vec::Vec;
+
+    fn ids(model: CpuModel, variant: u8, revision: u8) -> Vec<u32> {
+        affecting(model, Revision::new(variant, revision))
+            .map(|e| e.id)
+            .collect()
+    }
+
+    #[test]
+    fn range_contains() {
+        let range = RevisionRange::new(Revision::new(1, 2), Revision::new(2, 0));
+        let cases = [
+            (Revision::new(0, 15), false),
+            (Revision::new(1, 1), false),
+            (Revision::new(1, 2), true),
+            (Revision::new(1, 15), true),
+            (Revision::new(2, 0), true),
+            (Revision::new(2, 1), false),
+            (Revision::new(3, 0), false),
+        ];
+
+        for &(revision, expected) in &cases {
+            assert_eq!(range.contains(revision), expected, "{}", revision);
+        }
+
+        assert!(RevisionRange::ALL.contains(Revision::new(0, 0)));
+        assert!(RevisionRange::ALL.contains(Revision::new(15, 15)));
+    }
+
+    #[test]
+    fn affecting_boundaries() {
+        let cases: [(CpuModel, u8, u8, &[u32]); 8] = [
+            // 819472 ends at r0p1, 824069, 826319 and 827319 at r0p2, the others at r0p4.
+            (
+                CpuModel::CortexA53,
+                0,
+                1,
+                &[819472, 824069, 826319, 827319, 835769, 843419, 845719],
+            ),
+            (
+                CpuModel::CortexA53,
+                0,
+                2,
+                &[824069, 826319, 827319, 835769, 843419, 845719],
+            ),
+            (CpuModel::CortexA53, 0, 4, &[835769, 843419, 845719]),
+            (CpuModel::CortexA53, 0, 5, &[]),
+            // 1542419 starts at r3p0.
+            (CpuModel::NeoverseN1, 2, 0, &[1188873]),
+            (CpuModel::NeoverseN1, 2, 1, &[]),
+            (CpuModel::NeoverseN1, 3, 0, &[1542419]),
+            (CpuModel::NeoverseN1, 4, 1, &[]),
+        ];
+
+        for &(model, variant, revision, expected) in &cases {
+            assert_eq!(
+                ids(model, variant, revision),
+                expected,
+                "{:?} r{}p{}",
+                model,
+                variant,
+                revision
+            );
+        }
+    }
+
+    #[test]
+    fn affecting_model() {
+        // Same erratum number on another model.
+        assert_eq!(
+            ids(CpuModel::CortexA76, 1, 0),
+            [1165522, 1188873, 1286807, 1463225]
+        );
+        assert_eq!(ids(CpuModel::CortexA35, 0, 0), []);
+
+        let unknown = CpuModel::Unknown {
+            implementer: 0x41,
+            part_num: 0xD03,
+        };
+        assert_eq!(ids(unknown, 0, 0), []);
+    }
+}
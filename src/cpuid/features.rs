@@ -0,0 +1,559 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Processor features
+//!
+//! [`CpuFeatures`] is a snapshot of the AArch64 ID registers. Read it once during boot and keep it
+//! around to select code paths at runtime:
+//!
+//! ```ignore
+//! use cortex_a::cpuid::CpuFeatures;
+//!
+//! let features = CpuFeatures::detect();
+//!
+//! if features.has_lse() {
+//!     // Use the LSE atomics.
+//! }
+//! ```
+
+use crate::regs::*;
+use register::{Field, LocalRegisterCopy, RegisterLongName};
+
+/// Raw values of the AArch64 ID registers.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CpuFeatures {
+    pub pfr0: u64,
+    pub pfr1: u64,
+    pub isar0: u64,
+    pub isar1: u64,
+    pub isar2: u64,
+    pub mmfr0: u64,
+    pub mmfr1: u64,
+    pub mmfr2: u64,
+    pub dfr0: u64,
+    pub zfr0: u64,
+}
+
+/// Reads `field` from the raw register value `value`.
+#[inline]
+fn field<R: RegisterLongName>(value: u64, field: Field<u64, R>) -> u64 {
+    LocalRegisterCopy::<u64, R>::new(value).read(field)
+}
+
+impl CpuFeatures {
+    /// Reads the ID registers of the executing core.
+    ///
+    /// Reserved ID registers of the feature ID space read as zero, so this is safe on every
+    /// ARMv8-A implementation.
+    pub fn detect() -> CpuFeatures {
+        CpuFeatures {
+            pfr0: ID_AA64PFR0_EL1.get(),
+            pfr1: ID_AA64PFR1_EL1.get(),
+            isar0: ID_AA64ISAR0_EL1.get(),
+            isar1: ID_AA64ISAR1_EL1.get(),
+            isar2: ID_AA64ISAR2_EL1.get(),
+            mmfr0: ID_AA64MMFR0_EL1.get(),
+            mmfr1: ID_AA64MMFR1_EL1.get(),
+            mmfr2: ID_AA64MMFR2_EL1.get(),
+            dfr0: ID_AA64DFR0_EL1.get(),
+            zfr0: ID_AA64ZFR0_EL1.get(),
+        }
+    }
+
+    //--------------------------------------------------------------------------------------------
+    // Exception levels
+    //--------------------------------------------------------------------------------------------
+
+    /// EL2 is implemented.
+    pub fn has_el2(&self) -> bool {
+        field(self.pfr0, ID_AA64PFR0_EL1::EL2) != 0
+    }
+
+    /// EL3 is implemented.
+    pub fn has_el3(&self) -> bool {
+        field(self.pfr0, ID_AA64PFR0_EL1::EL3) != 0
+    }
+
+    /// EL0 can be executed in AArch32 state.
+    pub fn has_el0_aarch32(&self) -> bool {
+        field(self.pfr0, ID_AA64PFR0_EL1::EL0)
+            == ID_AA64PFR0_EL1::EL0::Value::AArch32AndAArch64 as u64
+    }
+
+    /// EL1 can be executed in AArch32 state.
+    pub fn has_el1_aarch32(&self) -> bool {
+        field(self.pfr0, ID_AA64PFR0_EL1::EL1)
+            == ID_AA64PFR0_EL1::EL1::Value::AArch32AndAArch64 as u64
+    }
+
+    /// EL2 can be executed in AArch32 state.
+    pub fn has_el2_aarch32(&self) -> bool {
+        field(self.pfr0, ID_AA64PFR0_EL1::EL2)
+            == ID_AA64PFR0_EL1::EL2::Value::AArch32AndAArch64 as u64
+    }
+
+    /// EL3 can be executed in AArch32 state.
+    pub fn has_el3_aarch32(&self) -> bool {
+        field(self.pfr0, ID_AA64PFR0_EL1::EL3)
+            == ID_AA64PFR0_EL1::EL3::Value::AArch32AndAArch64 as u64
+    }
+
+    /// Secure EL2 is implemented.
+    pub fn has_sel2(&self) -> bool {
+        field(self.pfr0, ID_AA64PFR0_EL1::SEL2) != 0
+    }
+
+    //--------------------------------------------------------------------------------------------
+    // Floating-point and SIMD
+    //--------------------------------------------------------------------------------------------
+
+    /// Floating-point is implemented.
+    pub fn has_fp(&self) -> bool {
+        field(self.pfr0, ID_AA64PFR0_EL1::FP) != ID_AA64PFR0_EL1::FP::Value::NotImplemented as u64
+    }
+
+    /// Half-precision floating-point arithmetic is implemented.
+    pub fn has_fp16(&self) -> bool {
+        field(self.pfr0, ID_AA64PFR0_EL1::FP)
+            == ID_AA64PFR0_EL1::FP::Value::ImplementedWithFP16 as u64
+    }
+
+    /// Advanced SIMD is implemented.
+    pub fn has_advsimd(&self) -> bool {
+        field(self.pfr0, ID_AA64PFR0_EL1::AdvSIMD)
+            != ID_AA64PFR0_EL1::AdvSIMD::Value::NotImplemented as u64
+    }
+
+    /// The Scalable Vector Extension is implemented.
+    pub fn has_sve(&self) -> bool {
+        field(self.pfr0, ID_AA64PFR0_EL1::SVE) != 0
+    }
+
+    /// SVE2 is implemented.
+    pub fn has_sve2(&self) -> bool {
+        self.has_sve() && field(self.zfr0, ID_AA64ZFR0_EL1::SVEver) >= 1
+    }
+
+    /// Dot Product instructions are implemented.
+    pub fn has_dotprod(&self) -> bool {
+        field(self.isar0, ID_AA64ISAR0_EL1::DP) != 0
+    }
+
+    /// SQRDMLAH and SQRDMLSH instructions are implemented.
+    pub fn has_rdm(&self) -> bool {
+        field(self.isar0, ID_AA64ISAR0_EL1::RDM) != 0
+    }
+
+    /// FMLAL and FMLSL instructions are implemented.
+    pub fn has_fhm(&self) -> bool {
+        field(self.isar0, ID_AA64ISAR0_EL1::FHM) != 0
+    }
+
+    /// Complex number instructions are implemented.
+    pub fn has_fcma(&self) -> bool {
+        field(self.isar1, ID_AA64ISAR1_EL1::FCMA) != 0
+    }
+
+    /// FJCVTZS is implemented.
+    pub fn has_jscvt(&self) -> bool {
+        field(self.isar1, ID_AA64ISAR1_EL1::JSCVT) != 0
+    }
+
+    /// BFloat16 instructions are implemented.
+    pub fn has_bf16(&self) -> bool {
+        field(self.isar1, ID_AA64ISAR1_EL1::BF16) != 0
+    }
+
+    /// Int8 matrix multiplication instructions are implemented.
+    pub fn has_i8mm(&self) -> bool {
+        field(self.isar1, ID_AA64ISAR1_EL1::I8MM) != 0
+    }
+
+    //--------------------------------------------------------------------------------------------
+    // Instructions
+    //--------------------------------------------------------------------------------------------
+
+    /// Large System Extensions atomic instructions are implemented.
+    pub fn has_lse(&self) -> bool {
+        field(self.isar0, ID_AA64ISAR0_EL1::Atomic) >= ID_AA64ISAR0_EL1::Atomic::Value::LSE as u64
+    }
+
+    /// CRC32 instructions are implemented.
+    pub fn has_crc32(&self) -> bool {
+        field(self.isar0, ID_AA64ISAR0_EL1::CRC32) != 0
+    }
+
+    /// AES instructions are implemented.
+    pub fn has_aes(&self) -> bool {
+        field(self.isar0, ID_AA64ISAR0_EL1::AES) != 0
+    }
+
+    /// PMULL and PMULL2 on 64-bit data are implemented.
+    pub fn has_pmull(&self) -> bool {
+        field(self.isar0, ID_AA64ISAR0_EL1::AES) >= ID_AA64ISAR0_EL1::AES::Value::AESAndPMULL as u64
+    }
+
+    /// SHA1 instructions are implemented.
+    pub fn has_sha1(&self) -> bool {
+        field(self.isar0, ID_AA64ISAR0_EL1::SHA1) != 0
+    }
+
+    /// SHA256 instructions are implemented.
+    pub fn has_sha256(&self) -> bool {
+        field(self.isar0, ID_AA64ISAR0_EL1::SHA2) != 0
+    }
+
+    /// SHA512 instructions are implemented.
+    pub fn has_sha512(&self) -> bool {
+        field(self.isar0, ID_AA64ISAR0_EL1::SHA2) >= ID_AA64ISAR0_EL1::SHA2::Value::SHA512 as u64
+    }
+
+    /// SHA3 instructions are implemented.
+    pub fn has_sha3(&self) -> bool {
+        field(self.isar0, ID_AA64ISAR0_EL1::SHA3) != 0
+    }
+
+    /// The RNDR and RNDRRS random number registers are implemented.
+    pub fn has_rndr(&self) -> bool {
+        field(self.isar0, ID_AA64ISAR0_EL1::RNDR) != 0
+    }
+
+    /// Flag manipulation instructions are implemented.
+    pub fn has_flagm(&self) -> bool {
+        field(self.isar0, ID_AA64ISAR0_EL1::TS) != 0
+    }
+
+    /// Outer Shareable TLB maintenance instructions are implemented.
+    pub fn has_tlbi_os(&self) -> bool {
+        field(self.isar0, ID_AA64ISAR0_EL1::TLB) != 0
+    }
+
+    /// TLB range maintenance instructions are implemented.
+    pub fn has_tlbi_range(&self) -> bool {
+        field(self.isar0, ID_AA64ISAR0_EL1::TLB)
+            >= ID_AA64ISAR0_EL1::TLB::Value::OuterShareableAndRange as u64
+    }
+
+    /// DC CVAP is implemented.
+    pub fn has_dcpop(&self) -> bool {
+        field(self.isar1, ID_AA64ISAR1_EL1::DPB) != 0
+    }
+
+    /// LDAPR instructions are implemented.
+    pub fn has_lrcpc(&self) -> bool {
+        field(self.isar1, ID_AA64ISAR1_EL1::LRCPC) != 0
+    }
+
+    /// The SB speculation barrier is implemented.
+    pub fn has_sb(&self) -> bool {
+        field(self.isar1, ID_AA64ISAR1_EL1::SB) != 0
+    }
+
+    /// Prediction invalidation instructions are implemented.
+    pub fn has_specres(&self) -> bool {
+        field(self.isar1, ID_AA64ISAR1_EL1::SPECRES) != 0
+    }
+
+    /// WFET and WFIT are implemented.
+    pub fn has_wfxt(&self) -> bool {
+        field(self.isar2, ID_AA64ISAR2_EL1::WFxT) != 0
+    }
+
+    //--------------------------------------------------------------------------------------------
+    // Security
+    //--------------------------------------------------------------------------------------------
+
+    /// Address authentication is implemented, with any algorithm.
+    pub fn has_pauth(&self) -> bool {
+        field(self.isar1, ID_AA64ISAR1_EL1::APA) != 0
+            || field(self.isar1, ID_AA64ISAR1_EL1::API) != 0
+            || field(self.isar2, ID_AA64ISAR2_EL1::APA3) != 0
+    }
+
+    /// Generic authentication (PACGA) is implemented, with any algorithm.
+    pub fn has_pauth_generic(&self) -> bool {
+        field(self.isar1, ID_AA64ISAR1_EL1::GPA) != 0
+            || field(self.isar1, ID_AA64ISAR1_EL1::GPI) != 0
+            || field(self.isar2, ID_AA64ISAR2_EL1::GPA3) != 0
+    }
+
+    /// Branch Target Identification is implemented.
+    pub fn has_bti(&self) -> bool {
+        field(self.pfr1, ID_AA64PFR1_EL1::BT) != 0
+    }
+
+    /// Returns the implemented level of the Memory Tagging Extension, the raw value of
+    /// `ID_AA64PFR1_EL1.MTE`.
+    pub fn mte_level(&self) -> u64 {
+        field(self.pfr1, ID_AA64PFR1_EL1::MTE)
+    }
+
+    /// The full Memory Tagging Extension, with tag storage, is implemented.
+    pub fn has_mte(&self) -> bool {
+        self.mte_level() >= ID_AA64PFR1_EL1::MTE::Value::Full as u64
+    }
+
+    /// PSTATE.SSBS is implemented.
+    pub fn has_ssbs(&self) -> bool {
+        field(self.pfr1, ID_AA64PFR1_EL1::SSBS) != 0
+    }
+
+    /// Data Independent Timing is implemented.
+    pub fn has_dit(&self) -> bool {
+        field(self.pfr0, ID_AA64PFR0_EL1::DIT) != 0
+    }
+
+    /// Branch targets cannot be hijacked across hardware contexts.
+    pub fn has_csv2(&self) -> bool {
+        field(self.pfr0, ID_AA64PFR0_EL1::CSV2) != 0
+    }
+
+    /// Data loaded under speculation with a fault cannot be used by later instructions.
+    pub fn has_csv3(&self) -> bool {
+        field(self.pfr0, ID_AA64PFR0_EL1::CSV3) != 0
+    }
+
+    //--------------------------------------------------------------------------------------------
+    // System
+    //--------------------------------------------------------------------------------------------
+
+    /// The System register interface to the GIC CPU interface is implemented.
+    pub fn has_gic_sysregs(&self) -> bool {
+        field(self.pfr0, ID_AA64PFR0_EL1::GIC) != 0
+    }
+
+    /// The RAS Extension is implemented.
+    pub fn has_ras(&self) -> bool {
+        field(self.pfr0, ID_AA64PFR0_EL1::RAS) != 0
+    }
+
+    /// The Activity Monitors Extension is implemented.
+    pub fn has_amu(&self) -> bool {
+        field(self.pfr0, ID_AA64PFR0_EL1::AMU) != 0
+    }
+
+    /// Returns the MPAM version as `(major, minor)`.
+    pub fn mpam_version(&self) -> (u64, u64) {
+        (
+            field(self.pfr0, ID_AA64PFR0_EL1::MPAM),
+            field(self.pfr1, ID_AA64PFR1_EL1::MPAM_frac),
+        )
+    }
+
+    //--------------------------------------------------------------------------------------------
+    // Memory model
+    //--------------------------------------------------------------------------------------------
+
+    /// Returns the supported physical address size in bits.
+    pub fn pa_bits(&self) -> u32 {
+        match field(self.mmfr0, ID_AA64MMFR0_EL1::PARange) {
+            0b0000 => 32,
+            0b0001 => 36,
+            0b0010 => 40,
+            0b0011 => 42,
+            0b0100 => 44,
+            0b0101 => 48,
+            _ => 52,
+        }
+    }
+
+    /// Returns the supported virtual address size in bits.
+    pub fn va_bits(&self) -> u32 {
+        if field(self.mmfr2, ID_AA64MMFR2_EL1::VARange)
+            == ID_AA64MMFR2_EL1::VARange::Value::Bits_52 as u64
+        {
+            52
+        } else {
+            48
+        }
+    }
+
+    /// Returns the number of ASID bits, 8 or 16.
+    pub fn asid_bits(&self) -> u32 {
+        if field(self.mmfr0, ID_AA64MMFR0_EL1::ASIDBits)
+            == ID_AA64MMFR0_EL1::ASIDBits::Value::Bits_16 as u64
+        {
+            16
+        } else {
+            8
+        }
+    }
+
+    /// Returns the number of VMID bits, 8 or 16.
+    pub fn vmid_bits(&self) -> u32 {
+        if field(self.mmfr1, ID_AA64MMFR1_EL1::VMIDBits)
+            == ID_AA64MMFR1_EL1::VMIDBits::Value::Bits_16 as u64
+        {
+            16
+        } else {
+            8
+        }
+    }
+
+    /// The 4KiB translation granule is supported at stage 1.
+    pub fn has_granule_4k(&self) -> bool {
+        field(self.mmfr0, ID_AA64MMFR0_EL1::TGran4)
+            != ID_AA64MMFR0_EL1::TGran4::Value::NotSupported as u64
+    }
+
+    /// The 16KiB translation granule is supported at stage 1.
+    pub fn has_granule_16k(&self) -> bool {
+        field(self.mmfr0, ID_AA64MMFR0_EL1::TGran16)
+            != ID_AA64MMFR0_EL1::TGran16::Value::NotSupported as u64
+    }
+
+    /// The 64KiB translation granule is supported at stage 1.
+    pub fn has_granule_64k(&self) -> bool {
+        field(self.mmfr0, ID_AA64MMFR0_EL1::TGran64)
+            != ID_AA64MMFR0_EL1::TGran64::Value::NotSupported as u64
+    }
+
+    /// Mixed-endian support is implemented at all Exception levels.
+    pub fn has_mixed_endian(&self) -> bool {
+        field(self.mmfr0, ID_AA64MMFR0_EL1::BigEnd) != 0
+    }
+
+    /// Virtualization Host Extensions are implemented.
+    pub fn has_vhe(&self) -> bool {
+        field(self.mmfr1, ID_AA64MMFR1_EL1::VH) != 0
+    }
+
+    /// Privileged Access Never is implemented.
+    pub fn has_pan(&self) -> bool {
+        field(self.mmfr1, ID_AA64MMFR1_EL1::PAN) != 0
+    }
+
+    /// LORegions are implemented.
+    pub fn has_lor(&self) -> bool {
+        field(self.mmfr1, ID_AA64MMFR1_EL1::LO) != 0
+    }
+
+    /// Hardware update of the Access flag is supported.
+    pub fn has_hw_access_flag(&self) -> bool {
+        field(self.mmfr1, ID_AA64MMFR1_EL1::HAFDBS) != 0
+    }
+
+    /// Hardware update of the Access flag and the dirty state is supported.
+    pub fn has_hw_dirty_state(&self) -> bool {
+        field(self.mmfr1, ID_AA64MMFR1_EL1::HAFDBS)
+            >= ID_AA64MMFR1_EL1::HAFDBS::Value::AccessFlagAndDirtyState as u64
+    }
+
+    /// Common not Private translations are implemented.
+    pub fn has_cnp(&self) -> bool {
+        field(self.mmfr2, ID_AA64MMFR2_EL1::CnP) != 0
+    }
+
+    /// User Access Override is implemented.
+    pub fn has_uao(&self) -> bool {
+        field(self.mmfr2, ID_AA64MMFR2_EL1::UAO) != 0
+    }
+
+    /// The implicit error synchronization event is implemented.
+    pub fn has_iesb(&self) -> bool {
+        field(self.mmfr2, ID_AA64MMFR2_EL1::IESB) != 0
+    }
+
+    /// Small translation tables (ARMv8.4-TTST) are supported, i.e. `TCR_ELx.TxSZ` may exceed 39.
+    pub fn has_small_translation_tables(&self) -> bool {
+        field(self.mmfr2, ID_AA64MMFR2_EL1::ST) != 0
+    }
+
+    /// Returns the implemented level of nested virtualization, the raw value of
+    /// `ID_AA64MMFR2_EL1.NV`.
+    pub fn nv_level(&self) -> u64 {
+        field(self.mmfr2, ID_AA64MMFR2_EL1::NV)
+    }
+
+    /// HCR_EL2.FWB is implemented.
+    pub fn has_fwb(&self) -> bool {
+        field(self.mmfr2, ID_AA64MMFR2_EL1::FWB) != 0
+    }
+
+    /// The E0PD mechanism is implemented.
+    pub fn has_e0pd(&self) -> bool {
+        field(self.mmfr2, ID_AA64MMFR2_EL1::E0PD) != 0
+    }
+
+    //--------------------------------------------------------------------------------------------
+    // Debug
+    //--------------------------------------------------------------------------------------------
+
+    /// Returns the number of hardware breakpoints.
+    pub fn num_breakpoints(&self) -> usize {
+        field(self.dfr0, ID_AA64DFR0_EL1::BRPs) as usize + 1
+    }
+
+    /// Returns the number of hardware watchpoints.
+    pub fn num_watchpoints(&self) -> usize {
+        field(self.dfr0, ID_AA64DFR0_EL1::WRPs) as usize + 1
+    }
+
+    /// Returns the number of context-aware breakpoints.
+    pub fn num_context_breakpoints(&self) -> usize {
+        field(self.dfr0, ID_AA64DFR0_EL1::CTX_CMPs) as usize + 1
+    }
+
+    /// Returns the PMU version, the raw value of `ID_AA64DFR0_EL1.PMUVer`.
+    pub fn pmu_version(&self) -> u64 {
+        field(self.dfr0, ID_AA64DFR0_EL1::PMUVer)
+    }
+
+    /// The architected Performance Monitors Extension, PMUv3, is implemented.
+    pub fn has_pmuv3(&self) -> bool {
+        let ver = self.pmu_version();
+
+        ver != ID_AA64DFR0_EL1::PMUVer::Value::NotImplemented as u64
+            && ver != ID_AA64DFR0_EL1::PMUVer::Value::ImplementationDefined as u64
+    }
+
+    /// The Statistical Profiling Extension is implemented.
+    pub fn has_spe(&self) -> bool {
+        field(self.dfr0, ID_AA64DFR0_EL1::PMSVer) != 0
+    }
+
+    /// The OS Double Lock is implemented.
+    pub fn has_double_lock(&self) -> bool {
+        field(self.dfr0, ID_AA64DFR0_EL1::DoubleLock)
+            == ID_AA64DFR0_EL1::DoubleLock::Value::Implemented as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_mmfr0(mmfr0: u64) -> CpuFeatures {
+        CpuFeatures {
+            mmfr0,
+            ..CpuFeatures::default()
+        }
+    }
+
+    #[test]
+    fn granules() {
+        // TGran4 = 0b0000, TGran64 = 0b0000, TGran16 = 0b0001.
+        let features = with_mmfr0(0x0000_0000_0010_0000);
+        assert!(features.has_granule_4k());
+        assert!(features.has_granule_16k());
+        assert!(features.has_granule_64k());
+
+        // TGran4 = 0b1111, TGran64 = 0b1111, TGran16 = 0b0000.
+        let features = with_mmfr0(0x0000_0000_FF00_0000);
+        assert!(!features.has_granule_4k());
+        assert!(!features.has_granule_16k());
+        assert!(!features.has_granule_64k());
+    }
+
+    #[test]
+    fn granules_lpa2() {
+        // TGran4 = 0b0001, TGran16 = 0b0010.
+        let features = with_mmfr0(0x0000_0000_1020_0000);
+        assert!(features.has_granule_4k());
+        assert!(features.has_granule_16k());
+    }
+}
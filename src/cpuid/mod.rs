@@ -0,0 +1,257 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Processor identification
+//!
+//! Decodes `MIDR_EL1` into a processor model and revision, and looks up the errata known to affect
+//! it.
+//!
+//! ```ignore
+//! use cortex_a::cpuid::CpuInfo;
+//!
+//! let cpu = CpuInfo::current();
+//!
+//! if cpu.is_affected_by(843419) {
+//!     // Apply the workaround.
+//! }
+//! ```
+
+pub mod errata;
+mod features;
+
+use crate::regs::{RegisterReadOnly, MIDR_EL1};
+use core::fmt;
+use register::LocalRegisterCopy;
+
+pub use self::{errata::Erratum, features::CpuFeatures};
+
+/// Processor model, identified by the implementer code and the primary part number.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CpuModel {
+    CortexA35,
+    CortexA53,
+    CortexA55,
+    CortexA57,
+    CortexA72,
+    CortexA73,
+    CortexA75,
+    CortexA76,
+    CortexA77,
+    CortexA78,
+    NeoverseN1,
+    NeoverseN2,
+    NeoverseV1,
+
+    /// A processor that is not known to this crate.
+    Unknown {
+        implementer: u8,
+        part_num: u16,
+    },
+}
+
+impl CpuModel {
+    /// Implementer code assigned to Arm Limited.
+    const ARM: u8 = 0x41;
+
+    /// Known models, as `(model, implementer, part number)`.
+    const KNOWN: &'static [(CpuModel, u8, u16)] = &[
+        (CpuModel::CortexA35, CpuModel::ARM, 0xD04),
+        (CpuModel::CortexA53, CpuModel::ARM, 0xD03),
+        (CpuModel::CortexA55, CpuModel::ARM, 0xD05),
+        (CpuModel::CortexA57, CpuModel::ARM, 0xD07),
+        (CpuModel::CortexA72, CpuModel::ARM, 0xD08),
+        (CpuModel::CortexA73, CpuModel::ARM, 0xD09),
+        (CpuModel::CortexA75, CpuModel::ARM, 0xD0A),
+        (CpuModel::CortexA76, CpuModel::ARM, 0xD0B),
+        (CpuModel::CortexA77, CpuModel::ARM, 0xD0D),
+        (CpuModel::CortexA78, CpuModel::ARM, 0xD41),
+        (CpuModel::NeoverseN1, CpuModel::ARM, 0xD0C),
+        (CpuModel::NeoverseN2, CpuModel::ARM, 0xD49),
+        (CpuModel::NeoverseV1, CpuModel::ARM, 0xD40),
+    ];
+
+    /// Decodes an implementer code and a primary part number.
+    pub fn from_ids(implementer: u8, part_num: u16) -> CpuModel {
+        CpuModel::KNOWN
+            .iter()
+            .find(|&&(_, imp, part)| imp == implementer && part == part_num)
+            .map(|&(model, _, _)| model)
+            .unwrap_or(CpuModel::Unknown {
+                implementer,
+                part_num,
+            })
+    }
+
+    /// Returns the implementer code, as found in `MIDR_EL1.Implementer`.
+    pub fn implementer(&self) -> u8 {
+        self.ids().0
+    }
+
+    /// Returns the primary part number, as found in `MIDR_EL1.PartNum`.
+    pub fn part_num(&self) -> u16 {
+        self.ids().1
+    }
+
+    fn ids(&self) -> (u8, u16) {
+        match *self {
+            CpuModel::Unknown {
+                implementer,
+                part_num,
+            } => (implementer, part_num),
+            model => CpuModel::KNOWN
+                .iter()
+                .find(|&&(m, _, _)| m == model)
+                .map(|&(_, imp, part)| (imp, part))
+                .unwrap(),
+        }
+    }
+}
+
+/// Product revision in the `rNpM` scheme, where `N` is the variant and `M` the revision.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Revision {
+    /// Major revision, from `MIDR_EL1.Variant`.
+    pub variant: u8,
+
+    /// Minor revision, from `MIDR_EL1.Revision`.
+    pub revision: u8,
+}
+
+impl Revision {
+    pub const fn new(variant: u8, revision: u8) -> Revision {
+        Revision { variant, revision }
+    }
+}
+
+impl fmt::Display for Revision {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "r{}p{}", self.variant, self.revision)
+    }
+}
+
+/// Processor model and revision, decoded from `MIDR_EL1`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CpuInfo {
+    pub model: CpuModel,
+    pub revision: Revision,
+}
+
+impl CpuInfo {
+    /// Reads `MIDR_EL1` of the executing core.
+    #[inline]
+    pub fn current() -> CpuInfo {
+        CpuInfo::from_midr(MIDR_EL1.get())
+    }
+
+    /// Decodes a raw `MIDR_EL1` value.
+    pub fn from_midr(midr: u64) -> CpuInfo {
+        let midr: LocalRegisterCopy<u64, MIDR_EL1::Register> = LocalRegisterCopy::new(midr);
+
+        CpuInfo {
+            model: CpuModel::from_ids(
+                midr.read(MIDR_EL1::Implementer) as u8,
+                midr.read(MIDR_EL1::PartNum) as u16,
+            ),
+            revision: Revision::new(
+                midr.read(MIDR_EL1::Variant) as u8,
+                midr.read(MIDR_EL1::Revision) as u8,
+            ),
+        }
+    }
+
+    /// Returns the known errata that affect this processor.
+    pub fn errata(&self) -> impl Iterator<Item = &'static Erratum> {
+        errata::affecting(self.model, self.revision)
+    }
+
+    /// Returns whether the erratum with the given Arm erratum number affects this processor.
+    pub fn is_affected_by(&self, id: u32) -> bool {
+        self.errata().any(|e| e.id == id)
+    }
+}
+
+impl fmt::Display for CpuInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.model {
+            CpuModel::Unknown {
+                implementer,
+                part_num,
+            } => write!(
+                f,
+                "{:#04x}:{:#05x} {}",
+                implementer, part_num, self.revision
+            ),
+            model => write!(f, "{:?} {}", model, self.revision),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::string::ToString;
+
+    #[test]
+    fn from_midr() {
+        let cases = [
+            (0x410F_D034, CpuModel::CortexA53, Revision::new(0, 4)),
+            (0x411F_D070, CpuModel::CortexA57, Revision::new(1, 0)),
+            (0x410F_D083, CpuModel::CortexA72, Revision::new(0, 3)),
+            (0x413F_D0B1, CpuModel::CortexA76, Revision::new(3, 1)),
+            (0x414F_D0C0, CpuModel::NeoverseN1, Revision::new(4, 0)),
+            (0x410F_D490, CpuModel::NeoverseN2, Revision::new(0, 0)),
+            (
+                0x611F_0221,
+                CpuModel::Unknown {
+                    implementer: 0x61,
+                    part_num: 0x022,
+                },
+                Revision::new(1, 1),
+            ),
+            (
+                0x410F_DFF0,
+                CpuModel::Unknown {
+                    implementer: 0x41,
+                    part_num: 0xDFF,
+                },
+                Revision::new(0, 0),
+            ),
+        ];
+
+        for &(midr, model, revision) in &cases {
+            let info = CpuInfo::from_midr(midr);
+
+            assert_eq!(info, CpuInfo { model, revision }, "{:#x}", midr);
+            assert_eq!(info.model.implementer() as u64, midr >> 24);
+            assert_eq!(info.model.part_num() as u64, midr >> 4 & 0xFFF);
+        }
+    }
+
+    #[test]
+    fn display() {
+        let cases = [
+            (Revision::new(0, 0), "r0p0"),
+            (Revision::new(2, 1), "r2p1"),
+            (Revision::new(15, 15), "r15p15"),
+        ];
+
+        for &(revision, expected) in &cases {
+            assert_eq!(revision.to_string(), expected);
+        }
+
+        assert_eq!(
+            CpuInfo::from_midr(0x410F_D034).to_string(),
+            "CortexA53 r0p4"
+        );
+        assert_eq!(
+            CpuInfo::from_midr(0x611F_0221).to_string(),
+            "0x61:0x022 r1p1"
+        );
+    }
+}
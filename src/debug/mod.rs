@@ -0,0 +1,387 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Self-hosted debug
+//!
+//! Installs hardware breakpoints and watchpoints of the executing core:
+//!
+//! ```ignore
+//! use cortex_a::{cpuid::CpuFeatures, debug::{ElFilter, HwDebug, WatchKind}};
+//!
+//! let mut debug = HwDebug::new(&CpuFeatures::detect());
+//!
+//! // Catch writes to the stack guard page.
+//! let wp = debug.add_watchpoint(guard_page, 4096, WatchKind::Store, ElFilter::El1)?;
+//! // ...
+//! debug.remove_watchpoint(wp)?;
+//! ```
+//!
+//! The resulting debug exceptions are only taken if `PSTATE.D` is clear at the target Exception
+//! level. The debug registers are per core, so every core needs its own `HwDebug`.
+
+pub mod step;
+
+use crate::{barrier, cpuid::CpuFeatures, regs::*};
+
+/// The number of breakpoint and watchpoint register pairs defined by the architecture.
+pub const MAX_SLOTS: usize = 16;
+
+/// The Exception levels at which a breakpoint or watchpoint matches.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ElFilter {
+    El0,
+    El1,
+    El0AndEl1,
+    El2,
+    El1AndEl2,
+    El0ToEl2,
+}
+
+impl ElFilter {
+    /// Returns the `(HMC, SSC, PxC)` encoding. All filters match in either Security state, except
+    /// the ones including EL2, which only match in Non-secure state.
+    fn encode(self) -> (u64, u64, u64) {
+        match self {
+            ElFilter::El0 => (0, 0b00, 0b10),
+            ElFilter::El1 => (0, 0b00, 0b01),
+            ElFilter::El0AndEl1 => (0, 0b00, 0b11),
+            ElFilter::El2 => (1, 0b01, 0b00),
+            ElFilter::El1AndEl2 => (1, 0b01, 0b01),
+            ElFilter::El0ToEl2 => (1, 0b01, 0b11),
+        }
+    }
+}
+
+/// The accesses that trigger a watchpoint.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WatchKind {
+    Load,
+    Store,
+    LoadStore,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DebugError {
+    /// All breakpoints or watchpoints are in use.
+    NoFreeSlot,
+
+    /// The address is not aligned as required for the requested length.
+    Misaligned,
+
+    /// The length can neither be expressed as a byte mask nor as an address mask.
+    InvalidLength,
+
+    /// The breakpoint or watchpoint is not installed in this `HwDebug`.
+    NotInstalled,
+}
+
+/// An installed breakpoint.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Breakpoint(usize);
+
+impl Breakpoint {
+    /// Returns the index of the breakpoint register pair.
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// An installed watchpoint.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Watchpoint(usize);
+
+impl Watchpoint {
+    /// Returns the index of the watchpoint register pair.
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// Allocator for the breakpoint and watchpoint registers of the executing core.
+#[derive(Debug)]
+pub struct HwDebug {
+    num_breakpoints: usize,
+    num_watchpoints: usize,
+    breakpoints: u16,
+    watchpoints: u16,
+}
+
+impl HwDebug {
+    /// Unlocks the OS Lock and the OS Double Lock if needed, disables all breakpoints and
+    /// watchpoints, and enables breakpoint and watchpoint exceptions at EL0 and at the debug target
+    /// Exception level through `MDSCR_EL1.{MDE, KDE}`.
+    pub fn new(features: &CpuFeatures) -> HwDebug {
+        if features.has_double_lock() {
+            OSDLR_EL1.write(OSDLR_EL1::DLK::Unlocked);
+        }
+
+        if OSLSR_EL1.matches_all(OSLSR_EL1::OSLK::Locked) {
+            OSLAR_EL1.write(OSLAR_EL1::OSLK::Unlocked);
+        }
+
+        unsafe { barrier::isb(barrier::SY) };
+
+        let debug = HwDebug {
+            num_breakpoints: features.num_breakpoints().min(MAX_SLOTS),
+            num_watchpoints: features.num_watchpoints().min(MAX_SLOTS),
+            breakpoints: 0,
+            watchpoints: 0,
+        };
+
+        for n in 0..debug.num_breakpoints {
+            set_dbgbcr(n, 0);
+        }
+        for n in 0..debug.num_watchpoints {
+            set_dbgwcr(n, 0);
+        }
+
+        MDSCR_EL1.modify(MDSCR_EL1::MDE::Enable + MDSCR_EL1::KDE::Enable);
+        unsafe { barrier::isb(barrier::SY) };
+
+        debug
+    }
+
+    /// Returns the number of implemented breakpoints.
+    pub fn num_breakpoints(&self) -> usize {
+        self.num_breakpoints
+    }
+
+    /// Returns the number of implemented watchpoints.
+    pub fn num_watchpoints(&self) -> usize {
+        self.num_watchpoints
+    }
+
+    /// Installs a breakpoint on the instruction at `addr`, which must be 4-byte aligned.
+    pub fn add_breakpoint(&mut self, addr: u64, el: ElFilter) -> Result<Breakpoint, DebugError> {
+        if addr & 0b11 != 0 {
+            return Err(DebugError::Misaligned);
+        }
+
+        let n = allocate(&mut self.breakpoints, self.num_breakpoints)?;
+        let (hmc, ssc, pmc) = el.encode();
+
+        set_dbgbvr(n, addr);
+        set_dbgbcr(
+            n,
+            (DBGBCR_EL1::BT::UnlinkedAddressMatch
+                + DBGBCR_EL1::HMC.val(hmc)
+                + DBGBCR_EL1::SSC.val(ssc)
+                + DBGBCR_EL1::PMC.val(pmc)
+                + DBGBCR_EL1::BAS.val(0b1111)
+                + DBGBCR_EL1::E::Enable)
+                .value,
+        );
+        unsafe { barrier::isb(barrier::SY) };
+
+        Ok(Breakpoint(n))
+    }
+
+    /// Removes a breakpoint. Fails if `bp` was not installed by this `HwDebug`.
+    pub fn remove_breakpoint(&mut self, bp: Breakpoint) -> Result<(), DebugError> {
+        release(&mut self.breakpoints, bp.0)?;
+
+        set_dbgbcr(bp.0, 0);
+        unsafe { barrier::isb(barrier::SY) };
+
+        Ok(())
+    }
+
+    /// Installs a watchpoint on the `len` bytes starting at `addr`.
+    ///
+    /// Up to eight bytes can be watched if they lie within one aligned double-word. Larger regions
+    /// must have a power of two size of up to 2GiB and be aligned to their size.
+    pub fn add_watchpoint(
+        &mut self,
+        addr: u64,
+        len: u64,
+        kind: WatchKind,
+        el: ElFilter,
+    ) -> Result<Watchpoint, DebugError> {
+        let (value, bas, mask) = watch_range(addr, len)?;
+        let n = allocate(&mut self.watchpoints, self.num_watchpoints)?;
+        let (hmc, ssc, pac) = el.encode();
+        let lsc = match kind {
+            WatchKind::Load => DBGWCR_EL1::LSC::Load,
+            WatchKind::Store => DBGWCR_EL1::LSC::Store,
+            WatchKind::LoadStore => DBGWCR_EL1::LSC::LoadStore,
+        };
+
+        set_dbgwvr(n, value);
+        set_dbgwcr(
+            n,
+            (DBGWCR_EL1::MASK.val(mask)
+                + DBGWCR_EL1::WT::Unlinked
+                + DBGWCR_EL1::HMC.val(hmc)
+                + DBGWCR_EL1::SSC.val(ssc)
+                + DBGWCR_EL1::BAS.val(bas)
+                + lsc
+                + DBGWCR_EL1::PAC.val(pac)
+                + DBGWCR_EL1::E::Enable)
+                .value,
+        );
+        unsafe { barrier::isb(barrier::SY) };
+
+        Ok(Watchpoint(n))
+    }
+
+    /// Removes a watchpoint. Fails if `wp` was not installed by this `HwDebug`.
+    pub fn remove_watchpoint(&mut self, wp: Watchpoint) -> Result<(), DebugError> {
+        release(&mut self.watchpoints, wp.0)?;
+
+        set_dbgwcr(wp.0, 0);
+        unsafe { barrier::isb(barrier::SY) };
+
+        Ok(())
+    }
+}
+
+/// Claims the lowest free slot in `used`.
+fn allocate(used: &mut u16, num: usize) -> Result<usize, DebugError> {
+    let n = (!*used).trailing_zeros() as usize;
+    if n >= num {
+        return Err(DebugError::NoFreeSlot);
+    }
+
+    *used |= 1 << n;
+
+    Ok(n)
+}
+
+/// Frees slot `n` in `used`, which must have been claimed.
+fn release(used: &mut u16, n: usize) -> Result<(), DebugError> {
+    if n >= MAX_SLOTS || *used & 1 << n == 0 {
+        return Err(DebugError::NotInstalled);
+    }
+
+    *used &= !(1 << n);
+
+    Ok(())
+}
+
+/// Returns the `DBGWVR_EL1` value and the `BAS` and `MASK` fields that watch the `len` bytes
+/// starting at `addr`.
+fn watch_range(addr: u64, len: u64) -> Result<(u64, u64, u64), DebugError> {
+    if len == 0 {
+        Err(DebugError::InvalidLength)
+    } else if len <= 8 {
+        let offset = addr & 0b111;
+        if offset + len > 8 {
+            return Err(DebugError::Misaligned);
+        }
+
+        Ok((addr & !0b111, ((1 << len) - 1) << offset, 0))
+    } else {
+        if !len.is_power_of_two() || len > 1 << 31 {
+            return Err(DebugError::InvalidLength);
+        }
+        if addr & (len - 1) != 0 {
+            return Err(DebugError::Misaligned);
+        }
+
+        Ok((addr, 0xFF, len.trailing_zeros() as u64))
+    }
+}
+
+macro_rules! indexed_setter {
+    ($name:ident, $($n:literal => $reg:ident,)*) => {
+        fn $name(n: usize, value: u64) {
+            match n {
+                $($n => $reg.set(value),)*
+                _ => unreachable!(),
+            }
+        }
+    };
+}
+
+#[rustfmt::skip]
+indexed_setter!(set_dbgbcr,
+    0 => DBGBCR0_EL1, 1 => DBGBCR1_EL1, 2 => DBGBCR2_EL1, 3 => DBGBCR3_EL1,
+    4 => DBGBCR4_EL1, 5 => DBGBCR5_EL1, 6 => DBGBCR6_EL1, 7 => DBGBCR7_EL1,
+    8 => DBGBCR8_EL1, 9 => DBGBCR9_EL1, 10 => DBGBCR10_EL1, 11 => DBGBCR11_EL1,
+    12 => DBGBCR12_EL1, 13 => DBGBCR13_EL1, 14 => DBGBCR14_EL1, 15 => DBGBCR15_EL1,
+);
+
+#[rustfmt::skip]
+indexed_setter!(set_dbgbvr,
+    0 => DBGBVR0_EL1, 1 => DBGBVR1_EL1, 2 => DBGBVR2_EL1, 3 => DBGBVR3_EL1,
+    4 => DBGBVR4_EL1, 5 => DBGBVR5_EL1, 6 => DBGBVR6_EL1, 7 => DBGBVR7_EL1,
+    8 => DBGBVR8_EL1, 9 => DBGBVR9_EL1, 10 => DBGBVR10_EL1, 11 => DBGBVR11_EL1,
+    12 => DBGBVR12_EL1, 13 => DBGBVR13_EL1, 14 => DBGBVR14_EL1, 15 => DBGBVR15_EL1,
+);
+
+#[rustfmt::skip]
+indexed_setter!(set_dbgwcr,
+    0 => DBGWCR0_EL1, 1 => DBGWCR1_EL1, 2 => DBGWCR2_EL1, 3 => DBGWCR3_EL1,
+    4 => DBGWCR4_EL1, 5 => DBGWCR5_EL1, 6 => DBGWCR6_EL1, 7 => DBGWCR7_EL1,
+    8 => DBGWCR8_EL1, 9 => DBGWCR9_EL1, 10 => DBGWCR10_EL1, 11 => DBGWCR11_EL1,
+    12 => DBGWCR12_EL1, 13 => DBGWCR13_EL1, 14 => DBGWCR14_EL1, 15 => DBGWCR15_EL1,
+);
+
+#[rustfmt::skip]
+indexed_setter!(set_dbgwvr,
+    0 => DBGWVR0_EL1, 1 => DBGWVR1_EL1, 2 => DBGWVR2_EL1, 3 => DBGWVR3_EL1,
+    4 => DBGWVR4_EL1, 5 => DBGWVR5_EL1, 6 => DBGWVR6_EL1, 7 => DBGWVR7_EL1,
+    8 => DBGWVR8_EL1, 9 => DBGWVR9_EL1, 10 => DBGWVR10_EL1, 11 => DBGWVR11_EL1,
+    12 => DBGWVR12_EL1, 13 => DBGWVR13_EL1, 14 => DBGWVR14_EL1, 15 => DBGWVR15_EL1,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allocate_lowest_free() {
+        let mut used = 0b1011;
+
+        assert_eq!(allocate(&mut used, 6), Ok(2));
+        assert_eq!(allocate(&mut used, 6), Ok(4));
+        assert_eq!(allocate(&mut used, 6), Ok(5));
+        assert_eq!(allocate(&mut used, 6), Err(DebugError::NoFreeSlot));
+        assert_eq!(used, 0b11_1111);
+
+        let mut used = 0xFFFF;
+        assert_eq!(allocate(&mut used, MAX_SLOTS), Err(DebugError::NoFreeSlot));
+    }
+
+    #[test]
+    fn release_checks_slot() {
+        let mut used = 0b0101;
+
+        assert_eq!(release(&mut used, 1), Err(DebugError::NotInstalled));
+        assert_eq!(release(&mut used, MAX_SLOTS), Err(DebugError::NotInstalled));
+        assert_eq!(release(&mut used, 2), Ok(()));
+        assert_eq!(release(&mut used, 2), Err(DebugError::NotInstalled));
+        assert_eq!(used, 0b0001);
+
+        // A freed slot is handed out again.
+        assert_eq!(allocate(&mut used, 4), Ok(1));
+    }
+
+    #[test]
+    fn watch_byte_mask() {
+        assert_eq!(watch_range(0x1000, 8), Ok((0x1000, 0xFF, 0)));
+        assert_eq!(watch_range(0x1000, 1), Ok((0x1000, 0b1, 0)));
+        assert_eq!(watch_range(0x1003, 2), Ok((0x1000, 0b1_1000, 0)));
+        assert_eq!(watch_range(0x1007, 1), Ok((0x1000, 0b1000_0000, 0)));
+        assert_eq!(watch_range(0x1004, 4), Ok((0x1000, 0b1111_0000, 0)));
+        assert_eq!(watch_range(0x1005, 4), Err(DebugError::Misaligned));
+        assert_eq!(watch_range(0x1000, 0), Err(DebugError::InvalidLength));
+    }
+
+    #[test]
+    fn watch_address_mask() {
+        assert_eq!(watch_range(0x1000, 16), Ok((0x1000, 0xFF, 4)));
+        assert_eq!(watch_range(0x2000, 4096), Ok((0x2000, 0xFF, 12)));
+        assert_eq!(
+            watch_range(0x8000_0000, 1 << 31),
+            Ok((0x8000_0000, 0xFF, 31))
+        );
+        assert_eq!(watch_range(0x1008, 16), Err(DebugError::Misaligned));
+        assert_eq!(watch_range(0x1000, 24), Err(DebugError::InvalidLength));
+        assert_eq!(watch_range(0, 1 << 32), Err(DebugError::InvalidLength));
+    }
+}
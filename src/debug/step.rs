@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Software step
+//!
+//! Steps the context that an exception handler returns to one instruction at a time. The saved
+//! `SPSR` of that context is usually part of the exception frame:
+//!
+//! ```ignore
+//! use cortex_a::debug::step::{self, StepSyndrome};
+//!
+//! // In the synchronous exception handler:
+//! match step::syndrome(esr) {
+//!     Some(_) => {
+//!         // One instruction was executed, re-arm to keep stepping...
+//!         step::arm(&mut frame.spsr);
+//!         // ... or resume normal execution.
+//!         step::disarm(&mut frame.spsr);
+//!     }
+//!     None => { /* Some other exception. */ }
+//! }
+//! ```
+//!
+//! Software step exceptions are taken to the debug target Exception level. Stepping the same
+//! Exception level additionally requires `MDSCR_EL1.KDE` and a clear `PSTATE.D`.
+
+use crate::{
+    iss::software_step::{SoftwareStepIss, ISS},
+    regs::*,
+};
+use register::LocalRegisterCopy;
+
+/// Information about the stepped instruction, decoded from the syndrome.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StepSyndrome {
+    /// An instruction other than a Load-Exclusive was stepped.
+    Instruction,
+
+    /// A Load-Exclusive instruction was stepped. Stepping on can make the exclusive sequence fail
+    /// forever.
+    LoadExclusive,
+
+    /// No syndrome information is available, e.g. because the exception was taken before the
+    /// instruction was executed.
+    Unknown,
+}
+
+#[inline(always)]
+fn ss_bit() -> u64 {
+    SPSR_EL1::SS::SET.value as u64
+}
+
+/// Arms software step for the context returned to with `spsr` as its saved `PSTATE`.
+///
+/// After the next `eret`, exactly one instruction is executed before a software step exception is
+/// taken.
+#[inline]
+pub fn arm(spsr: &mut u64) {
+    MDSCR_EL1.modify(MDSCR_EL1::SS::Enable);
+    *spsr |= ss_bit();
+}
+
+/// Disarms software step, the context returned to with `spsr` runs freely.
+#[inline]
+pub fn disarm(spsr: &mut u64) {
+    MDSCR_EL1.modify(MDSCR_EL1::SS::Disable);
+    *spsr &= !ss_bit();
+}
+
+/// Returns whether software step is enabled in `MDSCR_EL1`.
+#[inline]
+pub fn is_enabled() -> bool {
+    MDSCR_EL1.is_set(MDSCR_EL1::SS)
+}
+
+/// Decodes `esr`, the raw value of `ESR_EL1` or `ESR_EL2`. Returns `None` if the exception is not a
+/// software step exception.
+pub fn syndrome(esr: u32) -> Option<StepSyndrome> {
+    let esr: LocalRegisterCopy<u32, ESR_EL1::Register> = LocalRegisterCopy::new(esr);
+
+    match esr.read_as_enum(ESR_EL1::EC) {
+        Some(ESR_EL1::EC::Value::SoftwareStepLowerEL)
+        | Some(ESR_EL1::EC::Value::SoftwareStepCurrentEL) => (),
+        _ => return None,
+    }
+
+    let iss = SoftwareStepIss::new(esr.read(ESR_EL1::ISS));
+
+    let syndrome = if !iss.is_set(ISS::ISV) {
+        StepSyndrome::Unknown
+    } else if iss.is_set(ISS::EX) {
+        StepSyndrome::LoadExclusive
+    } else {
+        StepSyndrome::Instruction
+    };
+
+    Some(syndrome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ISV: u32 = 1 << 24;
+    const EX: u32 = 1 << 6;
+
+    /// `IFSC` of a software step exception, the Debug exception code.
+    const IFSC: u32 = 0b10_0010;
+
+    fn esr(ec: u32, iss: u32) -> u32 {
+        ec << 26 | 1 << 25 | iss
+    }
+
+    #[test]
+    fn syndromes() {
+        let cases = [
+            (esr(0b11_0010, IFSC), StepSyndrome::Unknown),
+            (esr(0b11_0011, IFSC), StepSyndrome::Unknown),
+            // EX is only valid with ISV set.
+            (esr(0b11_0011, EX | IFSC), StepSyndrome::Unknown),
+            (esr(0b11_0010, ISV | IFSC), StepSyndrome::Instruction),
+            (esr(0b11_0011, ISV | IFSC), StepSyndrome::Instruction),
+            (esr(0b11_0010, ISV | EX | IFSC), StepSyndrome::LoadExclusive),
+            (esr(0b11_0011, ISV | EX | IFSC), StepSyndrome::LoadExclusive),
+        ];
+
+        for &(esr, expected) in &cases {
+            assert_eq!(syndrome(esr), Some(expected), "{:#x}", esr);
+        }
+    }
+
+    #[test]
+    fn other_exceptions() {
+        // Unknown, SVC, breakpoint, watchpoint, BRK and an unallocated EC.
+        for &ec in &[
+            0b00_0000, 0b01_0101, 0b11_0000, 0b11_0101, 0b11_1100, 0b11_1111,
+        ] {
+            assert_eq!(syndrome(esr(ec, ISV | EX | IFSC)), None, "{:#b}", ec);
+            assert_eq!(syndrome(esr(ec, 0)), None, "{:#b}", ec);
+        }
+    }
+}
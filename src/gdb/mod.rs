@@ -0,0 +1,1182 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! GDB remote serial protocol stub
+//!
+//! [`GdbStub`] is entered from a synchronous exception handler and talks to GDB over a
+//! caller-provided byte [`Transport`], e.g. a UART. Memory is accessed through a [`Target`], so
+//! that the caller decides which addresses are safe to touch:
+//!
+//! ```ignore
+//! use cortex_a::gdb::{ExceptionFrame, GdbStub, Resume};
+//!
+//! static mut STUB: Option<GdbStub<Uart, Memory>> = None;
+//!
+//! // In the synchronous exception handler:
+//! let stub = unsafe { STUB.get_or_insert_with(|| GdbStub::new(Uart, Memory)) };
+//! match stub.handle_exception(frame, ESR_EL1.get(), FAR_EL1.get()) {
+//!     Resume::Continue | Resume::Step | Resume::Detached => { /* eret */ }
+//! }
+//!
+//! // Wherever the debugger should take over first:
+//! unsafe { llvm_asm!("brk #0" :::: "volatile") };
+//! ```
+//!
+//! Software breakpoints patch a `BRK` instruction into the code. Hardware breakpoints and
+//! watchpoints are available after [`GdbStub::with_hw_debug`]. Hardware breakpoints, watchpoints
+//! and single step at the Exception level the stub runs at require `PSTATE.D` to be clear in the
+//! stopped context.
+//!
+//! The register file reported to GDB consists of the core registers followed by a few EL1 system
+//! registers, which are read and written directly and not through the exception frame.
+
+mod packet;
+
+use crate::{
+    bti,
+    debug::{step, Breakpoint, ElFilter, HwDebug, WatchKind, Watchpoint},
+    regs::*,
+};
+use packet::{Reply, BUFFER_SIZE};
+use register::LocalRegisterCopy;
+
+/// The byte stream to GDB.
+pub trait Transport {
+    /// Blocks until a byte is received.
+    fn read_byte(&mut self) -> u8;
+
+    fn write_byte(&mut self, byte: u8);
+
+    /// Called after each packet, for transports that buffer written bytes.
+    fn flush(&mut self) {}
+}
+
+/// An access to memory that is not accessible.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MemoryFault;
+
+/// Memory accessor of the debugged program.
+pub trait Target {
+    fn read_memory(&mut self, addr: u64, buf: &mut [u8]) -> Result<(), MemoryFault>;
+
+    fn write_memory(&mut self, addr: u64, data: &[u8]) -> Result<(), MemoryFault>;
+
+    /// Makes instructions written to `len` bytes at `addr` visible to instruction fetches.
+    ///
+    /// Called after software breakpoints are inserted or removed and after GDB wrote memory. The
+    /// default implementation does nothing, which is only correct if the instruction cache is
+    /// disabled.
+    fn sync_instruction_cache(&mut self, _addr: u64, _len: usize) {}
+}
+
+/// The registers saved on exception entry.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ExceptionFrame {
+    /// `x0` to `x30`.
+    pub gpr: [u64; 31],
+
+    /// The stack pointer of the interrupted context.
+    pub sp: u64,
+
+    /// The saved `ELR_ELx`, i.e. the `pc` of the interrupted context.
+    pub elr: u64,
+
+    /// The saved `SPSR_ELx`, i.e. the `cpsr` of the interrupted context.
+    pub spsr: u64,
+}
+
+/// How to return from the exception after the stub handed control back.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Resume {
+    /// Continue execution.
+    Continue,
+
+    /// Execute a single instruction, software step is armed in the frame.
+    Step,
+
+    /// GDB detached or killed the session. All breakpoints are removed.
+    Detached,
+}
+
+/// The `BRK #0` instruction inserted for software breakpoints.
+const BRK: u32 = 0xD420_0000;
+
+const MAX_SW_BREAKPOINTS: usize = 32;
+const MAX_HW_SLOTS: usize = 16;
+
+const REG_SP: usize = 31;
+const REG_PC: usize = 32;
+const REG_CPSR: usize = 33;
+const REG_SYSTEM: usize = 34;
+
+const SIGILL: u8 = 4;
+const SIGTRAP: u8 = 5;
+const SIGBUS: u8 = 7;
+const SIGSEGV: u8 = 11;
+
+/// `errno` values sent in error replies.
+const EFAULT: u8 = 14;
+const EINVAL: u8 = 22;
+const ENOSPC: u8 = 28;
+
+/// A system register exposed to GDB.
+struct SystemRegister {
+    read: fn() -> u64,
+    write: Option<fn(u64)>,
+}
+
+/// The system registers, in the order of the `system` feature in [`TARGET_XML`].
+#[rustfmt::skip]
+static SYSTEM_REGISTERS: [SystemRegister; 9] = [
+    SystemRegister { read: || SCTLR_EL1.get(),        write: Some(|v| SCTLR_EL1.set(v)) },
+    SystemRegister { read: || TCR_EL1.get(),          write: Some(|v| TCR_EL1.set(v)) },
+    SystemRegister { read: || TTBR0_EL1.get(),        write: Some(|v| TTBR0_EL1.set(v)) },
+    SystemRegister { read: || TTBR1_EL1.get(),        write: Some(|v| TTBR1_EL1.set(v)) },
+    SystemRegister { read: || MAIR_EL1.get(),         write: Some(|v| MAIR_EL1.set(v)) },
+    SystemRegister { read: || VBAR_EL1.get(),         write: Some(|v| VBAR_EL1.set(v)) },
+    SystemRegister { read: || ESR_EL1.get() as u64,   write: None },
+    SystemRegister { read: || FAR_EL1.get(),          write: Some(|v| FAR_EL1.set(v)) },
+    SystemRegister { read: || SP_EL0.get(),           write: Some(|v| SP_EL0.set(v)) },
+];
+
+/// The target description.
+#[rustfmt::skip]
+static TARGET_XML: &str = concat!(
+    r#"<?xml version="1.0"?><!DOCTYPE target SYSTEM "gdb-target.dtd">"#,
+    r#"<target version="1.0"><architecture>aarch64</architecture>"#,
+    r#"<feature name="org.gnu.gdb.aarch64.core">"#,
+    r#"<reg name="x0" bitsize="64"/><reg name="x1" bitsize="64"/>"#,
+    r#"<reg name="x2" bitsize="64"/><reg name="x3" bitsize="64"/>"#,
+    r#"<reg name="x4" bitsize="64"/><reg name="x5" bitsize="64"/>"#,
+    r#"<reg name="x6" bitsize="64"/><reg name="x7" bitsize="64"/>"#,
+    r#"<reg name="x8" bitsize="64"/><reg name="x9" bitsize="64"/>"#,
+    r#"<reg name="x10" bitsize="64"/><reg name="x11" bitsize="64"/>"#,
+    r#"<reg name="x12" bitsize="64"/><reg name="x13" bitsize="64"/>"#,
+    r#"<reg name="x14" bitsize="64"/><reg name="x15" bitsize="64"/>"#,
+    r#"<reg name="x16" bitsize="64"/><reg name="x17" bitsize="64"/>"#,
+    r#"<reg name="x18" bitsize="64"/><reg name="x19" bitsize="64"/>"#,
+    r#"<reg name="x20" bitsize="64"/><reg name="x21" bitsize="64"/>"#,
+    r#"<reg name="x22" bitsize="64"/><reg name="x23" bitsize="64"/>"#,
+    r#"<reg name="x24" bitsize="64"/><reg name="x25" bitsize="64"/>"#,
+    r#"<reg name="x26" bitsize="64"/><reg name="x27" bitsize="64"/>"#,
+    r#"<reg name="x28" bitsize="64"/><reg name="x29" bitsize="64"/>"#,
+    r#"<reg name="x30" bitsize="64"/>"#,
+    r#"<reg name="sp" bitsize="64" type="data_ptr"/>"#,
+    r#"<reg name="pc" bitsize="64" type="code_ptr"/>"#,
+    r#"<reg name="cpsr" bitsize="32"/>"#,
+    r#"</feature>"#,
+    r#"<feature name="cortex-a.system">"#,
+    r#"<reg name="sctlr_el1" bitsize="64" regnum="34" group="system"/>"#,
+    r#"<reg name="tcr_el1" bitsize="64" group="system"/>"#,
+    r#"<reg name="ttbr0_el1" bitsize="64" type="data_ptr" group="system"/>"#,
+    r#"<reg name="ttbr1_el1" bitsize="64" type="data_ptr" group="system"/>"#,
+    r#"<reg name="mair_el1" bitsize="64" group="system"/>"#,
+    r#"<reg name="vbar_el1" bitsize="64" type="code_ptr" group="system"/>"#,
+    r#"<reg name="esr_el1" bitsize="64" group="system"/>"#,
+    r#"<reg name="far_el1" bitsize="64" type="data_ptr" group="system"/>"#,
+    r#"<reg name="sp_el0" bitsize="64" type="data_ptr" group="system"/>"#,
+    r#"</feature></target>"#,
+);
+
+/// A software breakpoint and the instruction it replaced.
+#[derive(Copy, Clone)]
+struct SwBreakpoint {
+    addr: u64,
+    insn: u32,
+}
+
+/// The reason of the last stop, reported again when GDB asks with `?`.
+#[derive(Copy, Clone)]
+struct Stop {
+    signal: u8,
+
+    /// The stop reason and the address of a watchpoint hit, e.g. `("rwatch", far)`.
+    watch: Option<(&'static str, u64)>,
+}
+
+struct HwWatchpoint {
+    addr: u64,
+    len: u64,
+    kind: WatchKind,
+    wp: Watchpoint,
+}
+
+/// Everything but the packet buffers, so that handlers can borrow the received packet and the
+/// reply at the same time.
+struct State<M> {
+    target: M,
+    debug: Option<HwDebug>,
+    el: ElFilter,
+
+    /// Whether software step was armed for the last resume.
+    stepping: bool,
+    stop: Stop,
+
+    /// Arm and disarm software step, replaced in tests to keep `MDSCR_EL1` untouched.
+    arm_step: fn(&mut u64),
+    disarm_step: fn(&mut u64),
+    sw_breakpoints: [Option<SwBreakpoint>; MAX_SW_BREAKPOINTS],
+    hw_breakpoints: [Option<(u64, Breakpoint)>; MAX_HW_SLOTS],
+    hw_watchpoints: [Option<HwWatchpoint>; MAX_HW_SLOTS],
+}
+
+/// A GDB remote serial protocol stub.
+///
+/// The stub holds two packet buffers of 4KiB each, so it is usually placed in a static.
+pub struct GdbStub<T, M> {
+    transport: T,
+    state: State<M>,
+    rx: [u8; BUFFER_SIZE],
+    reply: Reply,
+}
+
+impl<T: Transport, M: Target> GdbStub<T, M> {
+    /// Creates a stub that supports software breakpoints only.
+    pub fn new(transport: T, target: M) -> GdbStub<T, M> {
+        GdbStub {
+            transport,
+            state: State {
+                target,
+                debug: None,
+                el: ElFilter::El1,
+                stepping: false,
+                stop: Stop {
+                    signal: SIGTRAP,
+                    watch: None,
+                },
+                arm_step: step::arm,
+                disarm_step: step::disarm,
+                sw_breakpoints: [None; MAX_SW_BREAKPOINTS],
+                hw_breakpoints: Default::default(),
+                hw_watchpoints: Default::default(),
+            },
+            rx: [0; BUFFER_SIZE],
+            reply: Reply::new(),
+        }
+    }
+
+    /// Enables hardware breakpoints and watchpoints, matching at the Exception levels in `el`.
+    pub fn with_hw_debug(mut self, debug: HwDebug, el: ElFilter) -> GdbStub<T, M> {
+        self.state.debug = Some(debug);
+        self.state.el = el;
+
+        self
+    }
+
+    pub fn target(&mut self) -> &mut M {
+        &mut self.state.target
+    }
+
+    /// Reports the exception to GDB and serves its requests until it resumes the stopped context.
+    ///
+    /// `esr` and `far` are the values of `ESR_ELx` and `FAR_ELx` of the exception. A `BRK`
+    /// instruction that is not one of the stub's software breakpoints is skipped, so that
+    /// resuming does not trap again.
+    pub fn handle_exception(&mut self, frame: &mut ExceptionFrame, esr: u32, far: u64) -> Resume {
+        let esr: LocalRegisterCopy<u32, ESR_EL1::Register> = LocalRegisterCopy::new(esr);
+        let ec = esr.read_as_enum(ESR_EL1::EC);
+
+        if matches!(ec, Some(ESR_EL1::EC::Value::Brk64))
+            && self.state.sw_breakpoint(frame.elr).is_none()
+        {
+            frame.elr = frame.elr.wrapping_add(4);
+            bti::clear_btype(&mut frame.spsr);
+        }
+
+        self.state.stop = self.state.stop(ec, far);
+        self.reply.clear();
+        self.state.stop_reply(&mut self.reply);
+        self.reply.send(&mut self.transport);
+
+        loop {
+            let len = packet::receive(&mut self.transport, &mut self.rx);
+
+            self.reply.clear();
+            if let Some(resume) = self.state.dispatch(&self.rx[..len], frame, &mut self.reply) {
+                if resume == Resume::Detached && self.rx[0] == b'D' {
+                    self.reply.send(&mut self.transport);
+                }
+
+                return resume;
+            }
+            self.reply.send(&mut self.transport);
+        }
+    }
+}
+
+impl<M: Target> State<M> {
+    /// Handles a packet. Returns `Some` if the stopped context shall be resumed.
+    fn dispatch(
+        &mut self,
+        packet: &[u8],
+        frame: &mut ExceptionFrame,
+        reply: &mut Reply,
+    ) -> Option<Resume> {
+        let (&command, args) = packet.split_first()?;
+
+        match command {
+            b'?' => self.stop_reply(reply),
+            b'g' => read_registers(frame, reply),
+            b'G' => write_registers(frame, args, reply),
+            b'p' => read_register(frame, args, reply),
+            b'P' => write_register(frame, args, reply),
+            b'm' => self.read_memory(args, reply),
+            b'M' => self.write_memory(args, reply),
+            b'c' | b's' => {
+                if !args.is_empty() {
+                    match packet::parse_hex(args) {
+                        Some(addr) => frame.elr = addr,
+                        None => {
+                            reply.error(EINVAL);
+                            return None;
+                        }
+                    }
+                }
+
+                if command == b's' {
+                    (self.arm_step)(&mut frame.spsr);
+                    self.stepping = true;
+                    return Some(Resume::Step);
+                }
+
+                self.stop_stepping(frame);
+                return Some(Resume::Continue);
+            }
+            b'Z' | b'z' => self.breakpoint(command == b'Z', args, reply),
+            b'D' | b'k' => {
+                self.remove_all();
+                self.stop_stepping(frame);
+                reply.ok();
+
+                return Some(Resume::Detached);
+            }
+            b'H' => reply.ok(),
+            b'q' => self.query(args, reply),
+            _ => (),
+        }
+
+        None
+    }
+
+    fn stop_stepping(&mut self, frame: &mut ExceptionFrame) {
+        if self.stepping {
+            (self.disarm_step)(&mut frame.spsr);
+            self.stepping = false;
+        }
+    }
+
+    /// Returns the stop reason of an exception with class `ec`.
+    fn stop(&self, ec: Option<ESR_EL1::EC::Value>, far: u64) -> Stop {
+        use ESR_EL1::EC::Value::*;
+
+        let signal = match ec {
+            Some(InstrAbortLowerEL)
+            | Some(InstrAbortCurrentEL)
+            | Some(DataAbortLowerEL)
+            | Some(DataAbortCurrentEL) => SIGSEGV,
+            Some(PCAlignmentFault) | Some(SPAlignmentFault) => SIGBUS,
+            Some(Unknown) | Some(IllegalExecutionState) => SIGILL,
+            _ => SIGTRAP,
+        };
+
+        let watch = match ec {
+            Some(WatchpointLowerEL) | Some(WatchpointCurrentEL) => {
+                let kind = self
+                    .hw_watchpoints
+                    .iter()
+                    .flatten()
+                    .find(|w| far.wrapping_sub(w.addr) < w.len)
+                    .map(|w| w.kind);
+
+                let reason = match kind {
+                    Some(WatchKind::Load) => "rwatch",
+                    Some(WatchKind::LoadStore) => "awatch",
+                    _ => "watch",
+                };
+
+                Some((reason, far))
+            }
+            _ => None,
+        };
+
+        Stop { signal, watch }
+    }
+
+    /// Appends the stop reply for the last stop.
+    fn stop_reply(&self, reply: &mut Reply) {
+        reply.byte(b'T');
+        reply.hex_byte(self.stop.signal);
+
+        if let Some((reason, addr)) = self.stop.watch {
+            reply.str(reason);
+            reply.byte(b':');
+            reply.hex_number(addr);
+            reply.byte(b';');
+        }
+    }
+
+    fn query(&mut self, args: &[u8], reply: &mut Reply) {
+        if args.starts_with(b"Supported") {
+            reply.str("PacketSize=");
+            reply.hex_number(BUFFER_SIZE as u64);
+            reply.str(";qXfer:features:read+");
+        } else if args == b"Attached" {
+            reply.byte(b'1');
+        } else if let Some(annex) = strip_prefix(args, b"Xfer:features:read:") {
+            match packet::split(annex, b':') {
+                Some((b"target.xml", range)) => {
+                    let range = packet::split(range, b',').and_then(|(offset, len)| {
+                        Some((packet::parse_hex(offset)?, packet::parse_hex(len)?))
+                    });
+
+                    match range {
+                        Some((offset, len)) => transfer(TARGET_XML.as_bytes(), offset, len, reply),
+                        None => reply.error(EINVAL),
+                    }
+                }
+                _ => reply.error(0),
+            }
+        }
+    }
+
+    /// Handles `m addr,length`.
+    fn read_memory(&mut self, args: &[u8], reply: &mut Reply) {
+        let (addr, len) = match parse_addr_len(args) {
+            Some(args) => args,
+            None => return reply.error(EINVAL),
+        };
+
+        // Two hex digits per byte, whatever does not fit is requested again by GDB.
+        let len = len.min(reply.remaining() as u64 / 2);
+        let mut buf = [0; 64];
+        let mut done = 0;
+
+        while done < len {
+            let chunk = &mut buf[..(len - done).min(64) as usize];
+
+            if self
+                .target
+                .read_memory(addr.wrapping_add(done), chunk)
+                .is_err()
+            {
+                if done == 0 {
+                    reply.error(EFAULT);
+                }
+                return;
+            }
+
+            for &b in chunk.iter() {
+                reply.hex_byte(b);
+            }
+            done += chunk.len() as u64;
+        }
+    }
+
+    /// Handles `M addr,length:XX...`.
+    fn write_memory(&mut self, args: &[u8], reply: &mut Reply) {
+        let parsed = packet::split(args, b':')
+            .and_then(|(range, data)| Some((parse_addr_len(range)?, data)));
+
+        let ((addr, len), data) = match parsed {
+            Some(parsed) => parsed,
+            None => return reply.error(EINVAL),
+        };
+        if data.len() as u64 / 2 != len || data.len() % 2 != 0 {
+            return reply.error(EINVAL);
+        }
+
+        let mut buf = [0; 64];
+        for (i, hex) in data.chunks(128).enumerate() {
+            let chunk = &mut buf[..hex.len() / 2];
+
+            if packet::decode_hex(hex, chunk).is_none() {
+                return reply.error(EINVAL);
+            }
+            if self
+                .target
+                .write_memory(addr.wrapping_add(i as u64 * 64), chunk)
+                .is_err()
+            {
+                return reply.error(EFAULT);
+            }
+        }
+
+        self.target.sync_instruction_cache(addr, len as usize);
+        reply.ok();
+    }
+
+    /// Handles `Z type,addr,kind` and `z type,addr,kind`.
+    fn breakpoint(&mut self, insert: bool, args: &[u8], reply: &mut Reply) {
+        let parsed =
+            packet::split(args, b',').and_then(|(kind, rest)| Some((kind, parse_addr_len(rest)?)));
+
+        let (kind, (addr, len)) = match parsed {
+            Some(parsed) => parsed,
+            None => return reply.error(EINVAL),
+        };
+
+        let result = match (kind, insert) {
+            (b"0", true) => self.insert_sw_breakpoint(addr),
+            (b"0", false) => self.remove_sw_breakpoint(addr),
+            _ if self.debug.is_none() => return,
+            (b"1", true) => self.insert_hw_breakpoint(addr),
+            (b"1", false) => self.remove_hw_breakpoint(addr),
+            (b"2", true) => self.insert_watchpoint(addr, len, WatchKind::Store),
+            (b"3", true) => self.insert_watchpoint(addr, len, WatchKind::Load),
+            (b"4", true) => self.insert_watchpoint(addr, len, WatchKind::LoadStore),
+            (b"2", false) => self.remove_watchpoint(addr, len, WatchKind::Store),
+            (b"3", false) => self.remove_watchpoint(addr, len, WatchKind::Load),
+            (b"4", false) => self.remove_watchpoint(addr, len, WatchKind::LoadStore),
+            _ => return,
+        };
+
+        match result {
+            Ok(()) => reply.ok(),
+            Err(errno) => reply.error(errno),
+        }
+    }
+
+    fn sw_breakpoint(&self, addr: u64) -> Option<usize> {
+        self.sw_breakpoints
+            .iter()
+            .position(|bp| bp.map(|bp| bp.addr) == Some(addr))
+    }
+
+    fn insert_sw_breakpoint(&mut self, addr: u64) -> Result<(), u8> {
+        if self.sw_breakpoint(addr).is_some() {
+            return Ok(());
+        }
+
+        let slot = self
+            .sw_breakpoints
+            .iter()
+            .position(Option::is_none)
+            .ok_or(ENOSPC)?;
+
+        let mut insn = [0; 4];
+        self.target
+            .read_memory(addr, &mut insn)
+            .and_then(|_| self.target.write_memory(addr, &BRK.to_le_bytes()))
+            .map_err(|_| EFAULT)?;
+        self.target.sync_instruction_cache(addr, 4);
+
+        self.sw_breakpoints[slot] = Some(SwBreakpoint {
+            addr,
+            insn: u32::from_le_bytes(insn),
+        });
+
+        Ok(())
+    }
+
+    fn remove_sw_breakpoint(&mut self, addr: u64) -> Result<(), u8> {
+        let slot = self.sw_breakpoint(addr).ok_or(EINVAL)?;
+        let bp = self.sw_breakpoints[slot].take().unwrap();
+
+        self.target
+            .write_memory(bp.addr, &bp.insn.to_le_bytes())
+            .map_err(|_| EFAULT)?;
+        self.target.sync_instruction_cache(bp.addr, 4);
+
+        Ok(())
+    }
+
+    fn insert_hw_breakpoint(&mut self, addr: u64) -> Result<(), u8> {
+        let slot = self
+            .hw_breakpoints
+            .iter()
+            .position(Option::is_none)
+            .ok_or(ENOSPC)?;
+
+        let debug = self.debug.as_mut().unwrap();
+        let bp = debug.add_breakpoint(addr, self.el).map_err(|_| ENOSPC)?;
+        self.hw_breakpoints[slot] = Some((addr, bp));
+
+        Ok(())
+    }
+
+    fn remove_hw_breakpoint(&mut self, addr: u64) -> Result<(), u8> {
+        let slot = self
+            .hw_breakpoints
+            .iter()
+            .position(|bp| matches!(bp, Some((a, _)) if *a == addr))
+            .ok_or(EINVAL)?;
+
+        let (_, bp) = self.hw_breakpoints[slot].take().unwrap();
+        self.debug
+            .as_mut()
+            .unwrap()
+            .remove_breakpoint(bp)
+            .map_err(|_| EINVAL)
+    }
+
+    fn insert_watchpoint(&mut self, addr: u64, len: u64, kind: WatchKind) -> Result<(), u8> {
+        let slot = self
+            .hw_watchpoints
+            .iter()
+            .position(Option::is_none)
+            .ok_or(ENOSPC)?;
+
+        let debug = self.debug.as_mut().unwrap();
+        let wp = debug
+            .add_watchpoint(addr, len, kind, self.el)
+            .map_err(|_| EINVAL)?;
+        self.hw_watchpoints[slot] = Some(HwWatchpoint {
+            addr,
+            len,
+            kind,
+            wp,
+        });
+
+        Ok(())
+    }
+
+    fn remove_watchpoint(&mut self, addr: u64, len: u64, kind: WatchKind) -> Result<(), u8> {
+        let slot = self
+            .hw_watchpoints
+            .iter()
+            .position(|w| matches!(w, Some(w) if w.addr == addr && w.len == len && w.kind == kind))
+            .ok_or(EINVAL)?;
+
+        let w = self.hw_watchpoints[slot].take().unwrap();
+        self.debug
+            .as_mut()
+            .unwrap()
+            .remove_watchpoint(w.wp)
+            .map_err(|_| EINVAL)
+    }
+
+    /// Removes all breakpoints and watchpoints, ignoring failures.
+    fn remove_all(&mut self) {
+        for i in 0..MAX_SW_BREAKPOINTS {
+            if let Some(bp) = self.sw_breakpoints[i] {
+                let _ = self.remove_sw_breakpoint(bp.addr);
+            }
+        }
+
+        if let Some(debug) = self.debug.as_mut() {
+            for (_, bp) in self.hw_breakpoints.iter_mut().filter_map(Option::take) {
+                let _ = debug.remove_breakpoint(bp);
+            }
+            for w in self.hw_watchpoints.iter_mut().filter_map(Option::take) {
+                let _ = debug.remove_watchpoint(w.wp);
+            }
+        }
+    }
+}
+
+fn strip_prefix<'a>(s: &'a [u8], prefix: &[u8]) -> Option<&'a [u8]> {
+    if s.starts_with(prefix) {
+        Some(&s[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+/// Parses `addr,length`.
+fn parse_addr_len(s: &[u8]) -> Option<(u64, u64)> {
+    let (addr, len) = packet::split(s, b',')?;
+
+    Some((packet::parse_hex(addr)?, packet::parse_hex(len)?))
+}
+
+/// Appends the `qXfer` reply for `len` bytes of `data` at `offset`.
+fn transfer(data: &[u8], offset: u64, len: u64, reply: &mut Reply) {
+    let start = (offset as usize).min(data.len());
+    let len = (len as usize).min(reply.remaining() - 1);
+    let end = start.saturating_add(len).min(data.len());
+
+    reply.byte(if end == data.len() { b'l' } else { b'm' });
+    reply.bytes(&data[start..end]);
+}
+
+/// Returns the value and size in bytes of register `n`, or `None` if it does not exist.
+fn register(frame: &ExceptionFrame, n: usize) -> Option<(u64, usize)> {
+    match n {
+        0..=30 => Some((frame.gpr[n], 8)),
+        REG_SP => Some((frame.sp, 8)),
+        REG_PC => Some((frame.elr, 8)),
+        REG_CPSR => Some((frame.spsr, 4)),
+        _ => SYSTEM_REGISTERS
+            .get(n - REG_SYSTEM)
+            .map(|reg| ((reg.read)(), 8)),
+    }
+}
+
+/// Handles `g`, which covers the core registers. GDB reads the system registers with `p`.
+fn read_registers(frame: &ExceptionFrame, reply: &mut Reply) {
+    for n in 0..REG_SYSTEM {
+        let (value, bytes) = register(frame, n).unwrap();
+        reply.register(value, bytes);
+    }
+}
+
+/// Handles `G XX...`.
+fn write_registers(frame: &mut ExceptionFrame, mut args: &[u8], reply: &mut Reply) {
+    let mut regs = *frame;
+
+    for n in 0..REG_SYSTEM {
+        let (_, bytes) = register(frame, n).unwrap();
+        if args.len() < bytes * 2 {
+            break;
+        }
+
+        let value = match packet::decode_register(&args[..bytes * 2], bytes) {
+            Some(value) => value,
+            None => return reply.error(EINVAL),
+        };
+        set_core_register(&mut regs, n, value);
+        args = &args[bytes * 2..];
+    }
+
+    *frame = regs;
+    reply.ok();
+}
+
+/// Handles `p n`.
+fn read_register(frame: &ExceptionFrame, args: &[u8], reply: &mut Reply) {
+    match packet::parse_hex(args).and_then(|n| register(frame, n as usize)) {
+        Some((value, bytes)) => reply.register(value, bytes),
+        None => reply.error(EINVAL),
+    }
+}
+
+/// Handles `P n=XX...`.
+fn write_register(frame: &mut ExceptionFrame, args: &[u8], reply: &mut Reply) {
+    let parsed = packet::split(args, b'=').and_then(|(n, value)| {
+        let n = packet::parse_hex(n)? as usize;
+        let (_, bytes) = register(frame, n)?;
+
+        Some((n, packet::decode_register(value, bytes)?))
+    });
+
+    let (n, value) = match parsed {
+        Some(parsed) => parsed,
+        None => return reply.error(EINVAL),
+    };
+
+    if n < REG_SYSTEM {
+        set_core_register(frame, n, value);
+    } else {
+        match SYSTEM_REGISTERS[n - REG_SYSTEM].write {
+            Some(write) => write(value),
+            None => return reply.error(EINVAL),
+        }
+    }
+
+    reply.ok();
+}
+
+fn set_core_register(frame: &mut ExceptionFrame, n: usize, value: u64) {
+    match n {
+        0..=30 => frame.gpr[n] = value,
+        REG_SP => frame.sp = value,
+        REG_PC => frame.elr = value,
+        REG_CPSR => frame.spsr = (frame.spsr & !0xFFFF_FFFF) | value,
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::{collections::VecDeque, format, string::String, vec::Vec};
+
+    /// A transport replaying a scripted GDB session and recording everything written.
+    pub struct Script {
+        pub input: VecDeque<u8>,
+        pub output: Vec<u8>,
+    }
+
+    impl Script {
+        pub fn new(input: &[u8]) -> Script {
+            Script {
+                input: input.iter().copied().collect(),
+                output: Vec::new(),
+            }
+        }
+    }
+
+    impl Transport for Script {
+        fn read_byte(&mut self) -> u8 {
+            self.input.pop_front().expect("script exhausted")
+        }
+
+        fn write_byte(&mut self, byte: u8) {
+            self.output.push(byte);
+        }
+    }
+
+    const BASE: u64 = 0x1000;
+
+    /// 64 bytes of memory at `BASE`, anything else faults.
+    struct Memory {
+        data: [u8; 64],
+        synced: usize,
+    }
+
+    impl Memory {
+        fn range(&self, addr: u64, len: usize) -> Result<core::ops::Range<usize>, MemoryFault> {
+            let start = addr.checked_sub(BASE).ok_or(MemoryFault)? as usize;
+            let end = start.checked_add(len).ok_or(MemoryFault)?;
+
+            if end > self.data.len() {
+                return Err(MemoryFault);
+            }
+            Ok(start..end)
+        }
+    }
+
+    impl Target for Memory {
+        fn read_memory(&mut self, addr: u64, buf: &mut [u8]) -> Result<(), MemoryFault> {
+            let range = self.range(addr, buf.len())?;
+            buf.copy_from_slice(&self.data[range]);
+
+            Ok(())
+        }
+
+        fn write_memory(&mut self, addr: u64, data: &[u8]) -> Result<(), MemoryFault> {
+            let range = self.range(addr, data.len())?;
+            self.data[range].copy_from_slice(data);
+
+            Ok(())
+        }
+
+        fn sync_instruction_cache(&mut self, _addr: u64, _len: usize) {
+            self.synced += 1;
+        }
+    }
+
+    /// `PSTATE.SS`, toggled by the stand-ins for `step::arm` and `step::disarm`.
+    const SS: u64 = 1 << 21;
+
+    fn stub() -> GdbStub<Script, Memory> {
+        let memory = Memory {
+            data: [0; 64],
+            synced: 0,
+        };
+        let mut stub = GdbStub::new(Script::new(b""), memory);
+        stub.state.arm_step = |spsr| *spsr |= SS;
+        stub.state.disarm_step = |spsr| *spsr &= !SS;
+
+        stub
+    }
+
+    fn frame() -> ExceptionFrame {
+        let mut frame = ExceptionFrame::default();
+        for (n, gpr) in frame.gpr.iter_mut().enumerate() {
+            *gpr = 0x0101_0101_0101_0101 * n as u64;
+        }
+        frame.sp = 0xFFFF_0000_0000_8000;
+        frame.elr = BASE + 0x10;
+        frame.spsr = 0x3C5;
+
+        frame
+    }
+
+    /// `ESR_ELx` of a `BRK #0`.
+    const ESR_BRK: u32 = 0b11_1100 << 26 | 1 << 25;
+
+    /// `ESR_ELx` of a data abort.
+    const ESR_DABT: u32 = 0b10_0101 << 26 | 1 << 25;
+
+    /// `ESR_ELx` of a watchpoint hit.
+    const ESR_WATCH: u32 = 0b11_0101 << 26 | 1 << 25;
+
+    fn packet(data: &[u8]) -> Vec<u8> {
+        let sum = data.iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+
+        let mut packet = Vec::new();
+        packet.push(b'$');
+        packet.extend_from_slice(data);
+        packet.extend_from_slice(format!("#{:02x}", sum).as_bytes());
+
+        packet
+    }
+
+    /// Runs an exception through the stub, with GDB acknowledging every reply and sending
+    /// `commands`. Returns how the context is resumed and the replies sent by the stub.
+    fn session(
+        stub: &mut GdbStub<Script, Memory>,
+        frame: &mut ExceptionFrame,
+        esr: u32,
+        far: u64,
+        commands: &[&[u8]],
+    ) -> (Resume, Vec<String>) {
+        let mut input = Vec::new();
+        input.push(b'+');
+        for command in commands {
+            input.extend_from_slice(&packet(command));
+            input.push(b'+');
+        }
+        stub.transport = Script::new(&input);
+
+        let resume = stub.handle_exception(frame, esr, far);
+        (resume, replies(&stub.transport.output))
+    }
+
+    /// Extracts the data of the packets in `output`, checking their checksums.
+    fn replies(output: &[u8]) -> Vec<String> {
+        let mut replies = Vec::new();
+        let mut rest = output;
+
+        while let Some(start) = rest.iter().position(|&b| b == b'$') {
+            let end = start + rest[start..].iter().position(|&b| b == b'#').unwrap();
+            let data = &rest[start + 1..end];
+            let sum = data.iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+
+            assert_eq!(&rest[end + 1..end + 3], format!("{:02x}", sum).as_bytes());
+            replies.push(String::from_utf8(data.to_vec()).unwrap());
+            rest = &rest[end + 3..];
+        }
+
+        replies
+    }
+
+    fn hex(value: u64, bytes: usize) -> String {
+        value.to_le_bytes()[..bytes]
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    #[test]
+    fn stop_reply() {
+        let mut stub = stub();
+        let mut frame = frame();
+
+        let (resume, replies) = session(&mut stub, &mut frame, ESR_BRK, 0, &[b"?", b"c"]);
+        assert_eq!(resume, Resume::Continue);
+        assert_eq!(replies, ["T05", "T05"]);
+
+        // A `BRK` that is not a breakpoint of the stub is skipped.
+        assert_eq!(frame.elr, BASE + 0x14);
+
+        let (_, replies) = session(&mut stub, &mut frame, ESR_DABT, 0x2000, &[b"?", b"c"]);
+        assert_eq!(replies, ["T0b", "T0b"]);
+        assert_eq!(frame.elr, BASE + 0x14);
+
+        let (_, replies) = session(&mut stub, &mut frame, ESR_WATCH, 0x2008, &[b"?", b"c"]);
+        assert_eq!(replies, ["T05watch:2008;", "T05watch:2008;"]);
+    }
+
+    #[test]
+    fn read_registers() {
+        let mut stub = stub();
+        let mut frame = frame();
+
+        let (_, replies) = session(&mut stub, &mut frame, ESR_BRK, 0, &[b"g", b"p1f", b"c"]);
+
+        let mut expected: String = frame.gpr.iter().map(|&gpr| hex(gpr, 8)).collect();
+        expected += &hex(frame.sp, 8);
+        expected += &hex(frame.elr, 8);
+        expected += &hex(frame.spsr, 4);
+        assert_eq!(replies[1], expected);
+        assert_eq!(replies[2], hex(frame.sp, 8));
+    }
+
+    #[test]
+    fn write_registers() {
+        let mut stub = stub();
+        let mut frame = frame();
+
+        let mut g = String::from("G");
+        for n in 0..31 {
+            g += &hex(0x1000 + n, 8);
+        }
+        g += &hex(0x8000, 8);
+        g += &hex(0x2000, 8);
+        g += &hex(0x3C4, 4);
+        let p = format!("P1f={}", hex(0x9000, 8));
+
+        let (_, replies) = session(
+            &mut stub,
+            &mut frame,
+            ESR_BRK,
+            0,
+            &[g.as_bytes(), p.as_bytes(), b"c"],
+        );
+        assert_eq!(replies[1..], ["OK", "OK"]);
+
+        for (n, &gpr) in frame.gpr.iter().enumerate() {
+            assert_eq!(gpr, 0x1000 + n as u64);
+        }
+        assert_eq!(frame.sp, 0x9000);
+        assert_eq!(frame.elr, 0x2000);
+        assert_eq!(frame.spsr, 0x3C4);
+    }
+
+    #[test]
+    fn memory() {
+        let mut stub = stub();
+        let mut frame = frame();
+
+        let (_, replies) = session(
+            &mut stub,
+            &mut frame,
+            ESR_BRK,
+            0,
+            &[
+                b"M1002,4:deadbeef",
+                b"m1000,8",
+                b"m2000,4",
+                b"M2000,1:00",
+                b"c",
+            ],
+        );
+        assert_eq!(replies[1..], ["OK", "0000deadbeef0000", "E0e", "E0e"]);
+        assert_eq!(stub.target().data[2..6], [0xDE, 0xAD, 0xBE, 0xEF]);
+        assert_eq!(stub.target().synced, 1);
+    }
+
+    #[test]
+    fn sw_breakpoints() {
+        let mut stub = stub();
+        let mut frame = frame();
+        stub.target().data[0x10..0x14].copy_from_slice(&0xD503_201Fu32.to_le_bytes());
+
+        let (_, replies) = session(&mut stub, &mut frame, ESR_BRK, 0, &[b"Z0,1010,4", b"c"]);
+        assert_eq!(replies[1..], ["OK"]);
+        assert_eq!(stub.target().data[0x10..0x14], BRK.to_le_bytes());
+
+        // The breakpoint is hit, the stopped context must execute the original instruction.
+        frame.elr = BASE + 0x10;
+        let (_, replies) = session(
+            &mut stub,
+            &mut frame,
+            ESR_BRK,
+            0,
+            &[b"z0,1010,4", b"z0,1010,4", b"Z0,2000,4", b"c"],
+        );
+        assert_eq!(replies[1..], ["OK", "E16", "E0e"]);
+        assert_eq!(frame.elr, BASE + 0x10);
+        assert_eq!(stub.target().data[0x10..0x14], 0xD503_201Fu32.to_le_bytes());
+        assert_eq!(stub.target().synced, 2);
+    }
+
+    #[test]
+    fn detach_removes_breakpoints() {
+        let mut stub = stub();
+        let mut frame = frame();
+
+        let (resume, replies) = session(&mut stub, &mut frame, ESR_BRK, 0, &[b"Z0,1000,4", b"D"]);
+        assert_eq!(resume, Resume::Detached);
+        assert_eq!(replies[1..], ["OK", "OK"]);
+        assert_eq!(stub.target().data[..4], [0; 4]);
+    }
+
+    #[test]
+    fn resume() {
+        let mut stub = stub();
+        let mut frame = frame();
+
+        let (resume, replies) = session(&mut stub, &mut frame, ESR_BRK, 0, &[b"szz", b"s"]);
+        assert_eq!(resume, Resume::Step);
+        assert_eq!(replies[1..], ["E16"]);
+        assert_eq!(frame.elr, BASE + 0x14);
+        assert_eq!(frame.spsr & SS, SS);
+
+        let (resume, _) = session(&mut stub, &mut frame, ESR_BRK, 0, &[b"s1020"]);
+        assert_eq!(resume, Resume::Step);
+        assert_eq!(frame.elr, BASE + 0x20);
+
+        let (resume, _) = session(&mut stub, &mut frame, ESR_BRK, 0, &[b"c1030"]);
+        assert_eq!(resume, Resume::Continue);
+        assert_eq!(frame.elr, BASE + 0x30);
+        assert_eq!(frame.spsr & SS, 0);
+    }
+
+    #[test]
+    fn malformed_packets() {
+        let mut stub = stub();
+        let mut frame = frame();
+        let before = frame;
+
+        let (resume, replies) = session(
+            &mut stub,
+            &mut frame,
+            ESR_BRK,
+            0,
+            &[
+                b"",
+                b"m",
+                b"mzz,1",
+                b"m1000",
+                b"M1000,2:zz",
+                b"M1000,2:00",
+                b"Gzzzzzzzzzzzzzzzz",
+                b"p",
+                b"p30",
+                b"P1f=zz",
+                b"Z0,zz",
+                b"Z9,1000,4",
+                b"qXfer:features:read:target.xml:zz",
+                b"X",
+                b"c",
+            ],
+        );
+        assert_eq!(resume, Resume::Continue);
+        assert_eq!(
+            replies[1..],
+            [
+                "", "E16", "E16", "E16", "E16", "E16", "E16", "E16", "E16", "E16", "E16", "",
+                "E16", ""
+            ]
+        );
+
+        assert_eq!(frame.gpr, before.gpr);
+        assert_eq!(frame.sp, before.sp);
+        assert_eq!(stub.target().data, [0; 64]);
+    }
+
+    #[test]
+    fn rejected_packets_are_resent() {
+        let mut stub = stub();
+        let mut frame = frame();
+
+        let mut input = Vec::new();
+        input.push(b'+');
+        input.extend_from_slice(b"$g#00");
+        input.push(b'$');
+        input.extend_from_slice(&[b'g'; BUFFER_SIZE + 1]);
+        input.extend_from_slice(b"#00");
+        input.extend_from_slice(&packet(b"c"));
+        stub.transport = Script::new(&input);
+
+        assert_eq!(
+            stub.handle_exception(&mut frame, ESR_BRK, 0),
+            Resume::Continue
+        );
+        assert_eq!(
+            stub.transport.output.iter().filter(|&&b| b == b'-').count(),
+            2
+        );
+        assert_eq!(replies(&stub.transport.output), ["T05"]);
+    }
+
+    #[test]
+    fn target_description() {
+        let mut stub = stub();
+        let mut frame = frame();
+
+        let (_, replies) = session(
+            &mut stub,
+            &mut frame,
+            ESR_BRK,
+            0,
+            &[
+                b"qSupported:xmlRegisters=aarch64",
+                b"qXfer:features:read:target.xml:0,10",
+                b"c",
+            ],
+        );
+        assert_eq!(replies[1], "PacketSize=1000;qXfer:features:read+");
+        assert_eq!(replies[2], format!("m{}", &TARGET_XML[..0x10]));
+    }
+}
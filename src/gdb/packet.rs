@@ -0,0 +1,354 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Packet framing and hex encoding of the remote serial protocol
+
+use super::Transport;
+
+/// Size of the packet buffers, advertised to GDB as `PacketSize`.
+pub const BUFFER_SIZE: usize = 4096;
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+/// Prefix of an escaped byte, which is sent XORed with 0x20.
+const ESCAPE: u8 = b'}';
+
+/// Returns whether `b` must be escaped in packet data. `*` would start a run-length encoding.
+fn needs_escape(b: u8) -> bool {
+    matches!(b, b'$' | b'#' | b'}' | b'*')
+}
+
+fn hex_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Parses a big-endian hex number, as used for addresses and lengths.
+pub fn parse_hex(s: &[u8]) -> Option<u64> {
+    if s.is_empty() || s.len() > 16 {
+        return None;
+    }
+
+    s.iter()
+        .try_fold(0, |acc, &c| Some(acc << 4 | hex_value(c)? as u64))
+}
+
+/// Decodes pairs of hex digits into `out`, which must be half as long as `s`.
+pub fn decode_hex(s: &[u8], out: &mut [u8]) -> Option<()> {
+    if s.len() != out.len() * 2 {
+        return None;
+    }
+
+    for (pair, byte) in s.chunks(2).zip(out.iter_mut()) {
+        *byte = hex_value(pair[0])? << 4 | hex_value(pair[1])?;
+    }
+
+    Some(())
+}
+
+/// Decodes a little-endian register value of `bytes` bytes.
+pub fn decode_register(s: &[u8], bytes: usize) -> Option<u64> {
+    let mut buf = [0; 8];
+    decode_hex(s, &mut buf[..bytes])?;
+
+    Some(u64::from_le_bytes(buf))
+}
+
+/// Splits `s` at the first occurrence of `sep`.
+pub fn split(s: &[u8], sep: u8) -> Option<(&[u8], &[u8])> {
+    let pos = s.iter().position(|&c| c == sep)?;
+
+    Some((&s[..pos], &s[pos + 1..]))
+}
+
+/// A reply under construction.
+pub struct Reply {
+    buf: [u8; BUFFER_SIZE],
+    len: usize,
+}
+
+impl Reply {
+    pub const fn new() -> Reply {
+        Reply {
+            buf: [0; BUFFER_SIZE],
+            len: 0,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Appends a byte. Callers size their replies to fit, excess bytes are dropped.
+    pub fn byte(&mut self, b: u8) {
+        if self.len < BUFFER_SIZE {
+            self.buf[self.len] = b;
+            self.len += 1;
+        }
+    }
+
+    pub fn bytes(&mut self, s: &[u8]) {
+        for &b in s {
+            self.byte(b);
+        }
+    }
+
+    pub fn str(&mut self, s: &str) {
+        self.bytes(s.as_bytes());
+    }
+
+    pub fn hex_byte(&mut self, b: u8) {
+        self.byte(HEX[(b >> 4) as usize]);
+        self.byte(HEX[(b & 0xF) as usize]);
+    }
+
+    /// Appends a number as big-endian hex without leading zeros.
+    pub fn hex_number(&mut self, value: u64) {
+        let shifts = (0..64).step_by(4).rev();
+
+        for shift in shifts.skip_while(|&shift| shift > 0 && value >> shift == 0) {
+            self.byte(HEX[(value >> shift) as usize & 0xF]);
+        }
+    }
+
+    /// Appends the low `bytes` bytes of a register value in target (little-endian) byte order.
+    pub fn register(&mut self, value: u64, bytes: usize) {
+        for &b in &value.to_le_bytes()[..bytes] {
+            self.hex_byte(b);
+        }
+    }
+
+    pub fn ok(&mut self) {
+        self.str("OK");
+    }
+
+    pub fn error(&mut self, code: u8) {
+        self.byte(b'E');
+        self.hex_byte(code);
+    }
+
+    /// Returns the number of bytes that can still be appended.
+    pub fn remaining(&self) -> usize {
+        BUFFER_SIZE - self.len
+    }
+
+    /// Frames and sends the reply, repeating it until GDB acknowledges it.
+    ///
+    /// Bytes that cannot appear in packet data are escaped, the checksum covers the escaped data.
+    pub fn send<T: Transport>(&self, transport: &mut T) {
+        loop {
+            let mut checksum = 0u8;
+
+            transport.write_byte(b'$');
+            for &b in &self.buf[..self.len] {
+                if needs_escape(b) {
+                    transport.write_byte(ESCAPE);
+                    transport.write_byte(b ^ 0x20);
+                    checksum = checksum.wrapping_add(ESCAPE).wrapping_add(b ^ 0x20);
+                } else {
+                    transport.write_byte(b);
+                    checksum = checksum.wrapping_add(b);
+                }
+            }
+            transport.write_byte(b'#');
+            transport.write_byte(HEX[(checksum >> 4) as usize]);
+            transport.write_byte(HEX[(checksum & 0xF) as usize]);
+            transport.flush();
+
+            if transport.read_byte() != b'-' {
+                return;
+            }
+        }
+    }
+}
+
+/// Receives the next packet into `buf`, acknowledging it, and returns its length.
+///
+/// Bytes outside of packets, such as stray acknowledgements, are skipped. Escaped bytes are
+/// decoded. Packets with a bad checksum, a dangling escape or that do not fit into `buf` are
+/// rejected and GDB is asked to resend them.
+pub fn receive<T: Transport>(transport: &mut T, buf: &mut [u8]) -> usize {
+    loop {
+        while transport.read_byte() != b'$' {}
+
+        let mut len = 0;
+        let mut sum = 0u8;
+        let mut escaped = false;
+        let mut malformed = false;
+
+        loop {
+            let mut b = transport.read_byte();
+            if b == b'#' {
+                break;
+            }
+
+            sum = sum.wrapping_add(b);
+            if escaped {
+                b ^= 0x20;
+                escaped = false;
+            } else if b == ESCAPE {
+                escaped = true;
+                continue;
+            }
+
+            if len < buf.len() {
+                buf[len] = b;
+                len += 1;
+            } else {
+                malformed = true;
+            }
+        }
+
+        let hi = hex_value(transport.read_byte());
+        let lo = hex_value(transport.read_byte());
+
+        match (hi, lo) {
+            (Some(hi), Some(lo)) if !malformed && !escaped && hi << 4 | lo == sum => {
+                transport.write_byte(b'+');
+                transport.flush();
+
+                return len;
+            }
+            _ => {
+                transport.write_byte(b'-');
+                transport.flush();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::gdb::tests::Script;
+
+    extern crate std;
+    use std::vec::Vec;
+
+    fn reply(s: &[u8]) -> Reply {
+        let mut reply = Reply::new();
+        reply.bytes(s);
+        reply
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(parse_hex(b"dEaD"), Some(0xdead));
+        assert_eq!(parse_hex(b"ffffffffffffffff"), Some(u64::MAX));
+        assert_eq!(parse_hex(b"10000000000000000"), None);
+        assert_eq!(parse_hex(b""), None);
+        assert_eq!(parse_hex(b"12g"), None);
+
+        let mut out = [0; 2];
+        assert_eq!(decode_hex(b"a55a", &mut out), Some(()));
+        assert_eq!(out, [0xa5, 0x5a]);
+        assert_eq!(decode_hex(b"a55", &mut out), None);
+        assert_eq!(decode_hex(b"a5x5", &mut out), None);
+
+        assert_eq!(decode_register(b"78563412", 4), Some(0x1234_5678));
+        assert_eq!(decode_register(b"785634", 4), None);
+    }
+
+    #[test]
+    fn reply_encoding() {
+        let mut reply = Reply::new();
+        reply.hex_number(0);
+        reply.byte(b',');
+        reply.hex_number(0x1000);
+        reply.byte(b',');
+        reply.register(0x1122_3344, 4);
+        reply.byte(b',');
+        reply.error(22);
+
+        assert_eq!(&reply.buf[..reply.len], b"0,1000,44332211,E16");
+    }
+
+    #[test]
+    fn send_checksum() {
+        let mut script = Script::new(b"+");
+        reply(b"OK").send(&mut script);
+
+        assert_eq!(script.output, b"$OK#9a");
+    }
+
+    #[test]
+    fn send_repeats_on_nack() {
+        let mut script = Script::new(b"-+");
+        reply(b"OK").send(&mut script);
+
+        assert_eq!(script.output, b"$OK#9a$OK#9a");
+    }
+
+    #[test]
+    fn send_escapes() {
+        let mut script = Script::new(b"+");
+        reply(b"a}b#c$d*").send(&mut script);
+
+        // The checksum covers the escaped bytes.
+        let data = b"a}]b}\x03c}\x04d}\x0a";
+        let sum = data.iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+        let mut expected = Vec::new();
+        expected.push(b'$');
+        expected.extend_from_slice(data);
+        expected.extend_from_slice(std::format!("#{:02x}", sum).as_bytes());
+
+        assert_eq!(script.output, expected);
+    }
+
+    #[test]
+    fn receive_acks() {
+        let mut script = Script::new(b"+$g#67");
+        let mut buf = [0; 16];
+
+        assert_eq!(receive(&mut script, &mut buf), 1);
+        assert_eq!(&buf[..1], b"g");
+        assert_eq!(script.output, b"+");
+    }
+
+    #[test]
+    fn receive_nacks_bad_checksum() {
+        let mut script = Script::new(b"$g#00$g#zz$g#67");
+        let mut buf = [0; 16];
+
+        assert_eq!(receive(&mut script, &mut buf), 1);
+        assert_eq!(script.output, b"--+");
+    }
+
+    #[test]
+    fn receive_unescapes() {
+        // `X` with the escaped bytes `}` and `#`.
+        let data = b"X}]}\x03";
+        let sum = data.iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+        let mut input = Vec::new();
+        input.push(b'$');
+        input.extend_from_slice(data);
+        input.extend_from_slice(std::format!("#{:02x}", sum).as_bytes());
+
+        let mut script = Script::new(&input);
+        let mut buf = [0; 16];
+
+        assert_eq!(receive(&mut script, &mut buf), 3);
+        assert_eq!(&buf[..3], b"X}#");
+        assert_eq!(script.output, b"+");
+    }
+
+    #[test]
+    fn receive_rejects_malformed() {
+        // A dangling escape, then a packet that does not fit, then a valid one.
+        let mut script = Script::new(b"$}#7d$ggggg#3b$?#3f");
+        let mut buf = [0; 4];
+
+        assert_eq!(receive(&mut script, &mut buf), 1);
+        assert_eq!(&buf[..1], b"?");
+        assert_eq!(script.output, b"--+");
+    }
+}
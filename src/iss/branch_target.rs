@@ -0,0 +1,37 @@
+use register::{cpu::RegisterReadOnly, register_bitfields};
+
+// ISS encoding for an exception from a Branch Target Identification instruction
+//
+// This encoding is used by:
+// - Branch Target Exception, EC 0b001101.
+register_bitfields! {u32,
+    pub ISS [
+        /// The value of PSTATE.BTYPE that caused the exception.
+        ///
+        /// 01 BR, BRAA, BRAB, BRAAZ or BRABZ through X16 or X17, or from a non-guarded page.
+        /// 10 BLR, BLRAA, BLRAB, BLRAAZ or BLRABZ.
+        /// 11 BR, BRAA, BRAB, BRAAZ or BRABZ through any other register from a guarded page.
+        BTYPE OFFSET(0) NUMBITS(2) [
+            JumpX16X17 = 0b01,
+            Call = 0b10,
+            Jump = 0b11
+        ]
+    ]
+}
+
+pub struct BranchTargetIss {
+    value: u32,
+}
+
+impl RegisterReadOnly<u32, ISS::Register> for BranchTargetIss {
+    #[inline(always)]
+    fn get(&self) -> u32 {
+        self.value
+    }
+}
+
+impl BranchTargetIss {
+    pub fn new(value: u32) -> BranchTargetIss {
+        BranchTargetIss { value }
+    }
+}
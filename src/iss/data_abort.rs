@@ -0,0 +1,156 @@
+use register::{cpu::RegisterReadOnly, register_bitfields};
+
+// ISS encoding for an exception from a Data Abort
+//
+// This encoding is used by:
+// - Data Abort taken from a lower Exception level, EC 0b100100.
+// - Data Abort taken without a change in Exception level, EC 0b100101.
+//
+// The FnV, EA, S1PTW and FSC fields are at the same positions in the ISS encoding for an exception
+// from an Instruction Abort, EC 0b100000 and 0b100001.
+register_bitfields! {u32,
+    pub ISS [
+        /// Instruction syndrome valid. Indicates whether the syndrome information in ISS[23:14] is
+        /// valid.
+        ///
+        /// 0 No valid instruction syndrome. ISS[23:14] are RES0.
+        /// 1 ISS[23:14] hold a valid instruction syndrome.
+        ///
+        /// Only set for Data Aborts on a stage 2 translation of a single general-purpose register
+        /// load or store without writeback.
+        ISV   OFFSET(24) NUMBITS(1) [
+            NotValid = 0,
+            Valid = 1
+        ],
+
+        /// Syndrome Access Size. When ISV is 1, indicates the size of the access attempted by the
+        /// faulting operation.
+        SAS   OFFSET(22) NUMBITS(2) [
+            Byte = 0b00,
+            Halfword = 0b01,
+            Word = 0b10,
+            DoubleWord = 0b11
+        ],
+
+        /// Syndrome Sign Extend. When ISV is 1, for a byte, halfword or word load operation,
+        /// indicates whether the data item must be sign extended.
+        SSE   OFFSET(21) NUMBITS(1) [],
+
+        /// Syndrome Register Transfer. When ISV is 1, the register number of the Wt/Xt/Rt operand
+        /// of the faulting instruction.
+        SRT   OFFSET(16) NUMBITS(5) [],
+
+        /// Sixty Four bit general-purpose register transfer. When ISV is 1, indicates whether the
+        /// instruction loads or stores a 64-bit general-purpose register.
+        SF    OFFSET(15) NUMBITS(1) [
+            Bits32 = 0,
+            Bits64 = 1
+        ],
+
+        /// Acquire/Release. When ISV is 1, indicates whether the instruction has acquire/release
+        /// semantics.
+        AR    OFFSET(14) NUMBITS(1) [],
+
+        /// Indicates that the fault came from use of VNCR_EL2 register by EL1 code.
+        ///
+        /// When ARMv8.4-NV is not implemented, this bit is RES0.
+        VNCR  OFFSET(13) NUMBITS(1) [],
+
+        /// Synchronous Error Type. When DFSC is 0b010000, describes the PE error state after
+        /// taking the Data Abort.
+        ///
+        /// When ARMv8.2-RAS is not implemented, this field is RES0.
+        SET   OFFSET(11) NUMBITS(2) [
+            Recoverable = 0b00,
+            Uncontainable = 0b10,
+            Restartable = 0b11
+        ],
+
+        /// FAR not Valid, for a synchronous External abort other than a synchronous External abort
+        /// on a translation table walk.
+        ///
+        /// 0 FAR is valid.
+        /// 1 FAR is not valid, and holds an UNKNOWN value.
+        FnV   OFFSET(10) NUMBITS(1) [
+            Valid = 0,
+            NotValid = 1
+        ],
+
+        /// External abort type. Provides an IMPLEMENTATION DEFINED classification of External
+        /// aborts.
+        EA    OFFSET(9) NUMBITS(1) [],
+
+        /// Cache maintenance. Indicates whether the Data Abort came from a cache maintenance or
+        /// address translation instruction.
+        CM    OFFSET(8) NUMBITS(1) [],
+
+        /// For a stage 2 fault, indicates whether the fault was a stage 2 fault on an access made
+        /// for a stage 1 translation table walk.
+        ///
+        /// 0 Fault not on a stage 2 translation for a stage 1 translation table walk.
+        /// 1 Fault on the stage 2 translation of an access for a stage 1 translation table walk.
+        ///
+        /// For any abort other than a stage 2 fault this bit is RES0.
+        S1PTW OFFSET(7) NUMBITS(1) [],
+
+        /// Write not Read. Indicates whether a synchronous abort was caused by an instruction
+        /// writing to a memory location, or by an instruction reading from a memory location.
+        ///
+        /// For faults on a stage 1 translation table walk or on a cache maintenance or address
+        /// translation instruction, this bit is 1.
+        WnR   OFFSET(6) NUMBITS(1) [
+            Read = 0,
+            Write = 1
+        ],
+
+        /// Data Fault Status Code. The low two bits hold the level of the lookup, where
+        /// applicable.
+        DFSC  OFFSET(0) NUMBITS(6) [
+            AddressSizeL0 = 0b00_0000,
+            AddressSizeL1 = 0b00_0001,
+            AddressSizeL2 = 0b00_0010,
+            AddressSizeL3 = 0b00_0011,
+            TranslationL0 = 0b00_0100,
+            TranslationL1 = 0b00_0101,
+            TranslationL2 = 0b00_0110,
+            TranslationL3 = 0b00_0111,
+            AccessFlagL1 = 0b00_1001,
+            AccessFlagL2 = 0b00_1010,
+            AccessFlagL3 = 0b00_1011,
+            PermissionL1 = 0b00_1101,
+            PermissionL2 = 0b00_1110,
+            PermissionL3 = 0b00_1111,
+            SyncExternal = 0b01_0000,
+            SyncTagCheck = 0b01_0001,
+            SyncExternalWalkL0 = 0b01_0100,
+            SyncExternalWalkL1 = 0b01_0101,
+            SyncExternalWalkL2 = 0b01_0110,
+            SyncExternalWalkL3 = 0b01_0111,
+            SyncParity = 0b01_1000,
+            SyncParityWalkL0 = 0b01_1100,
+            SyncParityWalkL1 = 0b01_1101,
+            SyncParityWalkL2 = 0b01_1110,
+            SyncParityWalkL3 = 0b01_1111,
+            Alignment = 0b10_0001,
+            TlbConflict = 0b11_0000,
+            UnsupportedAtomic = 0b11_0001
+        ]
+    ]
+}
+
+pub struct DataAbortIss {
+    value: u32,
+}
+
+impl RegisterReadOnly<u32, ISS::Register> for DataAbortIss {
+    #[inline(always)]
+    fn get(&self) -> u32 {
+        self.value
+    }
+}
+
+impl DataAbortIss {
+    pub fn new(value: u32) -> DataAbortIss {
+        DataAbortIss { value }
+    }
+}
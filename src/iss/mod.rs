@@ -1,4 +1,7 @@
 //! Instruction Specific Syndrome encodings
 
+pub mod branch_target;
+pub mod data_abort;
 pub mod mcr_mrc;
+pub mod software_step;
 
@@ -0,0 +1,51 @@
+use register::{cpu::RegisterReadOnly, register_bitfields};
+
+// ISS encoding for an exception from a Software Step exception
+//
+// This encoding is used by:
+// - Software Step exception taken from a lower Exception level, EC 0b110010.
+// - Software Step exception taken without a change in Exception level, EC 0b110011.
+register_bitfields! {u32,
+    pub ISS [
+        /// Instruction syndrome valid. Indicates whether the EX bit is valid.
+        ///
+        /// 0 EX bit is RES0.
+        /// 1 EX bit is valid.
+        ISV OFFSET(24) NUMBITS(1) [
+            NotValid = 0,
+            Valid = 1
+        ],
+
+        /// Exclusive operation. If the ISV bit is set to 1, this bit indicates whether a
+        /// Load-Exclusive instruction was stepped.
+        ///
+        /// 0 An instruction other than a Load-Exclusive instruction was stepped.
+        /// 1 A Load-Exclusive instruction was stepped.
+        ///
+        /// If the ISV bit is set to 0, this bit is RES0, indicating no syndrome data is available.
+        EX OFFSET(6) NUMBITS(1) [
+            NotExclusive = 0,
+            Exclusive = 1
+        ],
+
+        /// Instruction Fault Status Code. Set to 0b100010 for a debug exception.
+        IFSC OFFSET(0) NUMBITS(6) []
+    ]
+}
+
+pub struct SoftwareStepIss {
+    value: u32,
+}
+
+impl RegisterReadOnly<u32, ISS::Register> for SoftwareStepIss {
+    #[inline(always)]
+    fn get(&self) -> u32 {
+        self.value
+    }
+}
+
+impl SoftwareStepIss {
+    pub fn new(value: u32) -> SoftwareStepIss {
+        SoftwareStepIss { value }
+    }
+}
@@ -16,15 +16,30 @@
 //!
 //! # Minimum Supported Rust Version (MSRV)
 //!
-//! This crate is guaranteed to compile on stable Rust 1.39 and up. It *might* compile with older
-//! versions but that may change in any new patch release.
+//! This crate requires a nightly toolchain, as it uses `llvm_asm!`, which was removed during the
+//! 1.60 development cycle. It is guaranteed to compile on nightly Rust of the 1.59 cycle, which is
+//! needed for the const generics of `SysReg`, including a defaulted type parameter after the const
+//! parameters, and for `assert!` in `const fn`.
 
 #![feature(core_intrinsics)]
 #![feature(custom_inner_attributes)]
 #![feature(llvm_asm)]
 #![no_std]
 
+pub mod asid;
 pub mod asm;
 pub mod barrier;
+pub mod bti;
+pub mod cpuid;
+pub mod debug;
+pub mod gdb;
 pub mod regs;
 pub mod iss;
+pub mod mte;
+pub mod pauth;
+pub mod pmu;
+pub mod timer;
+pub mod tlb;
+pub mod topology;
+pub mod translation;
+pub mod virt;
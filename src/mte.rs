@@ -0,0 +1,241 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Memory Tagging Extension
+//!
+//! Wrappers for the ARMv8.5-MemTag instructions and tag fault reporting, available after probing
+//! the feature:
+//!
+//! ```ignore
+//! use cortex_a::{cpuid::CpuFeatures, mte::{self, Mte}, regs::*};
+//!
+//! let mte = Mte::probe(&CpuFeatures::detect()).unwrap();
+//! mte.set_exclude(1 << 0);
+//! mte.enable(SCTLR_EL1::ATA::SET + SCTLR_EL1::TCF::Async);
+//!
+//! // Allocation: tag the pointer and its memory.
+//! let ptr = mte.irg(block, 0);
+//! for granule in (ptr..ptr + size).step_by(mte::GRANULE_SIZE) {
+//!     unsafe { mte.stg(granule) };
+//! }
+//!
+//! // Periodically, or on kernel exit:
+//! if mte.take_tag_faults().any() {
+//!     // An asynchronous tag check fault happened.
+//! }
+//! ```
+//!
+//! Tag checks require the memory to be mapped with the `Normal_Tagged` attribute of `MAIR_EL1`,
+//! and the top byte to be ignored in `TCR_EL1`.
+
+use crate::{barrier, cpuid::CpuFeatures, regs::*};
+use register::FieldValue;
+
+/// The number of bytes covered by one Allocation Tag.
+pub const GRANULE_SIZE: usize = 16;
+
+/// `IRG X0, X0, X1`
+const IRG_X0_X0_X1: u32 = 0x9AC1_1000;
+
+/// `GMI X0, X1, X0`
+const GMI_X0_X1_X0: u32 = 0x9AC0_1420;
+
+/// `STG X0, [X0]`
+const STG_X0: u32 = 0xD920_0800;
+
+/// `ST2G X0, [X0]`
+const ST2G_X0: u32 = 0xD9A0_0800;
+
+/// `STZG X0, [X0]`
+const STZG_X0: u32 = 0xD960_0800;
+
+/// `LDG X0, [X0]`
+const LDG_X0: u32 = 0xD960_0000;
+
+/// Executes `insn`, which reads X0 and X1 and writes its result to X0.
+macro_rules! mte_insn {
+    ($insn:expr, $x0:expr, $x1:expr) => {
+        match () {
+            #[cfg(target_arch = "aarch64")]
+            () => {
+                let result: u64;
+                unsafe {
+                    llvm_asm!(".inst $3" : "={x0}"(result) : "0"($x0), "{x1}"($x1), "i"($insn)
+                              : "memory" : "volatile");
+                }
+                result
+            }
+
+            #[cfg(not(target_arch = "aarch64"))]
+            () => {
+                let _ = ($insn, $x0, $x1);
+                unimplemented!()
+            }
+        }
+    };
+}
+
+/// Returns the logical Allocation Tag of `ptr`.
+#[inline]
+pub fn tag(ptr: u64) -> u8 {
+    ((ptr >> 56) & 0xF) as u8
+}
+
+/// Returns `ptr` with its logical Allocation Tag replaced by `tag`.
+#[inline]
+pub fn with_tag(ptr: u64, tag: u8) -> u64 {
+    (ptr & !(0xF << 56)) | ((tag as u64 & 0xF) << 56)
+}
+
+/// Accumulated asynchronous Tag Check Faults.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TagFaults {
+    /// A fault happened in the TTBR0 region.
+    pub lower: bool,
+
+    /// A fault happened in the TTBR1 region.
+    pub upper: bool,
+}
+
+impl TagFaults {
+    /// Returns whether any fault happened.
+    pub fn any(&self) -> bool {
+        self.lower || self.upper
+    }
+}
+
+/// Access token for the Memory Tagging Extension on the executing core.
+pub struct Mte;
+
+impl Mte {
+    /// Returns the token if the full Memory Tagging Extension, with tag storage, is implemented,
+    /// `None` otherwise.
+    pub fn probe(features: &CpuFeatures) -> Option<Mte> {
+        if !features.has_mte() {
+            return None;
+        }
+
+        Some(Mte)
+    }
+
+    /// Enables tag access and checking as selected by `fields`, a combination of the `ATA`,
+    /// `ATA0`, `TCF` and `TCF0` fields of `SCTLR_EL1`.
+    #[inline(always)]
+    pub fn enable(&self, fields: FieldValue<u64, SCTLR_EL1::Register>) {
+        SCTLR_EL1.modify_sync(fields);
+    }
+
+    /// Excludes the tags set in `exclude` from random tag generation, and selects the seeded
+    /// generator.
+    ///
+    /// Excluding tag 0 keeps untagged pointers from matching newly tagged memory.
+    #[inline]
+    pub fn set_exclude(&self, exclude: u16) {
+        GCR_EL1.write(GCR_EL1::Exclude.val(exclude as u64));
+    }
+
+    /// Seeds the pseudorandom tag generator. A zero seed disables randomization.
+    #[inline]
+    pub fn set_seed(&self, seed: u16) {
+        RGSR_EL1.write(RGSR_EL1::SEED.val(seed as u64));
+    }
+
+    /// Returns `ptr` with a random logical tag, excluding the tags in `exclude` and
+    /// `GCR_EL1.Exclude` (IRG).
+    #[inline(always)]
+    pub fn irg(&self, ptr: u64, exclude: u64) -> u64 {
+        mte_insn!(IRG_X0_X0_X1, ptr, exclude)
+    }
+
+    /// Returns `mask` with the bit of the logical tag of `ptr` added, for building exclusion masks
+    /// for [`Mte::irg`] (GMI).
+    #[inline(always)]
+    pub fn gmi(&self, ptr: u64, mask: u64) -> u64 {
+        mte_insn!(GMI_X0_X1_X0, mask, ptr)
+    }
+
+    /// Sets the Allocation Tag of the granule at `ptr` to the logical tag of `ptr` (STG).
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be mapped as Tagged Normal memory. Other pointers into the granule fault on
+    /// access, if their tag differs.
+    #[inline(always)]
+    pub unsafe fn stg(&self, ptr: u64) {
+        let _: u64 = mte_insn!(STG_X0, ptr, 0u64);
+    }
+
+    /// Sets the Allocation Tags of the two granules at `ptr` to the logical tag of `ptr` (ST2G).
+    ///
+    /// # Safety
+    ///
+    /// See [`Mte::stg`].
+    #[inline(always)]
+    pub unsafe fn st2g(&self, ptr: u64) {
+        let _: u64 = mte_insn!(ST2G_X0, ptr, 0u64);
+    }
+
+    /// Sets the Allocation Tag of the granule at `ptr` to the logical tag of `ptr`, and zeroes the
+    /// granule (STZG).
+    ///
+    /// # Safety
+    ///
+    /// See [`Mte::stg`]. Additionally, the granule must be valid for writes.
+    #[inline(always)]
+    pub unsafe fn stzg(&self, ptr: u64) {
+        let _: u64 = mte_insn!(STZG_X0, ptr, 0u64);
+    }
+
+    /// Returns `ptr` with its logical tag replaced by the Allocation Tag of the granule at `ptr`
+    /// (LDG).
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be mapped.
+    #[inline(always)]
+    pub unsafe fn ldg(&self, ptr: u64) -> u64 {
+        mte_insn!(LDG_X0, ptr, 0u64)
+    }
+
+    /// Returns and clears the asynchronous Tag Check Faults of EL1, accumulated in `TFSR_EL1`.
+    pub fn take_tag_faults(&self) -> TagFaults {
+        take_faults(
+            || TFSR_EL1.get(),
+            |v| TFSR_EL1.set(v),
+            TFSR_EL1::TF0::SET.value,
+            TFSR_EL1::TF1::SET.value,
+        )
+    }
+
+    /// Returns and clears the asynchronous Tag Check Faults of EL0, accumulated in `TFSRE0_EL1`.
+    pub fn take_tag_faults_el0(&self) -> TagFaults {
+        take_faults(
+            || TFSRE0_EL1.get(),
+            |v| TFSRE0_EL1.set(v),
+            TFSRE0_EL1::TF0::SET.value,
+            TFSRE0_EL1::TF1::SET.value,
+        )
+    }
+}
+
+/// Reads and clears a Tag Fault Status Register, after making outstanding tag checks visible.
+fn take_faults(read: impl Fn() -> u64, write: impl Fn(u64), tf0: u64, tf1: u64) -> TagFaults {
+    unsafe {
+        barrier::dsb(barrier::NSH);
+        barrier::isb(barrier::SY);
+    }
+
+    let status = read();
+    if status & (tf0 | tf1) != 0 {
+        write(0);
+    }
+
+    TagFaults {
+        lower: status & tf0 != 0,
+        upper: status & tf1 != 0,
+    }
+}
@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Pointer Authentication
+//!
+//! Key management and wrappers for the ARMv8.3-PAuth instructions, available after probing the
+//! feature:
+//!
+//! ```ignore
+//! use cortex_a::{cpuid::CpuFeatures, pauth::{KeyContext, PAuth}, regs::*};
+//!
+//! let pauth = PAuth::probe(&CpuFeatures::detect()).unwrap();
+//!
+//! // Per thread, with keys from a random number generator:
+//! let keys = KeyContext { ia: random_key(), ..Default::default() };
+//!
+//! // On context switch:
+//! prev.keys = pauth.keys();
+//! pauth.set_keys(&next.keys);
+//!
+//! pauth.enable(SCTLR_EL1::EnIA::SET);
+//! let signed = pauth.pacia(ptr, sp);
+//! ```
+//!
+//! Functions that change keys or enable authentication must not themselves return through an
+//! address signed with the affected key.
+
+use crate::{barrier, cpuid::CpuFeatures, regs::*};
+use register::FieldValue;
+
+/// `PACIA X0, X1`
+const PACIA_X0_X1: u32 = 0xDAC1_0020;
+
+/// `AUTIA X0, X1`
+const AUTIA_X0_X1: u32 = 0xDAC1_1020;
+
+/// `XPACI X0`
+const XPACI_X0: u32 = 0xDAC1_43E0;
+
+/// `PACGA X0, X0, X1`
+const PACGA_X0_X0_X1: u32 = 0x9AC1_3000;
+
+/// Executes `insn`, which reads X0 and X1 and writes its result to X0.
+macro_rules! pac_insn {
+    ($insn:expr, $x0:expr, $x1:expr) => {
+        match () {
+            #[cfg(target_arch = "aarch64")]
+            () => {
+                let result: u64;
+                unsafe {
+                    llvm_asm!(".inst $3" : "={x0}"(result) : "0"($x0), "{x1}"($x1), "i"($insn)
+                              :: "volatile");
+                }
+                result
+            }
+
+            #[cfg(not(target_arch = "aarch64"))]
+            () => {
+                let _ = ($insn, $x0, $x1);
+                unimplemented!()
+            }
+        }
+    };
+}
+
+/// A 128-bit Pointer Authentication key.
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct Key {
+    pub lo: u64,
+    pub hi: u64,
+}
+
+/// The Pointer Authentication keys of a thread, switched on context switch.
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct KeyContext {
+    /// Instruction key A.
+    pub ia: Key,
+
+    /// Instruction key B.
+    pub ib: Key,
+
+    /// Data key A.
+    pub da: Key,
+
+    /// Data key B.
+    pub db: Key,
+
+    /// Generic key.
+    pub ga: Key,
+}
+
+/// Access token for Pointer Authentication on the executing core.
+pub struct PAuth {
+    generic: bool,
+}
+
+impl PAuth {
+    /// Returns the token if address authentication is implemented, `None` otherwise.
+    pub fn probe(features: &CpuFeatures) -> Option<PAuth> {
+        if !features.has_pauth() {
+            return None;
+        }
+
+        Some(PAuth {
+            generic: features.has_pauth_generic(),
+        })
+    }
+
+    /// Returns whether generic authentication, i.e. [`PAuth::pacga`], is implemented.
+    pub fn has_generic(&self) -> bool {
+        self.generic
+    }
+
+    /// Enables authentication with the keys selected by `keys`, a combination of the `EnIA`,
+    /// `EnIB`, `EnDA` and `EnDB` fields of `SCTLR_EL1`, for EL1 and EL0.
+    #[inline(always)]
+    pub fn enable(&self, keys: FieldValue<u64, SCTLR_EL1::Register>) {
+        SCTLR_EL1.modify_sync(keys);
+    }
+
+    /// Disables authentication with all keys, for EL1 and EL0.
+    #[inline(always)]
+    pub fn disable(&self) {
+        SCTLR_EL1.modify_sync(
+            SCTLR_EL1::EnIA::CLEAR
+                + SCTLR_EL1::EnIB::CLEAR
+                + SCTLR_EL1::EnDA::CLEAR
+                + SCTLR_EL1::EnDB::CLEAR,
+        );
+    }
+
+    /// Reads the keys of the current thread from the key registers.
+    pub fn keys(&self) -> KeyContext {
+        KeyContext {
+            ia: Key {
+                lo: APIAKEYLO_EL1.get(),
+                hi: APIAKEYHI_EL1.get(),
+            },
+            ib: Key {
+                lo: APIBKEYLO_EL1.get(),
+                hi: APIBKEYHI_EL1.get(),
+            },
+            da: Key {
+                lo: APDAKEYLO_EL1.get(),
+                hi: APDAKEYHI_EL1.get(),
+            },
+            db: Key {
+                lo: APDBKEYLO_EL1.get(),
+                hi: APDBKEYHI_EL1.get(),
+            },
+            ga: Key {
+                lo: APGAKEYLO_EL1.get(),
+                hi: APGAKEYHI_EL1.get(),
+            },
+        }
+    }
+
+    /// Loads `keys` into the key registers.
+    #[inline(always)]
+    pub fn set_keys(&self, keys: &KeyContext) {
+        APIAKEYLO_EL1.set(keys.ia.lo);
+        APIAKEYHI_EL1.set(keys.ia.hi);
+        APIBKEYLO_EL1.set(keys.ib.lo);
+        APIBKEYHI_EL1.set(keys.ib.hi);
+        APDAKEYLO_EL1.set(keys.da.lo);
+        APDAKEYHI_EL1.set(keys.da.hi);
+        APDBKEYLO_EL1.set(keys.db.lo);
+        APDBKEYHI_EL1.set(keys.db.hi);
+        APGAKEYLO_EL1.set(keys.ga.lo);
+        APGAKEYHI_EL1.set(keys.ga.hi);
+        unsafe { barrier::isb(barrier::SY) };
+    }
+
+    /// Adds a pointer authentication code to the instruction address `ptr`, using key A and
+    /// `modifier`.
+    ///
+    /// Returns `ptr` unchanged if `SCTLR_EL1.EnIA` is clear.
+    #[inline(always)]
+    pub fn pacia(&self, ptr: u64, modifier: u64) -> u64 {
+        pac_insn!(PACIA_X0_X1, ptr, modifier)
+    }
+
+    /// Authenticates the instruction address `ptr`, using key A and `modifier`, and returns it
+    /// without the pointer authentication code.
+    ///
+    /// If authentication fails, an error code is inserted that makes the address fault on use, or,
+    /// with ARMv8.6-FPAC, an exception is taken immediately.
+    #[inline(always)]
+    pub fn autia(&self, ptr: u64, modifier: u64) -> u64 {
+        pac_insn!(AUTIA_X0_X1, ptr, modifier)
+    }
+
+    /// Removes the pointer authentication code from the instruction address `ptr` without
+    /// authenticating it.
+    #[inline(always)]
+    pub fn xpaci(&self, ptr: u64) -> u64 {
+        pac_insn!(XPACI_X0, ptr, 0u64)
+    }
+
+    /// Computes a 32-bit generic authentication code for `value` and `modifier`, returned in the
+    /// upper half of the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if generic authentication is not implemented.
+    #[inline(always)]
+    pub fn pacga(&self, value: u64, modifier: u64) -> u64 {
+        assert!(self.generic);
+
+        pac_insn!(PACGA_X0_X0_X1, value, modifier)
+    }
+}
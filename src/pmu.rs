@@ -0,0 +1,335 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors
+//!
+//! A small profiler on top of the PMUv3 cycle counter and event counters:
+//!
+//! ```ignore
+//! use cortex_a::pmu::{Event, Pmu};
+//!
+//! let pmu = Pmu::probe().unwrap();
+//! pmu.enable();
+//!
+//! let measurement = pmu.measure(&[Event::InstructionsRetired, Event::L1DRefill]);
+//! // Code under test.
+//! let sample = measurement.finish();
+//!
+//! let (cycles, instructions) = (sample.cycles(), sample.count(0));
+//! ```
+//!
+//! Event counters are 32 bits wide. A single overflow during a measurement is compensated using
+//! the overflow flags in `PMOVSCLR_EL0`; longer measurements should use the cycle counter, which
+//! is configured to be 64 bits wide.
+
+use crate::{barrier, cpuid::CpuFeatures, regs::*};
+
+/// The maximum number of event counters, not including the cycle counter.
+pub const MAX_COUNTERS: usize = 31;
+
+/// Common architectural and microarchitectural events.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+    /// Level 1 instruction cache refill.
+    L1IRefill,
+
+    /// Level 1 data cache refill.
+    L1DRefill,
+
+    /// Level 1 data cache access.
+    L1DAccess,
+
+    /// Instruction architecturally executed.
+    InstructionsRetired,
+
+    /// Exception taken.
+    ExceptionTaken,
+
+    /// Mispredicted or not predicted branch speculatively executed.
+    BranchMispredict,
+
+    /// Cycle.
+    Cycles,
+
+    /// Predictable branch speculatively executed.
+    BranchPredicted,
+
+    /// Data memory access.
+    MemoryAccess,
+
+    /// Level 2 data cache access.
+    L2DAccess,
+
+    /// Level 2 data cache refill.
+    L2DRefill,
+
+    /// Bus access.
+    BusAccess,
+
+    /// No operation issued because of the frontend.
+    StallFrontend,
+
+    /// No operation issued because of the backend.
+    StallBackend,
+
+    /// An event by its raw event number, e.g. an IMPLEMENTATION DEFINED one.
+    Raw(u16),
+}
+
+impl Event {
+    /// Returns the event number programmed into `PMEVTYPER<n>_EL0.EVTCOUNT`.
+    pub fn number(&self) -> u16 {
+        match *self {
+            Event::L1IRefill => 0x01,
+            Event::L1DRefill => 0x03,
+            Event::L1DAccess => 0x04,
+            Event::InstructionsRetired => 0x08,
+            Event::ExceptionTaken => 0x09,
+            Event::BranchMispredict => 0x10,
+            Event::Cycles => 0x11,
+            Event::BranchPredicted => 0x12,
+            Event::MemoryAccess => 0x13,
+            Event::L2DAccess => 0x16,
+            Event::L2DRefill => 0x17,
+            Event::BusAccess => 0x19,
+            Event::StallFrontend => 0x23,
+            Event::StallBackend => 0x24,
+            Event::Raw(number) => number,
+        }
+    }
+}
+
+/// Access token for the Performance Monitors of the executing core.
+pub struct Pmu {
+    num_counters: usize,
+}
+
+impl Pmu {
+    /// Returns the PMU if PMUv3 is implemented, `None` otherwise.
+    pub fn probe() -> Option<Pmu> {
+        if !CpuFeatures::detect().has_pmuv3() {
+            return None;
+        }
+
+        Some(Pmu {
+            num_counters: PMCR_EL0.read(PMCR_EL0::N) as usize,
+        })
+    }
+
+    /// Returns the number of implemented event counters, not including the cycle counter.
+    pub fn num_counters(&self) -> usize {
+        self.num_counters
+    }
+
+    /// Enables the counters that are enabled in `PMCNTENSET_EL0`, with a 64-bit cycle counter.
+    #[inline]
+    pub fn enable(&self) {
+        PMCR_EL0.modify(PMCR_EL0::LC::SET + PMCR_EL0::D::CLEAR + PMCR_EL0::E::SET);
+    }
+
+    /// Disables all counters.
+    #[inline]
+    pub fn disable(&self) {
+        PMCR_EL0.modify(PMCR_EL0::E::CLEAR);
+    }
+
+    /// Resets the cycle counter and all event counters to zero.
+    #[inline]
+    pub fn reset(&self) {
+        PMCR_EL0.modify(PMCR_EL0::C::SET + PMCR_EL0::P::SET);
+    }
+
+    /// Allows EL0 to read the counters.
+    #[inline]
+    pub fn allow_el0_read(&self, allow: bool) {
+        if allow {
+            PMUSERENR_EL0.write(PMUSERENR_EL0::ER::SET + PMUSERENR_EL0::CR::SET);
+        } else {
+            PMUSERENR_EL0.set(0);
+        }
+    }
+
+    /// Returns the value of the cycle counter.
+    #[inline]
+    pub fn cycles(&self) -> u64 {
+        unsafe { barrier::isb(barrier::SY) };
+
+        PMCCNTR_EL0.get()
+    }
+
+    /// Starts the cycle counter.
+    #[inline]
+    pub fn enable_cycle_counter(&self) {
+        PMCNTENSET_EL0.write(PMCNTENSET_EL0::C::SET);
+    }
+
+    /// Stops the cycle counter.
+    #[inline]
+    pub fn disable_cycle_counter(&self) {
+        PMCNTENCLR_EL0.write(PMCNTENCLR_EL0::C::SET);
+    }
+
+    /// Programs event counter `counter` to count `event` at EL0, EL1 and EL2, and starts it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `counter` is not implemented.
+    pub fn configure(&self, counter: usize, event: Event) {
+        self.select(counter);
+
+        PMXEVTYPER_EL0
+            .write(PMEVTYPER_EL0::NSH::SET + PMEVTYPER_EL0::EVTCOUNT.val(event.number() as u64));
+        PMXEVCNTR_EL0.set(0);
+        self.take_overflow(counter);
+
+        PMCNTENSET_EL0.set(1 << counter);
+    }
+
+    /// Stops event counter `counter`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `counter` is not implemented.
+    pub fn disable_counter(&self, counter: usize) {
+        assert!(counter < self.num_counters);
+
+        PMCNTENCLR_EL0.set(1 << counter);
+    }
+
+    /// Returns the value of event counter `counter`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `counter` is not implemented.
+    pub fn read(&self, counter: usize) -> u64 {
+        self.select(counter);
+
+        PMXEVCNTR_EL0.get() & u32::MAX as u64
+    }
+
+    /// Returns and clears the overflow flag of event counter `counter`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `counter` is not implemented.
+    pub fn take_overflow(&self, counter: usize) -> bool {
+        assert!(counter < self.num_counters);
+
+        let overflowed = PMOVSCLR_EL0.get() & (1 << counter) != 0;
+        if overflowed {
+            PMOVSCLR_EL0.set(1 << counter);
+        }
+
+        overflowed
+    }
+
+    /// Programs the first `events.len()` event counters and returns a guard that measures until
+    /// it is finished or dropped. The cycle counter is always included.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more events are requested than counters are implemented.
+    pub fn measure(&self, events: &[Event]) -> Measurement<'_> {
+        assert!(events.len() <= self.num_counters);
+
+        let mut start = [0; MAX_COUNTERS];
+
+        for (counter, &event) in events.iter().enumerate() {
+            self.configure(counter, event);
+            start[counter] = self.read(counter);
+        }
+
+        self.enable_cycle_counter();
+
+        Measurement {
+            pmu: self,
+            num_events: events.len(),
+            start,
+            start_cycles: self.cycles(),
+        }
+    }
+
+    #[inline]
+    fn select(&self, counter: usize) {
+        assert!(counter < self.num_counters);
+
+        PMSELR_EL0.write(PMSELR_EL0::SEL.val(counter as u32));
+        unsafe { barrier::isb(barrier::SY) };
+    }
+}
+
+/// A running measurement, see [`Pmu::measure`].
+///
+/// Dropping an unfinished measurement stops its event counters.
+pub struct Measurement<'a> {
+    pmu: &'a Pmu,
+    num_events: usize,
+    start: [u64; MAX_COUNTERS],
+    start_cycles: u64,
+}
+
+impl<'a> Measurement<'a> {
+    /// Stops the measurement and returns the counted cycles and events.
+    pub fn finish(self) -> Sample {
+        let cycles = self.pmu.cycles().wrapping_sub(self.start_cycles);
+        let mut counts = [0; MAX_COUNTERS];
+
+        for (counter, count) in counts.iter_mut().enumerate().take(self.num_events) {
+            let end = self.pmu.read(counter);
+            let mut delta = end.wrapping_sub(self.start[counter]) & u32::MAX as u64;
+
+            if self.pmu.take_overflow(counter) && end >= self.start[counter] {
+                delta += 1 << 32;
+            }
+
+            *count = delta;
+        }
+
+        Sample {
+            cycles,
+            num_events: self.num_events,
+            counts,
+        }
+    }
+}
+
+impl<'a> Drop for Measurement<'a> {
+    fn drop(&mut self) {
+        for counter in 0..self.num_events {
+            self.pmu.disable_counter(counter);
+        }
+    }
+}
+
+/// The result of a [`Measurement`].
+#[derive(Copy, Clone, Debug)]
+pub struct Sample {
+    cycles: u64,
+    num_events: usize,
+    counts: [u64; MAX_COUNTERS],
+}
+
+impl Sample {
+    /// Returns the number of cycles spent in the measurement.
+    pub fn cycles(&self) -> u64 {
+        self.cycles
+    }
+
+    /// Returns the count of the `index`th event passed to [`Pmu::measure`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of range.
+    pub fn count(&self, index: usize) -> u64 {
+        self.counts()[index]
+    }
+
+    /// Returns the counts of all events, in the order passed to [`Pmu::measure`].
+    pub fn counts(&self) -> &[u64] {
+        &self.counts[..self.num_events]
+    }
+}
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Auxiliary Fault Status Register 0 - EL1
+//!
+//! Provides additional IMPLEMENTATION DEFINED fault status information for exceptions taken to EL1.
+
+use register::cpu::RegisterReadWrite;
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, ()> for Reg {
+    sys_coproc_read_raw!(u64, "AFSR0_EL1");
+    sys_coproc_write_raw!(u64, "AFSR0_EL1");
+}
+
+pub static AFSR0_EL1: Reg = Reg {};
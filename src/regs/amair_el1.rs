@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Auxiliary Memory Attribute Indirection Register - EL1
+//!
+//! Provides IMPLEMENTATION DEFINED memory attributes for the memory regions specified by MAIR_EL1.
+
+use register::cpu::RegisterReadWrite;
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, ()> for Reg {
+    sys_coproc_read_raw!(u64, "AMAIR_EL1");
+    sys_coproc_write_raw!(u64, "AMAIR_EL1");
+}
+
+pub static AMAIR_EL1: Reg = Reg {};
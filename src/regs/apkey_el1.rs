@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Pointer Authentication Key Registers - EL1
+//!
+//! Hold the low (bits[63:0]) and high (bits[127:64]) halves of the 128-bit keys used by the
+//! Pointer Authentication instructions:
+//!
+//! - APIAKey and APIBKey for instruction addresses (PACIA*, AUTIA*, PACIB*, AUTIB*).
+//! - APDAKey and APDBKey for data addresses (PACDA*, AUTDA*, PACDB*, AUTDB*).
+//! - APGAKey for generic authentication (PACGA).
+
+use crate::regs::SysReg;
+
+pub static APIAKEYLO_EL1: SysReg<(), 3, 0, 2, 1, 0> = SysReg::new();
+pub static APIAKEYHI_EL1: SysReg<(), 3, 0, 2, 1, 1> = SysReg::new();
+pub static APIBKEYLO_EL1: SysReg<(), 3, 0, 2, 1, 2> = SysReg::new();
+pub static APIBKEYHI_EL1: SysReg<(), 3, 0, 2, 1, 3> = SysReg::new();
+pub static APDAKEYLO_EL1: SysReg<(), 3, 0, 2, 2, 0> = SysReg::new();
+pub static APDAKEYHI_EL1: SysReg<(), 3, 0, 2, 2, 1> = SysReg::new();
+pub static APDBKEYLO_EL1: SysReg<(), 3, 0, 2, 2, 2> = SysReg::new();
+pub static APDBKEYHI_EL1: SysReg<(), 3, 0, 2, 2, 3> = SysReg::new();
+pub static APGAKEYLO_EL1: SysReg<(), 3, 0, 2, 3, 0> = SysReg::new();
+pub static APGAKEYHI_EL1: SysReg<(), 3, 0, 2, 3, 1> = SysReg::new();
@@ -13,13 +13,92 @@
 
 use register::{cpu::RegisterReadWrite, register_bitfields};
 
-// When HCR_EL2.E2H == 0:
-// TODO: Figure out how we can differentiate depending on HCR_EL2.E2H state
-//
-// For now, implement the HCR_EL2.E2H == 0 version
+// The layout of bits [1:0] and [11:10] depends on HCR_EL2.E2H. Both variants are provided, the
+// fields that are only valid for one of the two layouts say so.
 register_bitfields! {u32,
     pub CNTHCTL_EL2 [
-        /// Traps Non-secure EL0 and EL1 accesses to the physical timer registers to EL2.
+        /// When HCR_EL2.E2H == 1, traps EL0 and EL1 accesses to the EL1 physical timer registers to
+        /// EL2 when EL2 is enabled for the current Security state.
+        ///
+        /// 0 From AArch64 state: EL0 and EL1 accesses to the CNTP_CTL_EL0, CNTP_CVAL_EL0, and
+        ///   CNTP_TVAL_EL0 are trapped to EL2 when HCR_EL2.TGE == 0, unless they are trapped by
+        ///   CNTKCTL_EL1.EL0PTEN.
+        /// 1 This control does not cause any instructions to be trapped.
+        ///
+        /// When HCR_EL2.E2H == 0, this bit is RES0.
+        EL1PTEN  OFFSET(11) NUMBITS(1) [],
+
+        /// When HCR_EL2.E2H == 1, traps EL0 and EL1 accesses to the EL1 physical counter register
+        /// to EL2 when EL2 is enabled for the current Security state.
+        ///
+        /// 0 From AArch64 state: EL0 and EL1 accesses to the CNTPCT_EL0 are trapped to EL2 when
+        ///   HCR_EL2.TGE == 0, unless they are trapped by CNTKCTL_EL1.EL0PCTEN.
+        /// 1 This control does not cause any instructions to be trapped.
+        ///
+        /// Named EL1PCTEN in the architecture, which is also the name of bit 0 when
+        /// HCR_EL2.E2H == 0. When HCR_EL2.E2H == 0, this bit is RES0.
+        EL1PCTEN_E2H OFFSET(10) NUMBITS(1) [],
+
+        /// When HCR_EL2.E2H == 1, traps EL0 accesses to the physical timer registers to EL2.
+        ///
+        /// 0 EL0 accesses to the CNTP_CTL_EL0, CNTP_CVAL_EL0, and CNTP_TVAL_EL0 registers are
+        ///   trapped to EL2 when HCR_EL2.TGE == 1.
+        /// 1 This control does not cause any instructions to be trapped.
+        ///
+        /// When HCR_EL2.E2H == 0, this bit is RES0.
+        EL0PTEN  OFFSET(9) NUMBITS(1) [],
+
+        /// When HCR_EL2.E2H == 1, traps EL0 accesses to the virtual timer registers to EL2.
+        ///
+        /// 0 EL0 accesses to the CNTV_CTL_EL0, CNTV_CVAL_EL0, and CNTV_TVAL_EL0 registers are
+        ///   trapped to EL2 when HCR_EL2.TGE == 1.
+        /// 1 This control does not cause any instructions to be trapped.
+        ///
+        /// When HCR_EL2.E2H == 0, this bit is RES0.
+        EL0VTEN  OFFSET(8) NUMBITS(1) [],
+
+        /// Selects which bit (0 to 15) of the counter register CNTPCT_EL0 is the trigger for the
+        /// event stream generated from that counter, when that stream is enabled.
+        EVNTI    OFFSET(4) NUMBITS(4) [],
+
+        /// Controls which transition of the counter register CNTPCT_EL0 trigger bit, defined by
+        /// EVNTI, generates an event when the event stream is enabled:
+        ///
+        /// 0 A 0 to 1 transition of the trigger bit triggers an event.
+        /// 1 A 1 to 0 transition of the trigger bit triggers an event.
+        EVNTDIR  OFFSET(3) NUMBITS(1) [
+            RisingEdge = 0,
+            FallingEdge = 1
+        ],
+
+        /// Enables the generation of an event stream from the counter register CNTPCT_EL0:
+        ///
+        /// 0 Disables the event stream.
+        /// 1 Enables the event stream.
+        EVNTEN   OFFSET(2) NUMBITS(1) [],
+
+        /// When HCR_EL2.E2H == 1, traps EL0 accesses to the frequency register and virtual counter
+        /// register to EL2.
+        ///
+        /// 0 EL0 accesses to the CNTVCT_EL0 are trapped to EL2 when HCR_EL2.TGE == 1. EL0 accesses
+        ///   to the CNTFRQ_EL0 are trapped to EL2 if EL0PCTEN is also 0.
+        /// 1 This control does not cause any instructions to be trapped.
+        ///
+        /// Occupies the same bit as EL1PCEN, which applies when HCR_EL2.E2H == 0.
+        EL0VCTEN OFFSET(1) NUMBITS(1) [],
+
+        /// When HCR_EL2.E2H == 1, traps EL0 accesses to the frequency register and physical
+        /// counter register to EL2.
+        ///
+        /// 0 EL0 accesses to the CNTPCT_EL0 are trapped to EL2 when HCR_EL2.TGE == 1. EL0 accesses
+        ///   to the CNTFRQ_EL0 are trapped to EL2 if EL0VCTEN is also 0.
+        /// 1 This control does not cause any instructions to be trapped.
+        ///
+        /// Occupies the same bit as EL1PCTEN, which applies when HCR_EL2.E2H == 0.
+        EL0PCTEN OFFSET(0) NUMBITS(1) [],
+
+        /// When HCR_EL2.E2H == 0, traps Non-secure EL0 and EL1 accesses to the physical timer
+        /// registers to EL2.
         ///
         /// 0 From AArch64 state: Non-secure EL0 and EL1 accesses to the CNTP_CTL_EL0,
         ///   CNTP_CVAL_EL0, and CNTP_TVAL_EL0 are trapped to EL2, unless it is trapped by
@@ -35,7 +114,8 @@ register_bitfields! {u32,
         /// than for the purpose of a direct read.
         EL1PCEN  OFFSET(1) NUMBITS(1) [],
 
-        /// Traps Non-secure EL0 and EL1 accesses to the physical counter register to EL2.
+        /// When HCR_EL2.E2H == 0, traps Non-secure EL0 and EL1 accesses to the physical counter
+        /// register to EL2.
         ///
         /// 0 From AArch64 state: Non-secure EL0 and EL1 accesses to the CNTPCT_EL0 are trapped to
         ///   EL2, unless it is trapped by CNTKCTL_EL1.EL0PCTEN.
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Counter-timer Physical Timer CompareValue register - EL2
+//!
+//! Holds the compare value for the EL2 physical timer.
+
+use register::cpu::RegisterReadWrite;
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, ()> for Reg {
+    sys_coproc_read_raw!(u64, "CNTHP_CVAL_EL2");
+    sys_coproc_write_raw!(u64, "CNTHP_CVAL_EL2");
+}
+
+pub static CNTHP_CVAL_EL2: Reg = Reg {};
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Counter-timer Kernel Control register - EL1
+//!
+//! Controls the generation of an event stream from the virtual counter, and access from EL0 to the
+//! physical counter, virtual counter, EL1 physical timers, and the virtual timer.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u32,
+    pub CNTKCTL_EL1 [
+        /// Traps EL0 accesses to the physical timer registers to EL1.
+        ///
+        /// 0 EL0 accesses to the CNTP_CTL_EL0, CNTP_CVAL_EL0, and CNTP_TVAL_EL0 registers are
+        ///   trapped to EL1.
+        /// 1 This control does not cause any instructions to be trapped.
+        EL0PTEN  OFFSET(9) NUMBITS(1) [],
+
+        /// Traps EL0 accesses to the virtual timer registers to EL1.
+        ///
+        /// 0 EL0 accesses to the CNTV_CTL_EL0, CNTV_CVAL_EL0, and CNTV_TVAL_EL0 registers are
+        ///   trapped to EL1.
+        /// 1 This control does not cause any instructions to be trapped.
+        EL0VTEN  OFFSET(8) NUMBITS(1) [],
+
+        /// Selects which bit (0 to 15) of the counter register CNTVCT_EL0 is the trigger for the
+        /// event stream generated from that counter, when that stream is enabled.
+        EVNTI    OFFSET(4) NUMBITS(4) [],
+
+        /// Controls which transition of the counter register CNTVCT_EL0 trigger bit, defined by
+        /// EVNTI, generates an event when the event stream is enabled:
+        ///
+        /// 0 A 0 to 1 transition of the trigger bit triggers an event.
+        /// 1 A 1 to 0 transition of the trigger bit triggers an event.
+        EVNTDIR  OFFSET(3) NUMBITS(1) [
+            RisingEdge = 0,
+            FallingEdge = 1
+        ],
+
+        /// When the ARMv8.6-ECV feature is not implemented, enables the generation of an event
+        /// stream from the counter register CNTVCT_EL0:
+        ///
+        /// 0 Disables the event stream.
+        /// 1 Enables the event stream.
+        EVNTEN   OFFSET(2) NUMBITS(1) [],
+
+        /// Traps EL0 accesses to the frequency register and virtual counter register to EL1.
+        ///
+        /// 0 EL0 accesses to the CNTVCT_EL0 are trapped to EL1. EL0 accesses to the CNTFRQ_EL0 are
+        ///   trapped to EL1 if EL0PCTEN is also 0.
+        /// 1 This control does not cause any instructions to be trapped.
+        EL0VCTEN OFFSET(1) NUMBITS(1) [],
+
+        /// Traps EL0 accesses to the frequency register and physical counter register to EL1.
+        ///
+        /// 0 EL0 accesses to the CNTPCT_EL0 are trapped to EL1. EL0 accesses to the CNTFRQ_EL0 are
+        ///   trapped to EL1 if EL0VCTEN is also 0.
+        /// 1 This control does not cause any instructions to be trapped.
+        EL0PCTEN OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u32, CNTKCTL_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u32, "CNTKCTL_EL1");
+    sys_coproc_write_raw!(u32, "CNTKCTL_EL1");
+}
+
+pub static CNTKCTL_EL1: Reg = Reg {};
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Counter-timer Physical Secure Timer CompareValue register - EL1
+//!
+//! Holds the compare value for the secure physical timer.
+
+use register::cpu::RegisterReadWrite;
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, ()> for Reg {
+    sys_coproc_read_raw!(u64, "CNTPS_CVAL_EL1");
+    sys_coproc_write_raw!(u64, "CNTPS_CVAL_EL1");
+}
+
+pub static CNTPS_CVAL_EL1: Reg = Reg {};
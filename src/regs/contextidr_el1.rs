@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Context ID Register - EL1
+//!
+//! Identifies the current Process Identifier, for use by debug and trace logic.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u64,
+    pub CONTEXTIDR_EL1 [
+        /// Process Identifier. This field must be programmed with a unique value that identifies
+        /// the current process.
+        PROCID OFFSET(0) NUMBITS(32) []
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, CONTEXTIDR_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u64, "CONTEXTIDR_EL1");
+    sys_coproc_write_raw!(u64, "CONTEXTIDR_EL1");
+}
+
+pub static CONTEXTIDR_EL1: Reg = Reg {};
@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Architectural Feature Access Control Register - EL1
+//!
+//! Controls access to trace, SME, SVE, and Advanced SIMD and floating-point functionality.
+
+use super::ContextSync;
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u64,
+    pub CPACR_EL1 [
+        /// Traps EL0 and EL1 System register accesses to all implemented trace registers from both
+        /// Execution states to EL1, or to EL2 when it is implemented and enabled in the current
+        /// Security state and HCR_EL2.TGE is 1:
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 This control causes EL0 and EL1 System register accesses to all implemented trace
+        ///   registers to be trapped.
+        TTA OFFSET(28) NUMBITS(1) [],
+
+        /// Traps execution at EL1 and EL0 of SME instructions, SVE instructions when PSTATE.SM is
+        /// 1, and instructions that directly access the SVCR or SMCR_EL1 System registers.
+        ///
+        /// When SME is not implemented, this field is RES0.
+        SMEN OFFSET(24) NUMBITS(2) [
+            TrapAll = 0b00,
+            TrapEl0 = 0b01,
+            TrapNone = 0b11
+        ],
+
+        /// Traps execution at EL1 and EL0 of instructions that access the Advanced SIMD and
+        /// floating-point registers from both Execution states.
+        FPEN OFFSET(20) NUMBITS(2) [
+            TrapAll = 0b00,
+            TrapEl0 = 0b01,
+            TrapNone = 0b11
+        ],
+
+        /// Traps execution at EL1 and EL0 of SVE instructions and instructions that directly
+        /// access the ZCR_EL1 System register.
+        ///
+        /// When SVE is not implemented, this field is RES0.
+        ZEN OFFSET(16) NUMBITS(2) [
+            TrapAll = 0b00,
+            TrapEl0 = 0b01,
+            TrapNone = 0b11
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, CPACR_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u64, "CPACR_EL1");
+    sys_coproc_write_raw!(u64, "CPACR_EL1");
+}
+
+impl ContextSync<u64, CPACR_EL1::Register> for Reg {}
+
+pub static CPACR_EL1: Reg = Reg {};
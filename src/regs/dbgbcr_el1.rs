@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Debug Breakpoint Control Registers - EL1
+//!
+//! Holds control information for a breakpoint. Forms breakpoint n together with value register
+//! DBGBVR<n>_EL1.
+
+use crate::regs::SysReg;
+use register::register_bitfields;
+
+register_bitfields! {u64,
+    pub DBGBCR_EL1 [
+        /// Breakpoint Type.
+        ///
+        /// 0b0000 Unlinked instruction address match.
+        /// 0b0001 Linked instruction address match.
+        /// 0b0010 Unlinked Context ID match.
+        /// 0b0011 Linked Context ID match.
+        ///
+        /// Other values are only valid for context-aware breakpoints.
+        BT  OFFSET(20) NUMBITS(4) [
+            UnlinkedAddressMatch = 0b0000,
+            LinkedAddressMatch = 0b0001,
+            UnlinkedContextIdMatch = 0b0010,
+            LinkedContextIdMatch = 0b0011
+        ],
+
+        /// Linked breakpoint number. For Linked breakpoints, specifies the index of the breakpoint
+        /// linked to.
+        LBN OFFSET(16) NUMBITS(4) [],
+
+        /// Security state control. Determines the Security states under which a Breakpoint debug
+        /// event for breakpoint n is generated. Used together with HMC and PMC.
+        SSC OFFSET(14) NUMBITS(2) [],
+
+        /// Higher mode control. Determines the debug perspective for deciding when a Breakpoint debug
+        /// event for breakpoint n is generated. Used together with SSC and PMC.
+        HMC OFFSET(13) NUMBITS(1) [],
+
+        /// Byte address select. Defines which half-words a regular breakpoint matches, regardless
+        /// of the instruction set and Execution state. For AArch64 instructions, must be 0b1111.
+        BAS OFFSET(5) NUMBITS(4) [],
+
+        /// Privilege mode control. Determines the Exception level or levels at which a Breakpoint
+        /// debug event for breakpoint n is generated. Used together with HMC and SSC.
+        PMC OFFSET(1) NUMBITS(2) [],
+
+        /// Enable breakpoint DBGBVR<n>_EL1.
+        ///
+        /// 0 Breakpoint disabled.
+        /// 1 Breakpoint enabled.
+        E   OFFSET(0) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ]
+    ]
+}
+
+macro_rules! dbgbcr {
+    ($($name:ident = $n:expr,)*) => {
+        $(
+            pub static $name: SysReg<DBGBCR_EL1::Register, 2, 0, 0, $n, 5> = SysReg::new();
+        )*
+    };
+}
+
+dbgbcr! {
+    DBGBCR0_EL1 = 0,
+    DBGBCR1_EL1 = 1,
+    DBGBCR2_EL1 = 2,
+    DBGBCR3_EL1 = 3,
+    DBGBCR4_EL1 = 4,
+    DBGBCR5_EL1 = 5,
+    DBGBCR6_EL1 = 6,
+    DBGBCR7_EL1 = 7,
+    DBGBCR8_EL1 = 8,
+    DBGBCR9_EL1 = 9,
+    DBGBCR10_EL1 = 10,
+    DBGBCR11_EL1 = 11,
+    DBGBCR12_EL1 = 12,
+    DBGBCR13_EL1 = 13,
+    DBGBCR14_EL1 = 14,
+    DBGBCR15_EL1 = 15,
+}
@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Debug Breakpoint Value Registers - EL1
+//!
+//! Holds a virtual address or a context ID for use in breakpoint matching. Forms breakpoint n
+//! together with control register DBGBCR<n>_EL1.
+//!
+//! For address matching, bits [1:0] must be zero and the top bits must be a sign extension of the
+//! highest implemented virtual address bit.
+
+use crate::regs::SysReg;
+
+macro_rules! dbgbvr {
+    ($($name:ident = $n:expr,)*) => {
+        $(
+            pub static $name: SysReg<(), 2, 0, 0, $n, 4> = SysReg::new();
+        )*
+    };
+}
+
+dbgbvr! {
+    DBGBVR0_EL1 = 0,
+    DBGBVR1_EL1 = 1,
+    DBGBVR2_EL1 = 2,
+    DBGBVR3_EL1 = 3,
+    DBGBVR4_EL1 = 4,
+    DBGBVR5_EL1 = 5,
+    DBGBVR6_EL1 = 6,
+    DBGBVR7_EL1 = 7,
+    DBGBVR8_EL1 = 8,
+    DBGBVR9_EL1 = 9,
+    DBGBVR10_EL1 = 10,
+    DBGBVR11_EL1 = 11,
+    DBGBVR12_EL1 = 12,
+    DBGBVR13_EL1 = 13,
+    DBGBVR14_EL1 = 14,
+    DBGBVR15_EL1 = 15,
+}
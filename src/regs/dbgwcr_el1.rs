@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Debug Watchpoint Control Registers - EL1
+//!
+//! Holds control information for a watchpoint. Forms watchpoint n together with value register
+//! DBGWVR<n>_EL1.
+
+use crate::regs::SysReg;
+use register::register_bitfields;
+
+register_bitfields! {u64,
+    pub DBGWCR_EL1 [
+        /// Address mask. Only objects up to 2GB can be watched using a single mask.
+        ///
+        /// 0b00000 No mask.
+        /// 0b00001 Reserved.
+        /// 0b00010 Reserved.
+        ///
+        /// Other values mask the corresponding number of address bits, from 0b00011 masking 3
+        /// address bits (0x00000007 mask for address) to 0b11111 masking 31 address bits
+        /// (0x7FFFFFFF mask for address). If MASK is nonzero, BAS must be 0b11111111.
+        MASK OFFSET(24) NUMBITS(5) [],
+
+        /// Watchpoint type.
+        ///
+        /// 0 Unlinked data address match.
+        /// 1 Linked data address match.
+        WT   OFFSET(20) NUMBITS(1) [
+            Unlinked = 0,
+            Linked = 1
+        ],
+
+        /// Linked breakpoint number. For Linked watchpoints, specifies the index of the breakpoint
+        /// linked to.
+        LBN OFFSET(16) NUMBITS(4) [],
+
+        /// Security state control. Determines the Security states under which a Watchpoint debug
+        /// event for watchpoint n is generated. Used together with HMC and PAC.
+        SSC OFFSET(14) NUMBITS(2) [],
+
+        /// Higher mode control. Determines the debug perspective for deciding when a Watchpoint debug
+        /// event for watchpoint n is generated. Used together with SSC and PAC.
+        HMC OFFSET(13) NUMBITS(1) [],
+
+        /// Byte address select. Each bit of this field selects whether a byte from within the word
+        /// or double-word addressed by DBGWVR<n>_EL1 is being watched.
+        BAS  OFFSET(5) NUMBITS(8) [],
+
+        /// Load/store control. This field enables watchpoint matching on the type of access being
+        /// made.
+        LSC  OFFSET(3) NUMBITS(2) [
+            Load = 0b01,
+            Store = 0b10,
+            LoadStore = 0b11
+        ],
+
+        /// Privilege of access control. Determines the Exception level or levels at which a
+        /// Watchpoint debug event for watchpoint n is generated. Used together with HMC and SSC.
+        PAC  OFFSET(1) NUMBITS(2) [],
+
+        /// Enable watchpoint n.
+        ///
+        /// 0 Watchpoint disabled.
+        /// 1 Watchpoint enabled.
+        E    OFFSET(0) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ]
+    ]
+}
+
+macro_rules! dbgwcr {
+    ($($name:ident = $n:expr,)*) => {
+        $(
+            pub static $name: SysReg<DBGWCR_EL1::Register, 2, 0, 0, $n, 7> = SysReg::new();
+        )*
+    };
+}
+
+dbgwcr! {
+    DBGWCR0_EL1 = 0,
+    DBGWCR1_EL1 = 1,
+    DBGWCR2_EL1 = 2,
+    DBGWCR3_EL1 = 3,
+    DBGWCR4_EL1 = 4,
+    DBGWCR5_EL1 = 5,
+    DBGWCR6_EL1 = 6,
+    DBGWCR7_EL1 = 7,
+    DBGWCR8_EL1 = 8,
+    DBGWCR9_EL1 = 9,
+    DBGWCR10_EL1 = 10,
+    DBGWCR11_EL1 = 11,
+    DBGWCR12_EL1 = 12,
+    DBGWCR13_EL1 = 13,
+    DBGWCR14_EL1 = 14,
+    DBGWCR15_EL1 = 15,
+}
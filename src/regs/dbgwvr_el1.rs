@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Debug Watchpoint Value Registers - EL1
+//!
+//! Holds a data address value for use in watchpoint matching. Forms watchpoint n together with
+//! control register DBGWCR<n>_EL1.
+//!
+//! Bits [1:0] must be zero and the top bits must be a sign extension of the highest implemented
+//! virtual address bit.
+
+use crate::regs::SysReg;
+
+macro_rules! dbgwvr {
+    ($($name:ident = $n:expr,)*) => {
+        $(
+            pub static $name: SysReg<(), 2, 0, 0, $n, 6> = SysReg::new();
+        )*
+    };
+}
+
+dbgwvr! {
+    DBGWVR0_EL1 = 0,
+    DBGWVR1_EL1 = 1,
+    DBGWVR2_EL1 = 2,
+    DBGWVR3_EL1 = 3,
+    DBGWVR4_EL1 = 4,
+    DBGWVR5_EL1 = 5,
+    DBGWVR6_EL1 = 6,
+    DBGWVR7_EL1 = 7,
+    DBGWVR8_EL1 = 8,
+    DBGWVR9_EL1 = 9,
+    DBGWVR10_EL1 = 10,
+    DBGWVR11_EL1 = 11,
+    DBGWVR12_EL1 = 12,
+    DBGWVR13_EL1 = 13,
+    DBGWVR14_EL1 = 14,
+    DBGWVR15_EL1 = 15,
+}
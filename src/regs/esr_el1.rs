@@ -9,7 +9,7 @@
 //!
 //! Holds syndrome information for an exception taken to EL1.
 
-use register::{cpu::RegisterReadOnly, register_bitfields};
+use register::{cpu::RegisterReadWrite, register_bitfields};
 
 register_bitfields! {u32,
     pub ESR_EL1 [
@@ -26,6 +26,7 @@ register_bitfields! {u32,
             Unknown               = 0b00_0000,
             TrappedWFIorWFE       = 0b00_0001,
             TrappedFP             = 0b00_0111,
+            BranchTarget          = 0b00_1101,
             IllegalExecutionState = 0b00_1110,
             SVC64                 = 0b01_0101,
             HVC64                 = 0b01_0110,
@@ -37,7 +38,14 @@ register_bitfields! {u32,
             DataAbortLowerEL      = 0b10_0100,
             DataAbortCurrentEL    = 0b10_0101,
             SPAlignmentFault      = 0b10_0110,
-            TrappedFP64           = 0b10_1100
+            TrappedFP64           = 0b10_1100,
+            BreakpointLowerEL     = 0b11_0000,
+            BreakpointCurrentEL   = 0b11_0001,
+            SoftwareStepLowerEL   = 0b11_0010,
+            SoftwareStepCurrentEL = 0b11_0011,
+            WatchpointLowerEL     = 0b11_0100,
+            WatchpointCurrentEL   = 0b11_0101,
+            Brk64                 = 0b11_1100
         ],
 
         /// Instruction Length for synchronous exceptions.
@@ -52,8 +60,9 @@ register_bitfields! {u32,
 
 pub struct Reg;
 
-impl RegisterReadOnly<u32, ESR_EL1::Register> for Reg {
+impl RegisterReadWrite<u32, ESR_EL1::Register> for Reg {
     sys_coproc_read_raw!(u32, "ESR_EL1");
+    sys_coproc_write_raw!(u32, "ESR_EL1");
 }
 
 #[allow(non_upper_case_globals)]
@@ -28,6 +28,7 @@ register_bitfields! {u32,
             TrappedMCRorMRC       = 0b00_0011,
             TrappedMCRRorMRRC     = 0b00_0100,
             TrappedFP             = 0b00_0111,
+            BranchTarget          = 0b00_1101,
             IllegalExecutionState = 0b00_1110,
             SVC64                 = 0b01_0101,
             HVC64                 = 0b01_0110,
@@ -39,7 +40,14 @@ register_bitfields! {u32,
             DataAbortLowerEL      = 0b10_0100,
             DataAbortCurrentEL    = 0b10_0101,
             SPAlignmentFault      = 0b10_0110,
-            TrappedFP64           = 0b10_1100
+            TrappedFP64           = 0b10_1100,
+            BreakpointLowerEL     = 0b11_0000,
+            BreakpointCurrentEL   = 0b11_0001,
+            SoftwareStepLowerEL   = 0b11_0010,
+            SoftwareStepCurrentEL = 0b11_0011,
+            WatchpointLowerEL     = 0b11_0100,
+            WatchpointCurrentEL   = 0b11_0101,
+            Brk64                 = 0b11_1100
         ],
 
         /// Instruction Length for synchronous exceptions.
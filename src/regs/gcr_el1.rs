@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Tag Control Register - EL1
+//!
+//! Controls the generation of random Allocation Tags by the IRG instruction and the Allocation
+//! Tags used by ADDG and SUBG.
+
+use crate::regs::SysReg;
+use register::register_bitfields;
+
+register_bitfields! {u64,
+    pub GCR_EL1 [
+        /// Non-zero value of this bit selects the random Allocation Tag generator of IRG.
+        ///
+        /// 0 IRG generates tags using the seed in RGSR_EL1.
+        /// 1 IRG generates tags using an IMPLEMENTATION DEFINED algorithm, RGSR_EL1 is unused.
+        RRND    OFFSET(16) NUMBITS(1) [],
+
+        /// Allocation Tag values excluded from random tag generation. Bit n set excludes tag n
+        /// from the tags generated by IRG, ADDG and SUBG.
+        Exclude OFFSET(0)  NUMBITS(16) []
+    ]
+}
+
+pub static GCR_EL1: SysReg<GCR_EL1::Register, 3, 0, 1, 0, 6> = SysReg::new();
@@ -10,10 +10,186 @@
 //! Provides configuration controls for virtualization, including defining
 //! whether various Non-secure operations are trapped to EL2.
 
+use super::{ContextSync, ReservedBits};
 use register::{cpu::RegisterReadWrite, register_bitfields};
 
 register_bitfields! {u64,
     pub HCR_EL2 [
+        /// TWE Delay. The delay before a WFE instruction at EL1 or EL0 is trapped to EL2, when
+        /// HCR_EL2.TWEDEn is 1, is at least 2^(TWEDEL + 8) cycles.
+        ///
+        /// When ARMv8.6-TWED is not implemented, this field is RES0.
+        TWEDEL   OFFSET(60) NUMBITS(4) [],
+
+        /// TWE Delay Enable. Enables a configurable delayed trap of the WFE instruction caused by
+        /// HCR_EL2.TWE.
+        ///
+        /// When ARMv8.6-TWED is not implemented, this bit is RES0.
+        TWEDEn   OFFSET(59) NUMBITS(1) [],
+
+        /// Trap ID group 5. Traps EL0 and EL1 reads of GMID_EL1 to EL2.
+        ///
+        /// When ARMv8.5-MemTag is not implemented, this bit is RES0.
+        TID5     OFFSET(58) NUMBITS(1) [],
+
+        /// Default Cacheability Tagging. When HCR_EL2.DC is 1, stage 1 translations of the EL1&0
+        /// translation regime are treated as Tagged when this bit is 1.
+        ///
+        /// When ARMv8.5-MemTag is not implemented, this bit is RES0.
+        DCT      OFFSET(57) NUMBITS(1) [],
+
+        /// Allocation Tag Access. When 0, accesses to Allocation Tags at EL1 and EL0 are prevented
+        /// and accesses to the MTE System registers are trapped to EL2.
+        ///
+        /// When ARMv8.5-MemTag is not implemented, this bit is RES0.
+        ATA      OFFSET(56) NUMBITS(1) [],
+
+        /// Trap TLB maintenance instructions that operate on the Outer Shareable domain, executed
+        /// at EL1, to EL2.
+        ///
+        /// When ARMv8.4-TLBI is not implemented, this bit is RES0.
+        TTLBOS   OFFSET(55) NUMBITS(1) [],
+
+        /// Trap TLB maintenance instructions that operate on the Inner Shareable domain, executed
+        /// at EL1, to EL2.
+        ///
+        /// When ARMv8.4-TLBI is not implemented, this bit is RES0.
+        TTLBIS   OFFSET(54) NUMBITS(1) [],
+
+        /// Enable Access to the SCXTNUM_EL1 and SCXTNUM_EL0 registers. When 0, these accesses from
+        /// EL1 and EL0 are trapped to EL2.
+        ///
+        /// When ARMv8.0-CSV2 context number support is not implemented, this bit is RES0.
+        EnSCXT   OFFSET(53) NUMBITS(1) [],
+
+        /// Trap cache maintenance instructions that operate to the Point of Unification, executed
+        /// at EL1 or EL0, to EL2.
+        ///
+        /// When ARMv8.4-EVT is not implemented, this bit is RES0.
+        TOCU     OFFSET(52) NUMBITS(1) [],
+
+        /// Activity Monitors Virtual Offsets Enable. Enables the AMEVCNTVOFF0<n>_EL2 and
+        /// AMEVCNTVOFF1<n>_EL2 offsets for reads of the activity monitor counters from EL1 and EL0.
+        ///
+        /// When ARMv8.6-AMU is not implemented, this bit is RES0.
+        AMVOFFEN OFFSET(51) NUMBITS(1) [],
+
+        /// Trap ICIALLUIS/IC IALLUIS cache maintenance instructions, executed at EL1, to EL2.
+        ///
+        /// When ARMv8.4-EVT is not implemented, this bit is RES0.
+        TICAB    OFFSET(50) NUMBITS(1) [],
+
+        /// Trap ID group 4. Traps EL1 and EL0 accesses to CCSIDR_EL1, CCSIDR2_EL1, CLIDR_EL1 and
+        /// CSSELR_EL1 to EL2.
+        ///
+        /// When ARMv8.4-EVT is not implemented, this bit is RES0.
+        TID4     OFFSET(49) NUMBITS(1) [],
+
+        // Bit 48 is RES0.
+
+        /// Fault Injection Enable. When 0, accesses to the ERXPFGCDN_EL1, ERXPFGCTL_EL1 and
+        /// ERXPFGF_EL1 registers from EL1 are trapped to EL2.
+        ///
+        /// When ARMv8.4-RAS is not implemented, this bit is RES0.
+        FIEN     OFFSET(47) NUMBITS(1) [],
+
+        /// Forced Write-Back. When 1, stage 2 translations determine the final memory type
+        /// independently of stage 1, and can force Normal Write-Back memory.
+        ///
+        /// When ARMv8.4-S2FWB is not implemented, this bit is RES0.
+        FWB      OFFSET(46) NUMBITS(1) [],
+
+        /// Nested Virtualization. Redirects EL1 System register accesses to memory at VNCR_EL2,
+        /// when HCR_EL2.NV is 1.
+        ///
+        /// When ARMv8.4-NV is not implemented, this bit is RES0.
+        NV2      OFFSET(45) NUMBITS(1) [],
+
+        /// Address Translation. Traps EL1 execution of AT S1E0R, AT S1E0W, AT S1E1R, AT S1E1W,
+        /// AT S1E1RP and AT S1E1WP to EL2.
+        ///
+        /// When ARMv8.4-NV is not implemented, this bit is RES0.
+        AT       OFFSET(44) NUMBITS(1) [],
+
+        /// Nested Virtualization. When HCR_EL2.NV is 1, traps EL1 accesses to the registers that
+        /// a guest hypervisor using VHE does not use, e.g. SPSR_EL1 and ELR_EL1, to EL2.
+        ///
+        /// When ARMv8.3-NV is not implemented, this bit is RES0.
+        NV1      OFFSET(43) NUMBITS(1) [],
+
+        /// Nested Virtualization. Traps EL1 accesses to EL2 System registers and EL2 instructions
+        /// to EL2, and makes CurrentEL report EL2 at EL1.
+        ///
+        /// When ARMv8.3-NV is not implemented, this bit is RES0.
+        NV       OFFSET(42) NUMBITS(1) [],
+
+        /// Controls the use of instructions related to Pointer Authentication at EL1 and EL0.
+        ///
+        /// 0 The use of PAC instructions is trapped to EL2.
+        /// 1 This control does not cause any instructions to be trapped.
+        ///
+        /// When ARMv8.3-PAuth is not implemented, this bit is RES0.
+        API      OFFSET(41) NUMBITS(1) [],
+
+        /// Trap registers holding "key" values for Pointer Authentication.
+        ///
+        /// 0 EL1 accesses to the APxxKey_EL1 registers are trapped to EL2.
+        /// 1 This control does not cause any instructions to be trapped.
+        ///
+        /// When ARMv8.3-PAuth is not implemented, this bit is RES0.
+        APK      OFFSET(40) NUMBITS(1) [],
+
+        /// Enables the Transactional Memory Extension at EL0 and EL1. When 0, TSTART at EL0 and
+        /// EL1 is UNDEFINED.
+        ///
+        /// When TME is not implemented, this bit is RES0.
+        TME      OFFSET(39) NUMBITS(1) [],
+
+        /// Mismatched Inner/Outer Cacheable Non-Coherency Enable, for the EL1&0 translation
+        /// regimes.
+        ///
+        /// 0 For the EL1&0 translation regimes, for permitted accesses to a memory location that
+        ///   use a common definition of the Shareability and Cacheability of the location, there
+        ///   must be no loss of coherency if the Inner Cacheability attribute for those accesses
+        ///   differs from the Outer Cacheability attribute.
+        /// 1 For the EL1&0 translation regimes, for permitted accesses to a memory location that
+        ///   use a common definition of the Shareability and Cacheability of the location, there
+        ///   might be a loss of coherency if the Inner Cacheability attribute for those accesses
+        ///   differs from the Outer Cacheability attribute.
+        MIOCNCE  OFFSET(38) NUMBITS(1) [],
+
+        /// Route synchronous External abort exceptions to EL2.
+        ///
+        /// When ARMv8.2-RAS is not implemented, this bit is RES0.
+        TEA      OFFSET(37) NUMBITS(1) [],
+
+        /// Trap Error record accesses. Traps EL1 accesses to the RAS error record registers to EL2.
+        ///
+        /// When ARMv8.2-RAS is not implemented, this bit is RES0.
+        TERR     OFFSET(36) NUMBITS(1) [],
+
+        /// Trap LORegion registers. Traps EL1 accesses to LORSA_EL1, LOREA_EL1, LORN_EL1, LORC_EL1
+        /// and LORID_EL1 to EL2.
+        ///
+        /// When ARMv8.1-LOR is not implemented, this bit is RES0.
+        TLOR     OFFSET(35) NUMBITS(1) [],
+
+        /// EL2 Host. Enables a configuration where a Host Operating System is running in EL2, and
+        /// the Host Operating System's applications are running in EL0.
+        ///
+        /// When ARMv8.1-VHE is not implemented, this bit is RES0.
+        E2H      OFFSET(34) NUMBITS(1) [],
+
+        /// Stage 2 Instruction access cacheability disable. For the EL1&0 translation regime, when
+        /// HCR_EL2.VM is 1, forces all stage 2 translations for instruction accesses to Normal
+        /// memory to be Non-cacheable.
+        ID       OFFSET(33) NUMBITS(1) [],
+
+        /// Stage 2 Data access cacheability disable. For the EL1&0 translation regime, when
+        /// HCR_EL2.VM is 1, forces all stage 2 translations for data accesses and translation
+        /// table walks to Normal memory to be Non-cacheable.
+        CD       OFFSET(32) NUMBITS(1) [],
+
         /// Execution state control for lower Exception levels:
         ///
         /// 0 Lower levels are all AArch32.
@@ -30,11 +206,92 @@ register_bitfields! {u64,
         ///
         /// When ARMv8.1-VHE is implemented, and the value of HCR_EL2.{E2H, TGE} is {1, 1}, this
         /// field behaves as 1 for all purposes other than a direct read of the value of this bit.
-        RW   OFFSET(31) NUMBITS(1) [
+        RW       OFFSET(31) NUMBITS(1) [
             AllLowerELsAreAarch32 = 0,
             EL1IsAarch64 = 1
         ],
 
+        /// Trap Reads of Virtual Memory controls. Traps EL1 reads of the virtual memory control
+        /// registers (SCTLR_EL1, TTBR0_EL1, TTBR1_EL1, TCR_EL1, ESR_EL1, FAR_EL1, AFSR0_EL1,
+        /// AFSR1_EL1, MAIR_EL1, AMAIR_EL1 and CONTEXTIDR_EL1) to EL2.
+        TRVM     OFFSET(30) NUMBITS(1) [],
+
+        /// HVC instruction disable. When EL3 is implemented, this bit is RES0.
+        ///
+        /// 0 HVC instruction execution is enabled at EL2 and EL1.
+        /// 1 HVC instructions are UNDEFINED at EL2 and EL1.
+        HCD      OFFSET(29) NUMBITS(1) [],
+
+        /// Trap DC ZVA instructions. Traps EL0 and EL1 execution of DC ZVA instructions to EL2,
+        /// and makes reads of DCZID_EL0.DZP from EL0 and EL1 return 1.
+        TDZ      OFFSET(28) NUMBITS(1) [],
+
+        /// Trap General Exceptions, from EL0.
+        ///
+        /// 0 This control has no effect on execution at EL0.
+        /// 1 All exceptions that would be routed to EL1 are routed to EL2, and the PE behaves as if
+        ///   SCTLR_EL1.M is 0 for all purposes other than a direct read of SCTLR_EL1.
+        ///
+        /// With HCR_EL2.E2H, this selects the EL2&0 translation regime for EL0.
+        TGE      OFFSET(27) NUMBITS(1) [],
+
+        /// Trap Virtual Memory controls. Traps EL1 writes to the virtual memory control registers
+        /// (SCTLR_EL1, TTBR0_EL1, TTBR1_EL1, TCR_EL1, ESR_EL1, FAR_EL1, AFSR0_EL1, AFSR1_EL1,
+        /// MAIR_EL1, AMAIR_EL1 and CONTEXTIDR_EL1) to EL2.
+        TVM      OFFSET(26) NUMBITS(1) [],
+
+        /// Trap TLB maintenance instructions. Traps EL1 execution of TLBI instructions to EL2.
+        TTLB     OFFSET(25) NUMBITS(1) [],
+
+        /// Trap cache maintenance instructions that operate to the Point of Unification. Traps
+        /// execution of IC IVAU, IC IALLU and DC CVAU at EL0 and EL1, and IC IALLUIS at EL1, to
+        /// EL2.
+        TPU      OFFSET(24) NUMBITS(1) [],
+
+        /// Trap data or unified cache maintenance instructions that operate to the Point of
+        /// Coherency or Persistence. Traps execution of DC CIVAC, DC CVAC, DC CVAP, DC CVADP and,
+        /// at EL1, DC IVAC, to EL2.
+        ///
+        /// Named TPC when ARMv8.2-DCPoP is implemented.
+        TPCP     OFFSET(23) NUMBITS(1) [],
+
+        /// Trap data or unified cache maintenance instructions that operate by Set/Way. Traps EL1
+        /// execution of DC ISW, DC CSW and DC CISW to EL2.
+        TSW      OFFSET(22) NUMBITS(1) [],
+
+        /// Trap Auxiliary Control Registers. Traps EL1 accesses to ACTLR_EL1 to EL2.
+        TACR     OFFSET(21) NUMBITS(1) [],
+
+        /// Trap IMPLEMENTATION DEFINED functionality. Traps EL1 accesses to the System register
+        /// encodings reserved for IMPLEMENTATION DEFINED functionality to EL2.
+        TIDCP    OFFSET(20) NUMBITS(1) [],
+
+        /// Trap SMC instructions. Traps EL1 execution of SMC instructions to EL2.
+        TSC      OFFSET(19) NUMBITS(1) [],
+
+        /// Trap ID group 3. Traps EL1 and EL0 reads of the AArch64 and AArch32 feature ID
+        /// registers, e.g. ID_AA64PFR0_EL1 and ID_AA64ISAR0_EL1, to EL2.
+        TID3     OFFSET(18) NUMBITS(1) [],
+
+        /// Trap ID group 2. Traps EL1 and EL0 accesses to CTR_EL0, CCSIDR_EL1, CCSIDR2_EL1,
+        /// CLIDR_EL1 and CSSELR_EL1 to EL2.
+        TID2     OFFSET(17) NUMBITS(1) [],
+
+        /// Trap ID group 1. Traps EL1 reads of REVIDR_EL1, AIDR_EL1 and SMIDR_EL1 to EL2.
+        TID1     OFFSET(16) NUMBITS(1) [],
+
+        /// Trap ID group 0. Traps EL0 and EL1 reads of the AArch32 JIDR and FPSID registers to
+        /// EL2.
+        TID0     OFFSET(15) NUMBITS(1) [],
+
+        /// Traps EL0 and EL1 execution of WFE instructions to EL2, when the instruction would
+        /// otherwise have caused the PE to enter a low-power state.
+        TWE      OFFSET(14) NUMBITS(1) [],
+
+        /// Traps EL0 and EL1 execution of WFI instructions to EL2, when the instruction would
+        /// otherwise have caused the PE to enter a low-power state.
+        TWI      OFFSET(13) NUMBITS(1) [],
+
         /// Default Cacheability.
         ///
         /// 0 This control has no effect on the Non-secure EL1&0 translation regime.
@@ -63,11 +320,11 @@ register_bitfields! {u64,
         ///
         /// When ARMv8.1-VHE is implemented, and the value of HCR_EL2.{E2H, TGE} is {1, 1}, this
         /// field behaves as 0 for all purposes other than a direct read of the value of this field.
-        DC   OFFSET(12) NUMBITS(1) [],
+        DC       OFFSET(12) NUMBITS(1) [],
 
         /// Barrier Shareability upgrade. The value in this field determines the minimum
         /// shareability domain that is applied to any barrier executed from Non-secure EL1 or EL0.
-        BSU  OFFSET(10) NUMBITS(2) [
+        BSU      OFFSET(10) NUMBITS(2) [
             NoEffect = 0,
             InnerShareable = 1,
             OuterShareable = 2,
@@ -82,7 +339,7 @@ register_bitfields! {u64,
         ///
         /// AArch64: TLBI VMALLE1, TLBI VAE1, TLBI ASIDE1, TLBI VAAE1, TLBI VALE1, TLBI
         /// VAALE1, IC IALLU.
-        FB   OFFSET(9) NUMBITS(1) [],
+        FB       OFFSET(9) NUMBITS(1) [],
 
         /// Virtual System Error/Asynchronous Abort
         ///
@@ -92,7 +349,7 @@ register_bitfields! {u64,
         ///
         /// The virtual System Error/Asynchronous Abort is only enabled when the HCR_EL2.AMO bit is
         /// set.
-        VSE  OFFSET(8) NUMBITS(1) [],
+        VSE      OFFSET(8) NUMBITS(1) [],
 
         /// Virtual IRQ Interrupt
         ///
@@ -101,7 +358,7 @@ register_bitfields! {u64,
         /// 1 Virtual IRQ is pending by this mechanism.
         ///
         /// The virtual IRQ is only enabled when the HCR_EL2.IMO bit is set.
-        VI   OFFSET(7) NUMBITS(1) [],
+        VI       OFFSET(7) NUMBITS(1) [],
 
         /// Virtual FIQ Interrupt
         ///
@@ -110,7 +367,7 @@ register_bitfields! {u64,
         /// 1 Virtual FIQ is pending by this mechanism.
         ///
         /// The virtual FIQ is only enabled when the HCR_EL2.FMO bit is set.
-        VF   OFFSET(6) NUMBITS(1) [],
+        VF       OFFSET(6) NUMBITS(1) [],
 
         /// Asynchronous External Abort and SError Interrupt routing.
         ///
@@ -127,7 +384,7 @@ register_bitfields! {u64,
         ///
         ///   Virtual Asynchronous External Aborts and SError Interrupts are enabled in Non-secure
         ///   state.
-        AMO  OFFSET(5) NUMBITS(1) [],
+        AMO      OFFSET(5) NUMBITS(1) [],
 
         /// Physical IRQ Routing
         ///
@@ -143,7 +400,7 @@ register_bitfields! {u64,
         ///   taken to EL2 unless they are routed to EL3.
         ///
         ///   Virtual IRQ interrupts are enabled in Non-secure state.
-        IMO  OFFSET(4) NUMBITS(1) [],
+        IMO      OFFSET(4) NUMBITS(1) [],
 
         /// Physical FIQ Routing
         ///
@@ -159,7 +416,7 @@ register_bitfields! {u64,
         ///   taken to EL2 unless they are routed to EL3.
         ///
         ///   Virtual FIQ interrupts are enabled in Non-secure state.
-        FMO  OFFSET(3) NUMBITS(1) [],
+        FMO      OFFSET(3) NUMBITS(1) [],
 
         /// Protected Table Walk.
         ///
@@ -173,7 +430,7 @@ register_bitfields! {u64,
         ///
         /// 1 The memory access generates a stage 2 Permission fault
         ///
-        PTW  OFFSET(2) NUMBITS(1) [],
+        PTW      OFFSET(2) NUMBITS(1) [],
 
         /// Set/Way Invalidation Override. Causes Non-secure EL1 execution of the data cache
         /// invalidate by set/way instructions to perform a data cache clean and invalidate by
@@ -199,7 +456,7 @@ register_bitfields! {u64,
         ///
         /// When HCR_EL2.TGE is 1, the PE ignores the value of this field for all purposes other
         /// than a direct read of this field.
-        SWIO OFFSET(1) NUMBITS(1) [],
+        SWIO     OFFSET(1) NUMBITS(1) [],
 
         /// Virtualization MMU enable for Non-secure EL1 and EL0 stage 2 address translation.
         ///
@@ -207,7 +464,7 @@ register_bitfields! {u64,
         ///
         /// 1 EL1 and EL0 stage 2 address translation enabbled
         ///
-        VM OFFSET(0) NUMBITS(1) []
+        VM       OFFSET(0) NUMBITS(1) []
     ]
 }
 
@@ -218,4 +475,14 @@ impl RegisterReadWrite<u64, HCR_EL2::Register> for Reg {
     sys_coproc_write_raw!(u64, "HCR_EL2");
 }
 
+impl ContextSync<u64, HCR_EL2::Register> for Reg {}
+
+impl ReservedBits<u64, HCR_EL2::Register> for Reg {
+    const RES1: u64 = 0;
+    const FIXED_RES1: u64 = 0;
+
+    /// Bit 48.
+    const RES0: u64 = 1 << 48;
+}
+
 pub static HCR_EL2: Reg = Reg {};
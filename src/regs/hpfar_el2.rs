@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Hypervisor IPA Fault Address Register - EL2
+//!
+//! Holds the faulting IPA for some aborts on a stage 2 translation taken to EL2.
+//!
+//! The register only holds bits [51:12] of the IPA. The page offset of the faulting address has to
+//! be taken from `FAR_EL2`, see `virt::fault`.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u64,
+    pub HPFAR_EL2 [
+        /// Faulting IPA address space.
+        ///
+        /// 0 Faulting IPA is from the Secure IPA space.
+        /// 1 Faulting IPA is from the Non-secure IPA space.
+        ///
+        /// When ARMv8.4-SecEL2 is not implemented, this bit is RES0.
+        NS   OFFSET(63) NUMBITS(1) [
+            Secure = 0,
+            NonSecure = 1
+        ],
+
+        /// Faulting Intermediate Physical Address. Holds bits [51:12] of the faulting IPA.
+        ///
+        /// When ARMv8.2-LPA is not implemented, bits [43:40] are RES0.
+        FIPA OFFSET(4) NUMBITS(40) []
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, HPFAR_EL2::Register> for Reg {
+    sys_coproc_read_raw!(u64, "HPFAR_EL2");
+    sys_coproc_write_raw!(u64, "HPFAR_EL2");
+}
+
+pub static HPFAR_EL2: Reg = Reg {};
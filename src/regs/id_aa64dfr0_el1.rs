@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! AArch64 Debug Feature Register 0 - EL1
+//!
+//! Provides top level information about the debug system in AArch64 state.
+
+use register::{cpu::RegisterReadOnly, register_bitfields};
+
+register_bitfields! {u64,
+    pub ID_AA64DFR0_EL1 [
+        /// Multi-threaded PMU extension. Defined values are:
+        ///
+        /// 0000 ARMv8.6-MTPMU not implemented. If ARMv8.6-MTPMU is not implemented, it is
+        ///      IMPLEMENTATION DEFINED whether PMEVTYPER<n>_EL0.MT are read/write or RES0.
+        /// 0001 ARMv8.6-MTPMU implemented.
+        /// 1111 ARMv8.6-MTPMU not implemented. PMEVTYPER<n>_EL0.MT are RES0.
+        ///
+        /// All other values are reserved.
+        MTPMU OFFSET(48) NUMBITS(4) [],
+
+        /// Indicates support for ARMv8.4 Self-hosted Trace Extension. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        TraceFilt OFFSET(40) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// OS Double Lock implemented. Defined values are:
+        ///
+        /// 0000 OS Double Lock implemented. OSDLR_EL1 is RW.
+        /// 1111 OS Double Lock not implemented. OSDLR_EL1 is RAZ/WI.
+        ///
+        /// All other values are reserved.
+        DoubleLock OFFSET(36) NUMBITS(4) [
+            Implemented = 0b0000,
+            NotImplemented = 0b1111
+        ],
+
+        /// Statistical Profiling Extension version. Defined values are:
+        ///
+        /// 0000 Statistical Profiling Extension not implemented.
+        /// 0001 Statistical Profiling Extension implemented.
+        /// 0010 As 0001, and adds support for the Event packet Alignment flag, and PMSEVFR_EL1
+        ///      bit[11].
+        ///
+        /// All other values are reserved.
+        PMSVer OFFSET(32) NUMBITS(4) [],
+
+        /// Number of breakpoints that are context-aware, minus 1. These are the highest numbered
+        /// breakpoints.
+        CTX_CMPs OFFSET(28) NUMBITS(4) [],
+
+        /// Number of watchpoints, minus 1. The value of 0b0000 is reserved.
+        WRPs OFFSET(20) NUMBITS(4) [],
+
+        /// Number of breakpoints, minus 1. The value of 0b0000 is reserved.
+        BRPs OFFSET(12) NUMBITS(4) [],
+
+        /// Performance Monitors Extension version. Defined values are:
+        ///
+        /// 0000 Performance Monitors Extension not implemented.
+        /// 0001 Performance Monitors Extension, PMUv3 implemented.
+        /// 0100 PMUv3 for ARMv8.1.
+        /// 0101 PMUv3 for ARMv8.4.
+        /// 0110 PMUv3 for ARMv8.5.
+        /// 0111 PMUv3 for ARMv8.7.
+        /// 1111 IMPLEMENTATION DEFINED form of performance monitors supported, PMUv3 not supported.
+        ///
+        /// All other values are reserved.
+        PMUVer OFFSET(8) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            PMUv3 = 0b0001,
+            PMUv3p1 = 0b0100,
+            PMUv3p4 = 0b0101,
+            PMUv3p5 = 0b0110,
+            PMUv3p7 = 0b0111,
+            ImplementationDefined = 0b1111
+        ],
+
+        /// Trace support. Indicates whether System register interface to a PE trace unit is
+        /// implemented. Defined values are:
+        ///
+        /// 0000 PE trace unit System registers not implemented.
+        /// 0001 PE trace unit System registers implemented.
+        ///
+        /// All other values are reserved.
+        TraceVer OFFSET(4) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Debug architecture version. Indicates presence of Armv8-A debug architecture. Defined
+        /// values are:
+        ///
+        /// 0110 Armv8-A debug architecture.
+        /// 0111 Armv8-A debug architecture with Virtualization Host Extensions.
+        /// 1000 Armv8.2-A debug architecture.
+        /// 1001 Armv8.4-A debug architecture.
+        ///
+        /// All other values are reserved.
+        DebugVer OFFSET(0) NUMBITS(4) [
+            ARMv8 = 0b0110,
+            ARMv8_VHE = 0b0111,
+            ARMv8p2 = 0b1000,
+            ARMv8p4 = 0b1001
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadOnly<u64, ID_AA64DFR0_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u64, "ID_AA64DFR0_EL1");
+}
+
+pub static ID_AA64DFR0_EL1: Reg = Reg {};
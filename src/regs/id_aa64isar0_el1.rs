@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! AArch64 Instruction Set Attribute Register 0 - EL1
+//!
+//! Provides information about the instructions implemented in AArch64 state.
+
+use register::{cpu::RegisterReadOnly, register_bitfields};
+
+register_bitfields! {u64,
+    pub ID_AA64ISAR0_EL1 [
+        /// Indicates support for Random Number instructions (ARMv8.5-RNG). Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.. RNDR and RNDRRS registers are implemented
+        ///
+        /// All other values are reserved.
+        RNDR OFFSET(60) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for Outer shareable and TLB range maintenance instructions. Defined
+        /// values are:
+        ///
+        /// 0000 Outer shareable and TLB range maintenance instructions are not implemented.
+        /// 0001 Outer shareable TLB maintenance instructions are implemented.
+        /// 0010 Outer shareable and TLB range maintenance instructions are implemented.
+        ///
+        /// All other values are reserved.
+        TLB OFFSET(56) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            OuterShareable = 0b0001,
+            OuterShareableAndRange = 0b0010
+        ],
+
+        /// Indicates support for flag manipulation instructions. Defined values are:
+        ///
+        /// 0000 No flag manipulation instructions are implemented.
+        /// 0001 CFINV, RMIF, SETF16, and SETF8 instructions are implemented.
+        /// 0010 As 0001, and AXFLAG and XAFLAG instructions are implemented.
+        ///
+        /// All other values are reserved.
+        TS OFFSET(52) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            FlagM = 0b0001,
+            FlagM2 = 0b0010
+        ],
+
+        /// Indicates support for FMLAL and FMLSL instructions. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        FHM OFFSET(48) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for Dot Product instructions (UDOT and SDOT). Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        DP OFFSET(44) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for SM4 instructions. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        SM4 OFFSET(40) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for SM3 instructions. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        SM3 OFFSET(36) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for SHA3 instructions (EOR3, RAX1, XAR, and BCAX). Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        SHA3 OFFSET(32) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for SQRDMLAH and SQRDMLSH instructions. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        RDM OFFSET(28) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for TME instructions. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        TME OFFSET(24) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for Atomic instructions. Defined values are:
+        ///
+        /// 0000 No Atomic instructions implemented.
+        /// 0010 LDADD, LDCLR, LDEOR, LDSET, LDSMAX, LDSMIN, LDUMAX, LDUMIN, CAS, CASP, and SWP
+        ///      instructions implemented (ARMv8.1-LSE).
+        ///
+        /// All other values are reserved.
+        Atomic OFFSET(20) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            LSE = 0b0010
+        ],
+
+        /// Indicates support for CRC32 instructions. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        CRC32 OFFSET(16) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for SHA2 instructions. Defined values are:
+        ///
+        /// 0000 No SHA2 instructions implemented.
+        /// 0001 SHA256H, SHA256H2, SHA256SU0, and SHA256SU1 instructions implemented.
+        /// 0010 As 0001, and SHA512H, SHA512H2, SHA512SU0, and SHA512SU1 instructions implemented.
+        ///
+        /// All other values are reserved.
+        SHA2 OFFSET(12) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            SHA256 = 0b0001,
+            SHA512 = 0b0010
+        ],
+
+        /// Indicates support for SHA1 instructions. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        SHA1 OFFSET(8) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for AES instructions. Defined values are:
+        ///
+        /// 0000 No AES instructions implemented.
+        /// 0001 AESE, AESD, AESMC, and AESIMC instructions implemented.
+        /// 0010 As for 0001, plus PMULL/PMULL2 instructions operating on 64-bit data quantities.
+        ///
+        /// All other values are reserved.
+        AES OFFSET(4) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            AES = 0b0001,
+            AESAndPMULL = 0b0010
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadOnly<u64, ID_AA64ISAR0_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u64, "ID_AA64ISAR0_EL1");
+}
+
+pub static ID_AA64ISAR0_EL1: Reg = Reg {};
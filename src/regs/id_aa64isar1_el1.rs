@@ -0,0 +1,239 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! AArch64 Instruction Set Attribute Register 1 - EL1
+//!
+//! Provides information about the features and instructions implemented in AArch64 state.
+
+use register::{cpu::RegisterReadOnly, register_bitfields};
+
+register_bitfields! {u64,
+    pub ID_AA64ISAR1_EL1 [
+        /// Indicates support for LD64B and ST64B* instructions. Defined values are:
+        ///
+        /// 0000 The LD64B, ST64B, ST64BV, and ST64BV0 instructions are not supported.
+        /// 0001 The LD64B and ST64B instructions are supported.
+        /// 0010 As 0001, and the ST64BV instruction is supported.
+        /// 0011 As 0010, and the ST64BV0 instruction is supported.
+        ///
+        /// All other values are reserved.
+        LS64 OFFSET(60) NUMBITS(4) [],
+
+        /// Indicates support for the XS attribute, the TLBI and DSB instructions with the nXS
+        /// qualifier. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        XS OFFSET(56) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for Advanced SIMD and Floating-point Int8 matrix multiplication
+        /// instructions. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        I8MM OFFSET(52) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for the Data Gathering Hint instruction. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        DGH OFFSET(48) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for Advanced SIMD and Floating-point BFloat16 instructions. Defined
+        /// values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        BF16 OFFSET(44) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for prediction invalidation instructions (CFP RCTX, DVP RCTX and CPP
+        /// RCTX). Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        SPECRES OFFSET(40) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for the SB instruction. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        SB OFFSET(36) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for the FRINT32Z, FRINT32X, FRINT64Z, and FRINT64X instructions.
+        /// Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        FRINTTS OFFSET(32) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for an IMPLEMENTATION DEFINED algorithm for generic code
+        /// authentication in AArch64 state. Defined values are:
+        ///
+        /// 0000 Generic Authentication using an IMPLEMENTATION DEFINED algorithm is not
+        ///      implemented.
+        /// 0001 Generic Authentication using an IMPLEMENTATION DEFINED algorithm is implemented.
+        ///      This includes the PACGA instruction.
+        ///
+        /// All other values are reserved.
+        GPI OFFSET(28) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates whether the QARMA5 algorithm is implemented in the PE for generic code
+        /// authentication in AArch64 state. Defined values are:
+        ///
+        /// 0000 Generic Authentication using the QARMA5 algorithm is not implemented.
+        /// 0001 Generic Authentication using the QARMA5 algorithm is implemented. This includes the
+        ///      PACGA instruction.
+        ///
+        /// All other values are reserved.
+        GPA OFFSET(24) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for weaker release consistency, RCpc, based model. Defined values are:
+        ///
+        /// 0000 The LDAPR*, LDAPUR*, and STLUR* instructions are not implemented.
+        /// 0001 The LDAPR* instructions are implemented.
+        /// 0010 As 0001, and the LDAPUR* and STLUR* instructions are implemented.
+        ///
+        /// All other values are reserved.
+        LRCPC OFFSET(20) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            RCpc = 0b0001,
+            RCpc2 = 0b0010
+        ],
+
+        /// Indicates support for complex number addition and multiplication (FCMLA and FCADD).
+        /// Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        FCMA OFFSET(16) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for JavaScript conversion from double precision floating point values
+        /// to integers (FJCVTZS). Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        JSCVT OFFSET(12) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates whether an IMPLEMENTATION DEFINED algorithm is implemented in the PE for
+        /// address authentication, in AArch64 state. This field does not indicate whether generic
+        /// code authentication is supported. Defined values are:
+        ///
+        /// 0000 Address Authentication using an IMPLEMENTATION DEFINED algorithm is not
+        ///      implemented.
+        /// 0001 Address Authentication using an IMPLEMENTATION DEFINED algorithm is implemented,
+        ///      with the HaveEnhancedPAC() and HaveEnhancedPAC2() functions returning FALSE.
+        /// 0010 As 0001, with HaveEnhancedPAC() returning TRUE.
+        /// 0011 As 0001, with HaveEnhancedPAC2() returning TRUE.
+        /// 0100 As 0011, with HaveFPAC() returning TRUE.
+        /// 0101 As 0011, with HaveFPACCombined() returning TRUE.
+        ///
+        /// All other values are reserved.
+        API OFFSET(8) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            PAuth = 0b0001,
+            EPAC = 0b0010,
+            PAuth2 = 0b0011,
+            FPAC = 0b0100,
+            FPACCombined = 0b0101
+        ],
+
+        /// Indicates whether the QARMA5 algorithm is implemented in the PE for address
+        /// authentication, in AArch64 state. This field does not indicate whether generic code
+        /// authentication is supported. Defined values are:
+        ///
+        /// 0000 Address Authentication using the QARMA5 algorithm is not implemented.
+        /// 0001 Address Authentication using the QARMA5 algorithm is implemented, with the
+        ///      HaveEnhancedPAC() and HaveEnhancedPAC2() functions returning FALSE.
+        /// 0010 As 0001, with HaveEnhancedPAC() returning TRUE.
+        /// 0011 As 0001, with HaveEnhancedPAC2() returning TRUE.
+        /// 0100 As 0011, with HaveFPAC() returning TRUE.
+        /// 0101 As 0011, with HaveFPACCombined() returning TRUE.
+        ///
+        /// All other values are reserved.
+        APA OFFSET(4) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            PAuth = 0b0001,
+            EPAC = 0b0010,
+            PAuth2 = 0b0011,
+            FPAC = 0b0100,
+            FPACCombined = 0b0101
+        ],
+
+        /// Data Persistence writeback. Indicates support for the DC CVAP and DC CVADP instructions
+        /// in AArch64 state. Defined values are:
+        ///
+        /// 0000 DC CVAP not supported.
+        /// 0001 DC CVAP supported.
+        /// 0010 DC CVAP and DC CVADP supported.
+        ///
+        /// All other values are reserved.
+        DPB OFFSET(0) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            CVAP = 0b0001,
+            CVAPAndCVADP = 0b0010
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadOnly<u64, ID_AA64ISAR1_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u64, "ID_AA64ISAR1_EL1");
+}
+
+pub static ID_AA64ISAR1_EL1: Reg = Reg {};
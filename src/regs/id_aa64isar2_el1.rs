@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! AArch64 Instruction Set Attribute Register 2 - EL1
+//!
+//! Provides information about the features and instructions implemented in AArch64 state.
+
+use register::{cpu::RegisterReadOnly, register_bitfields};
+
+register_bitfields! {u64,
+    pub ID_AA64ISAR2_EL1 [
+        /// Indicates whether the ConstPACField() function used as part of PAC addition returns
+        /// FALSE or TRUE. Defined values are:
+        ///
+        /// 0000 ConstPACField() returns FALSE.
+        /// 0001 ConstPACField() returns TRUE.
+        ///
+        /// All other values are reserved.
+        PAC_frac OFFSET(24) NUMBITS(4) [],
+
+        /// Indicates support for the BC instruction. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        BC OFFSET(20) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for the Memory Copy and Memory Set instructions. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        MOPS OFFSET(16) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates whether the QARMA3 algorithm is implemented in the PE for address
+        /// authentication, in AArch64 state. This field does not indicate whether generic code
+        /// authentication is supported. Defined values are:
+        ///
+        /// 0000 Address Authentication using the QARMA3 algorithm is not implemented.
+        /// 0001 Address Authentication using the QARMA3 algorithm is implemented, with the
+        ///      HaveEnhancedPAC() and HaveEnhancedPAC2() functions returning FALSE.
+        /// 0010 As 0001, with HaveEnhancedPAC() returning TRUE.
+        /// 0011 As 0001, with HaveEnhancedPAC2() returning TRUE.
+        /// 0100 As 0011, with HaveFPAC() returning TRUE.
+        /// 0101 As 0011, with HaveFPACCombined() returning TRUE.
+        ///
+        /// All other values are reserved.
+        APA3 OFFSET(12) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            PAuth = 0b0001,
+            EPAC = 0b0010,
+            PAuth2 = 0b0011,
+            FPAC = 0b0100,
+            FPACCombined = 0b0101
+        ],
+
+        /// Indicates whether the QARMA3 algorithm is implemented in the PE for generic code
+        /// authentication in AArch64 state. Defined values are:
+        ///
+        /// 0000 Generic Authentication using the QARMA3 algorithm is not implemented.
+        /// 0001 Generic Authentication using the QARMA3 algorithm is implemented. This includes the
+        ///      PACGA instruction.
+        ///
+        /// All other values are reserved.
+        GPA3 OFFSET(8) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for 12 bits of mantissa in reciprocal and reciprocal square root
+        /// instructions in AArch64 state, when FPCR.AH is 1. Defined values are:
+        ///
+        /// 0000 Reciprocal and reciprocal square root estimates give 8 bits of mantissa, when
+        ///      FPCR.AH is 1.
+        /// 0001 Reciprocal and reciprocal square root estimates give 12 bits of mantissa, when
+        ///      FPCR.AH is 1.
+        ///
+        /// All other values are reserved.
+        RPRES OFFSET(4) NUMBITS(4) [],
+
+        /// Indicates support for the WFET and WFIT instructions in AArch64 state. Defined values
+        /// are:
+        ///
+        /// 0000 WFET and WFIT are not supported.
+        /// 0010 WFET and WFIT are supported, and the register number is reported in the ESR_ELx on
+        ///      exceptions.
+        ///
+        /// All other values are reserved.
+        WFxT OFFSET(0) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0010
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadOnly<u64, ID_AA64ISAR2_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u64, "S3_0_C0_C6_2");
+}
+
+pub static ID_AA64ISAR2_EL1: Reg = Reg {};
@@ -14,14 +14,96 @@ use register::{cpu::RegisterReadOnly, register_bitfields};
 
 register_bitfields! {u64,
     pub ID_AA64MMFR0_EL1 [
+        /// Indicates presence of Enhanced Counter Virtualization. Defined values are:
+        ///
+        /// 0000 Enhanced Counter Virtualization is not implemented.
+        /// 0001 Enhanced Counter Virtualization is implemented.
+        /// 0010 As 0001, and also includes support for CNTHCTL_EL2.ECV and CNTPOFF_EL2.
+        ///
+        /// All other values are reserved.
+        ECV OFFSET(60) NUMBITS(4) [],
+
+        /// Indicates presence of the Fine-Grained Trap controls. Defined values are:
+        ///
+        /// 0000 Fine-grained trap controls are not implemented.
+        /// 0001 Fine-grained trap controls are implemented.
+        ///
+        /// All other values are reserved.
+        FGT OFFSET(56) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for disabling context synchronizing exception entry and exit. Defined
+        /// values are:
+        ///
+        /// 0000 All exception entries and exits are context synchronization events.
+        /// 0001 Non-context synchronizing exception entry and exit are supported.
+        ///
+        /// All other values are reserved.
+        ExS OFFSET(44) NUMBITS(4) [],
+
+        /// Indicates support for 4KiB memory translation granule size at stage 2. Defined values
+        /// are:
+        ///
+        /// 0000 Support for 4KiB granule at stage 2 is identified in the ID_AA64MMFR0_EL1.TGran4
+        ///      field.
+        /// 0001 4KiB granule not supported at stage 2.
+        /// 0010 4KiB granule supported at stage 2.
+        /// 0011 4KiB granule supports 52-bit input and output addresses at stage 2
+        ///      (ARMv8.7-LPA2).
+        ///
+        /// All other values are reserved.
+        TGran4_2 OFFSET(40) NUMBITS(4) [
+            AsStage1 = 0b0000,
+            NotSupported = 0b0001,
+            Supported = 0b0010,
+            SupportedLPA2 = 0b0011
+        ],
+
+        /// Indicates support for 64KiB memory translation granule size at stage 2. Defined values
+        /// are:
+        ///
+        /// 0000 Support for 64KiB granule at stage 2 is identified in the ID_AA64MMFR0_EL1.TGran64
+        ///      field.
+        /// 0001 64KiB granule not supported at stage 2.
+        /// 0010 64KiB granule supported at stage 2.
+        ///
+        /// All other values are reserved.
+        TGran64_2 OFFSET(36) NUMBITS(4) [
+            AsStage1 = 0b0000,
+            NotSupported = 0b0001,
+            Supported = 0b0010
+        ],
+
+        /// Indicates support for 16KiB memory translation granule size at stage 2. Defined values
+        /// are:
+        ///
+        /// 0000 Support for 16KiB granule at stage 2 is identified in the ID_AA64MMFR0_EL1.TGran16
+        ///      field.
+        /// 0001 16KiB granule not supported at stage 2.
+        /// 0010 16KiB granule supported at stage 2.
+        /// 0011 16KiB granule supports 52-bit input and output addresses at stage 2
+        ///      (ARMv8.7-LPA2).
+        ///
+        /// All other values are reserved.
+        TGran16_2 OFFSET(32) NUMBITS(4) [
+            AsStage1 = 0b0000,
+            NotSupported = 0b0001,
+            Supported = 0b0010,
+            SupportedLPA2 = 0b0011
+        ],
+
         /// Support for 4KiB memory translation granule size. Defined values are:
         ///
         /// 0000 4KiB granule supported.
+        /// 0001 4KiB granule supports 52-bit input and output addresses (ARMv8.7-LPA2).
         /// 1111 4KiB granule not supported.
         ///
         /// All other values are reserved.
         TGran4  OFFSET(28) NUMBITS(4) [
             Supported = 0b0000,
+            SupportedLPA2 = 0b0001,
             NotSupported = 0b1111
         ],
 
@@ -39,14 +121,54 @@ register_bitfields! {u64,
         /// Support for 16KiB memory translation granule size. Defined values are:
         ///
         /// 0001 16KiB granule supported.
+        /// 0010 16KiB granule supports 52-bit input and output addresses (ARMv8.7-LPA2).
         /// 0000 16KiB granule not supported.
         ///
         /// All other values are reserved.
         TGran16 OFFSET(20) NUMBITS(4) [
             Supported = 0b0001,
+            SupportedLPA2 = 0b0010,
             NotSupported = 0b0000
         ],
 
+        /// Indicates support for mixed-endian at EL0 only. Defined values are:
+        ///
+        /// 0000 No mixed-endian support at EL0. The SCTLR_EL1.E0E bit has a fixed value.
+        /// 0001 Mixed-endian support at EL0. The SCTLR_EL1.E0E bit can be configured.
+        ///
+        /// All other values are reserved.
+        ///
+        /// This field is invalid and is RES0 if the BigEnd field, bits[11:8], is not 0000.
+        BigEndEL0 OFFSET(16) NUMBITS(4) [],
+
+        /// Secure versus Non-secure Memory distinction. Defined values are:
+        ///
+        /// 0000 Does not support a distinction between Secure and Non-secure Memory.
+        /// 0001 Does support a distinction between Secure and Non-secure Memory.
+        ///
+        /// All other values are reserved.
+        SNSMem OFFSET(12) NUMBITS(4) [],
+
+        /// Indicates support for mixed-endian configuration. Defined values are:
+        ///
+        /// 0000 No mixed-endian support. The SCTLR_ELx.EE bits have a fixed value. See the
+        ///      BigEndEL0 field, bits[19:16], for whether EL0 supports mixed-endian.
+        /// 0001 Mixed-endian support. The SCTLR_ELx.EE and SCTLR_EL1.E0E bits can be configured.
+        ///
+        /// All other values are reserved.
+        BigEnd OFFSET(8) NUMBITS(4) [],
+
+        /// Number of ASID bits. Defined values are:
+        ///
+        /// 0000 8 bits.
+        /// 0010 16 bits.
+        ///
+        /// All other values are reserved.
+        ASIDBits OFFSET(4) NUMBITS(4) [
+            Bits_8 = 0b0000,
+            Bits_16 = 0b0010
+        ],
+
         /// Physical Address range supported. Defined values are:
         ///
         /// 0000 32 bits, 4GiB.
@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! AArch64 Memory Model Feature Register 1 - EL1
+//!
+//! Provides information about the implemented memory model and memory management support in AArch64
+//! state.
+
+use register::{cpu::RegisterReadOnly, register_bitfields};
+
+register_bitfields! {u64,
+    pub ID_AA64MMFR1_EL1 [
+        /// Indicates support for intermediate caching of translation table walks. Defined values
+        /// are:
+        ///
+        /// 0000 The intermediate caching of translation table walks might include non-coherent
+        ///      physical translation caches.
+        /// 0001 The intermediate caching of translation table walks does not include non-coherent
+        ///      physical translation caches.
+        ///
+        /// All other values are reserved.
+        nTLBPA OFFSET(48) NUMBITS(4) [],
+
+        /// Indicates support for FPCR.{AH, FIZ, NEP}. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        AFP OFFSET(44) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for HCRX_EL2 and its associated EL3 trap. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        HCX OFFSET(40) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for Enhanced Translation Synchronization. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        ETS OFFSET(36) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for the configurable delayed trapping of WFE. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        TWED OFFSET(32) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for execute-never control distinction by Exception level at stage 2.
+        /// Defined values are:
+        ///
+        /// 0000 Distinction between EL0 and EL1 execute-never control at stage 2 not supported.
+        /// 0001 Distinction between EL0 and EL1 execute-never control at stage 2 supported.
+        ///
+        /// All other values are reserved.
+        XNX OFFSET(28) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Describes whether the PE can generate SError interrupt exceptions from speculative reads
+        /// of memory, including speculative instruction fetches. Defined values are:
+        ///
+        /// 0000 The PE never generates an SError interrupt due to an External abort on a
+        ///      speculative read.
+        /// 0001 The PE might generate an SError interrupt due to an External abort on a speculative
+        ///      read.
+        ///
+        /// All other values are reserved.
+        SpecSEI OFFSET(24) NUMBITS(4) [],
+
+        /// Privileged Access Never. Indicates support for the PAN bit in PSTATE, SPSR_EL1,
+        /// SPSR_EL2, SPSR_EL3, and DSPSR_EL0. Defined values are:
+        ///
+        /// 0000 PAN not supported.
+        /// 0001 PAN supported.
+        /// 0010 PAN supported and AT S1E1RP and AT S1E1WP instructions supported.
+        /// 0011 PAN supported, AT S1E1RP and AT S1E1WP instructions supported, and SCTLR_EL1.EPAN
+        ///      and SCTLR_EL2.EPAN bits supported.
+        ///
+        /// All other values are reserved.
+        PAN OFFSET(20) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            PAN = 0b0001,
+            PAN2 = 0b0010,
+            PAN3 = 0b0011
+        ],
+
+        /// Indicates support for LORegions. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        LO OFFSET(16) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Hierarchical Permission Disables. Indicates support for disabling hierarchical controls
+        /// in translation tables. Defined values are:
+        ///
+        /// 0000 Disabling of hierarchical controls not supported.
+        /// 0001 Disabling of hierarchical controls supported with the TCR_EL1.{HPD1, HPD0},
+        ///      TCR_EL2.HPD or TCR_EL2.{HPD1, HPD0}, and TCR_EL3.HPD bits.
+        /// 0010 As for value 0001, and adds possible hardware allocation of bits[62:59] of the
+        ///      translation table descriptors from the final lookup level for IMPLEMENTATION
+        ///      DEFINED use.
+        ///
+        /// All other values are reserved.
+        HPDS OFFSET(12) NUMBITS(4) [],
+
+        /// Indicates support for Virtualization Host Extensions (ARMv8.1-VHE). Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        VH OFFSET(8) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Number of VMID bits. Defined values are:
+        ///
+        /// 0000 8 bits
+        /// 0010 16 bits
+        ///
+        /// All other values are reserved.
+        VMIDBits OFFSET(4) NUMBITS(4) [
+            Bits_8 = 0b0000,
+            Bits_16 = 0b0010
+        ],
+
+        /// Hardware updates to Access flag and Dirty state in translation tables. Defined values
+        /// are:
+        ///
+        /// 0000 Hardware update of the Access flag and dirty state are not supported.
+        /// 0001 Hardware update of the Access flag is supported.
+        /// 0010 Hardware update of both the Access flag and dirty state is supported.
+        ///
+        /// All other values are reserved.
+        HAFDBS OFFSET(0) NUMBITS(4) [
+            NotSupported = 0b0000,
+            AccessFlag = 0b0001,
+            AccessFlagAndDirtyState = 0b0010
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadOnly<u64, ID_AA64MMFR1_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u64, "ID_AA64MMFR1_EL1");
+}
+
+pub static ID_AA64MMFR1_EL1: Reg = Reg {};
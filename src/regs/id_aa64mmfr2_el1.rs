@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! AArch64 Memory Model Feature Register 2 - EL1
+//!
+//! Provides information about the implemented memory model and memory management support in AArch64
+//! state.
+
+use register::{cpu::RegisterReadOnly, register_bitfields};
+
+register_bitfields! {u64,
+    pub ID_AA64MMFR2_EL1 [
+        /// Indicates support for the E0PD mechanism (ARMv8.5-E0PD). Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        E0PD OFFSET(60) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Enhanced Virtualization Traps. Defined values are:
+        ///
+        /// 0000 HCR_EL2.{TOCU, TICAB, TID4} traps are not supported.
+        /// 0001 HCR_EL2.{TOCU, TICAB, TID4} traps are supported. HCR_EL2.{TTLBOS, TTLBIS} traps are
+        ///      not supported.
+        /// 0010 HCR_EL2.{TTLBOS, TTLBIS, TOCU, TICAB, TID4} traps are supported.
+        ///
+        /// All other values are reserved.
+        EVT OFFSET(56) NUMBITS(4) [],
+
+        /// Allows identification of the requirements of the hardware to have break-before-make
+        /// sequences when changing block size for a translation. Defined values are:
+        ///
+        /// 0000 Level 0 support for changing block size is supported.
+        /// 0001 Level 1 support for changing block size is supported.
+        /// 0010 Level 2 support for changing block size is supported.
+        ///
+        /// All other values are reserved.
+        BBM OFFSET(52) NUMBITS(4) [],
+
+        /// Indicates support for TTL field in address operations. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        TTL OFFSET(48) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for HCR_EL2.FWB. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        FWB OFFSET(40) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates the value of ESR_ELx.EC that reports an exception generated by a read access
+        /// to the feature ID space. Defined values are:
+        ///
+        /// 0000 An exception which is generated by a read access to the feature ID space, other
+        ///      than a trap caused by HCR_EL2.TIDx, SCTLR_EL1.UCT, or SCTLR_EL2.UCT, is reported by
+        ///      ESR_ELx.EC == 0x0.
+        /// 0001 All exceptions generated by an AArch64 read access to the feature ID space are
+        ///      reported by ESR_ELx.EC == 0x18.
+        ///
+        /// All other values are reserved.
+        IDS OFFSET(36) NUMBITS(4) [],
+
+        /// Indicates support for unaligned single-copy atomicity and atomic functions
+        /// (ARMv8.4-LSE). Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        AT OFFSET(32) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for 48-bit T0SZ and T1SZ fields for the small translation table
+        /// extension (ARMv8.4-TTST). Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        ST OFFSET(28) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Nested Virtualization. Defined values are:
+        ///
+        /// 0000 Nested virtualization is not supported.
+        /// 0001 The HCR_EL2.{AT, NV1, NV} bits are implemented.
+        /// 0010 The VNCR_EL2 register and the HCR_EL2.{NV2, AT, NV1, NV} bits are implemented.
+        ///
+        /// All other values are reserved.
+        NV OFFSET(24) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            NV = 0b0001,
+            NV2 = 0b0010
+        ],
+
+        /// Support for the use of revised CCSIDR_EL1 register format. Defined values are:
+        ///
+        /// 0000 32-bit format implemented for all levels of the CCSIDR_EL1.
+        /// 0001 64-bit format implemented for all levels of the CCSIDR_EL1.
+        ///
+        /// All other values are reserved.
+        CCIDX OFFSET(20) NUMBITS(4) [],
+
+        /// Indicates support for a larger virtual address. Defined values are:
+        ///
+        /// 0000 VMSAv8-64 supports 48-bit VAs.
+        /// 0001 VMSAv8-64 supports 52-bit VAs when using the 64KB translation granule. The size for
+        ///      other translation granules is not defined by this field.
+        ///
+        /// All other values are reserved.
+        VARange OFFSET(16) NUMBITS(4) [
+            Bits_48 = 0b0000,
+            Bits_52 = 0b0001
+        ],
+
+        /// Indicates support for the SCTLR_ELx.IESB implicit error synchronization event. Defined
+        /// values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        IESB OFFSET(12) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for the SCTLR_ELx.{LSMAOE, nTLSMD} controls for load/store multiple
+        /// instructions. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        LSM OFFSET(8) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for User Access Override (ARMv8.2-UAO). Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        UAO OFFSET(4) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for Common not Private translations (ARMv8.2-TTCNP). Defined values
+        /// are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        CnP OFFSET(0) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadOnly<u64, ID_AA64MMFR2_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u64, "S3_0_C0_C7_2");
+}
+
+pub static ID_AA64MMFR2_EL1: Reg = Reg {};
@@ -0,0 +1,220 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! AArch64 Processor Feature Register 0 - EL1
+//!
+//! Provides additional information about implemented PE features in AArch64 state.
+
+use register::{cpu::RegisterReadOnly, register_bitfields};
+
+register_bitfields! {u64,
+    pub ID_AA64PFR0_EL1 [
+        /// Speculative use of faulting data. Defined values are:
+        ///
+        /// 0000 This PE does not disclose whether data loaded under speculation with a permission
+        ///      or domain fault can be used to form an address or generate condition codes or SVE
+        ///      predicate values to be used by other instructions in the speculative sequence.
+        /// 0001 Data loaded under speculation with a permission or domain fault cannot be used to
+        ///      form an address, generate condition codes or SVE predicate values to be used by
+        ///      other instructions in the speculative sequence.
+        ///
+        /// All other values are reserved.
+        CSV3 OFFSET(60) NUMBITS(4) [
+            NotDisclosed = 0b0000,
+            Safe = 0b0001
+        ],
+
+        /// Speculative use of out of context branch targets. Defined values are:
+        ///
+        /// 0000 The implementation does not disclose whether it is possible to hijack branch
+        ///      targets.
+        /// 0001 Branch targets trained in one hardware described context can only affect
+        ///      speculative execution in a different hardware described context in a
+        ///      hard-to-determine way.
+        /// 0010 As 0001, and SCXTNUM_ELx registers are supported.
+        ///
+        /// All other values are reserved.
+        CSV2 OFFSET(56) NUMBITS(4) [
+            NotDisclosed = 0b0000,
+            Safe = 0b0001,
+            SafeWithSCXTNUM = 0b0010
+        ],
+
+        /// Indicates support for Data Independent Timing (ARMv8.4-DIT). Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        DIT OFFSET(48) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Activity Monitors Extension. Defined values are:
+        ///
+        /// 0000 Activity Monitors Extension is not implemented.
+        /// 0001 Activity Monitors Extension version 1 (ARMv8.4-AMU) is implemented.
+        /// 0010 As 0001, and the ARMv8.6 virtualization of the activity monitor offsets is
+        ///      implemented.
+        ///
+        /// All other values are reserved.
+        AMU OFFSET(44) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            AMUv1 = 0b0001,
+            AMUv1p1 = 0b0010
+        ],
+
+        /// Memory Partitioning and Monitoring (MPAM) Extension, major version number. Defined
+        /// values are:
+        ///
+        /// 0000 The major version number of the MPAM extension is 0.
+        /// 0001 The major version number of the MPAM extension is 1.
+        ///
+        /// All other values are reserved.
+        MPAM OFFSET(40) NUMBITS(4) [],
+
+        /// Indicates support for Secure EL2 (ARMv8.4-SecEL2). Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        SEL2 OFFSET(36) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for the Scalable Vector Extension (SVE). Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        SVE OFFSET(32) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// RAS Extension version. Defined values are:
+        ///
+        /// 0000 No RAS Extension.
+        /// 0001 RAS Extension implemented.
+        /// 0010 ARMv8.4-RAS, the RAS Extension version 1.1, is implemented.
+        ///
+        /// All other values are reserved.
+        RAS OFFSET(28) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            RAS = 0b0001,
+            RASv1p1 = 0b0010
+        ],
+
+        /// System register GIC CPU interface. Defined values are:
+        ///
+        /// 0000 GIC CPU interface system registers not implemented.
+        /// 0001 System register interface to versions 3.0 and 4.0 of the GIC CPU interface is
+        ///      supported.
+        /// 0011 System register interface to version 4.1 of the GIC CPU interface is supported.
+        ///
+        /// All other values are reserved.
+        GIC OFFSET(24) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            GICv3 = 0b0001,
+            GICv4p1 = 0b0011
+        ],
+
+        /// Advanced SIMD. Defined values are:
+        ///
+        /// 0000 Advanced SIMD is implemented, including support for the following SISD and SIMD
+        ///      operations: integer byte, halfword, word and doubleword element operations;
+        ///      single-precision and double-precision floating-point arithmetic; conversions
+        ///      between single-precision and half-precision data types, and double-precision and
+        ///      half-precision data types.
+        /// 0001 As for 0000, and also includes support for half-precision floating-point
+        ///      arithmetic.
+        /// 1111 Advanced SIMD is not implemented.
+        ///
+        /// All other values are reserved.
+        AdvSIMD OFFSET(20) NUMBITS(4) [
+            Implemented = 0b0000,
+            ImplementedWithFP16 = 0b0001,
+            NotImplemented = 0b1111
+        ],
+
+        /// Floating-point. Defined values are:
+        ///
+        /// 0000 Floating-point is implemented, and includes support for single-precision and
+        ///      double-precision floating-point types, and conversions between those types and the
+        ///      half-precision type.
+        /// 0001 As for 0000, and also includes support for half-precision floating-point
+        ///      arithmetic.
+        /// 1111 Floating-point is not implemented.
+        ///
+        /// All other values are reserved.
+        FP OFFSET(16) NUMBITS(4) [
+            Implemented = 0b0000,
+            ImplementedWithFP16 = 0b0001,
+            NotImplemented = 0b1111
+        ],
+
+        /// EL3 Exception level handling. Defined values are:
+        ///
+        /// 0000 EL3 is not implemented.
+        /// 0001 EL3 can be executed in AArch64 state only.
+        /// 0010 EL3 can be executed in either AArch64 or AArch32 state.
+        ///
+        /// All other values are reserved.
+        EL3 OFFSET(12) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            AArch64 = 0b0001,
+            AArch32AndAArch64 = 0b0010
+        ],
+
+        /// EL2 Exception level handling. Defined values are:
+        ///
+        /// 0000 EL2 is not implemented.
+        /// 0001 EL2 can be executed in AArch64 state only.
+        /// 0010 EL2 can be executed in either AArch64 or AArch32 state.
+        ///
+        /// All other values are reserved.
+        EL2 OFFSET(8) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            AArch64 = 0b0001,
+            AArch32AndAArch64 = 0b0010
+        ],
+
+        /// EL1 Exception level handling. Defined values are:
+        ///
+        /// 0001 EL1 can be executed in AArch64 state only.
+        /// 0010 EL1 can be executed in either AArch64 or AArch32 state.
+        ///
+        /// All other values are reserved.
+        EL1 OFFSET(4) NUMBITS(4) [
+            AArch64 = 0b0001,
+            AArch32AndAArch64 = 0b0010
+        ],
+
+        /// EL0 Exception level handling. Defined values are:
+        ///
+        /// 0001 EL0 can be executed in AArch64 state only.
+        /// 0010 EL0 can be executed in either AArch64 or AArch32 state.
+        ///
+        /// All other values are reserved.
+        EL0 OFFSET(0) NUMBITS(4) [
+            AArch64 = 0b0001,
+            AArch32AndAArch64 = 0b0010
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadOnly<u64, ID_AA64PFR0_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u64, "ID_AA64PFR0_EL1");
+}
+
+pub static ID_AA64PFR0_EL1: Reg = Reg {};
@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! AArch64 Processor Feature Register 1 - EL1
+//!
+//! Reserved for future expansion of information about implemented PE features in AArch64 state.
+
+use register::{cpu::RegisterReadOnly, register_bitfields};
+
+register_bitfields! {u64,
+    pub ID_AA64PFR1_EL1 [
+        /// CSV2 fractional field. Defined values are:
+        ///
+        /// 0000 Either CSV2 not exposed or CSV2 exposed but without any additional disclosure.
+        /// 0001 ARMv8.5-CSV2 is implemented, but SCXTNUM_ELx registers are not supported.
+        /// 0010 ARMv8.5-CSV2 is implemented, and SCXTNUM_ELx registers are supported.
+        ///
+        /// All other values are reserved.
+        CSV2_frac OFFSET(32) NUMBITS(4) [],
+
+        /// Indicates support for the Scalable Matrix Extension. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        SME OFFSET(24) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Minor version number of the MPAM extension.
+        MPAM_frac OFFSET(16) NUMBITS(4) [],
+
+        /// RAS Extension fractional field. Defined values are:
+        ///
+        /// 0000 If ID_AA64PFR0_EL1.RAS == 0001, RAS Extension implemented.
+        /// 0001 If ID_AA64PFR0_EL1.RAS == 0001, as 0000 and adds support for additional
+        ///      ERXMISC<m>_EL1 System registers.
+        ///
+        /// All other values are reserved.
+        RAS_frac OFFSET(12) NUMBITS(4) [],
+
+        /// Support for the Memory Tagging Extension. Defined values are:
+        ///
+        /// 0000 Memory Tagging Extension is not implemented.
+        /// 0001 Instruction-only Memory Tagging Extension is implemented.
+        /// 0010 Full Memory Tagging Extension is implemented.
+        /// 0011 Memory Tagging Extension is implemented with support for asymmetric Tag Check Fault
+        ///      handling.
+        ///
+        /// All other values are reserved.
+        MTE OFFSET(8) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            InstructionsOnly = 0b0001,
+            Full = 0b0010,
+            Asymmetric = 0b0011
+        ],
+
+        /// Speculative Store Bypassing controls in AArch64 state. Defined values are:
+        ///
+        /// 0000 AArch64 provides no mechanism to control the use of Speculative Store Bypassing.
+        /// 0001 AArch64 provides the PSTATE.SSBS mechanism to mark regions that are Speculative
+        ///      Store Bypass Safe.
+        /// 0010 As 0001, and adds the MSR and MRS instructions to directly read and write the
+        ///      PSTATE.SSBS field.
+        ///
+        /// All other values are reserved.
+        SSBS OFFSET(4) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            PSTATE = 0b0001,
+            PSTATEWithMSRMRS = 0b0010
+        ],
+
+        /// Indicates support for Branch Target Identification (ARMv8.5-BTI). Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        BT OFFSET(0) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadOnly<u64, ID_AA64PFR1_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u64, "ID_AA64PFR1_EL1");
+}
+
+pub static ID_AA64PFR1_EL1: Reg = Reg {};
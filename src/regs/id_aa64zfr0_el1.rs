@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! SVE Feature ID register 0 - EL1
+//!
+//! Provides additional information about the implemented features of the AArch64 Scalable Vector
+//! Extension, when the ID_AA64PFR0_EL1.SVE field is not zero.
+
+use register::{cpu::RegisterReadOnly, register_bitfields};
+
+register_bitfields! {u64,
+    pub ID_AA64ZFR0_EL1 [
+        /// Indicates support for the SVE double-precision floating-point matrix multiplication
+        /// instruction, and related SVE instructions. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        F64MM OFFSET(56) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for the SVE single-precision floating-point matrix multiplication
+        /// instruction. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        F32MM OFFSET(52) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for SVE Int8 matrix multiplication instructions. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        I8MM OFFSET(44) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for SVE SM4 instructions. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        SM4 OFFSET(40) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for the SVE SHA3 instructions. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        SHA3 OFFSET(32) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for SVE BFloat16 instructions. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        BF16 OFFSET(20) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for SVE bit permute instructions. Defined values are:
+        ///
+        /// 0000 Not implemented.
+        /// 0001 Implemented.
+        ///
+        /// All other values are reserved.
+        BitPerm OFFSET(16) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for SVE AES instructions. Defined values are:
+        ///
+        /// 0000 SVE AES instructions are not implemented.
+        /// 0001 SVE AESE, AESD, AESMC, and AESIMC instructions are implemented.
+        /// 0010 As 0001, plus SVE PMULLB and PMULLT instructions with 64-bit source.
+        ///
+        /// All other values are reserved.
+        AES OFFSET(4) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            AES = 0b0001,
+            AESAndPMULL = 0b0010
+        ],
+
+        /// Scalable Vector Extension instruction set version. Defined values are:
+        ///
+        /// 0000 SVE instructions are implemented.
+        /// 0001 As 0000, and adds the mandatory SVE2 instructions.
+        ///
+        /// All other values are reserved.
+        SVEver OFFSET(0) NUMBITS(4) [
+            SVE = 0b0000,
+            SVE2 = 0b0001
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadOnly<u64, ID_AA64ZFR0_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u64, "S3_0_C0_C4_4");
+}
+
+pub static ID_AA64ZFR0_EL1: Reg = Reg {};
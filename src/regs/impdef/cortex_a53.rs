@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Cortex-A53 IMPLEMENTATION DEFINED registers
+//!
+//! Descriptions are taken from the Arm Cortex-A53 MPCore Processor Technical Reference Manual.
+
+use register::register_bitfields;
+
+register_bitfields! {u64,
+    /// CPU Auxiliary Control Register - EL1
+    pub CPUACTLR_EL1 [
+        /// Enable data cache clean as data cache clean and invalidate.
+        ///
+        /// 0 Normal behavior, data cache clean operations are not affected.
+        /// 1 Executes data cache clean operations as data cache clean and invalidate.
+        ENDCCASCI OFFSET(44) NUMBITS(1) [],
+
+        /// Write streaming no-allocate threshold.
+        RADIS OFFSET(27) NUMBITS(2) [
+            Lines_16 = 0b00,
+            Lines_128 = 0b01,
+            Lines_512 = 0b10,
+            Disabled = 0b11
+        ],
+
+        /// Write streaming no-L1-allocate threshold.
+        L1RADIS OFFSET(25) NUMBITS(2) [
+            Lines_4 = 0b00,
+            Lines_64 = 0b01,
+            Lines_128 = 0b10,
+            Disabled = 0b11
+        ],
+
+        /// Disable transient allocation hint.
+        ///
+        /// 0 Normal operation.
+        /// 1 The transient allocation hint in the MAIR is ignored and treated the same as
+        ///   non-transient allocation types.
+        DTAH OFFSET(24) NUMBITS(1) []
+    ]
+}
+
+register_bitfields! {u64,
+    /// CPU Extended Control Register - EL1
+    pub CPUECTLR_EL1 [
+        /// Enables the processor to receive instruction cache and TLB maintenance operations
+        /// broadcast from other processors in the cluster.
+        ///
+        /// This bit must be set before the caches and MMU are enabled, or any cache and TLB
+        /// maintenance operations are performed. It must be cleared during a processor power down
+        /// sequence.
+        SMPEN OFFSET(6) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Advanced SIMD and floating-point retention control. The number of Architectural Timer
+        /// ticks required before the core enters retention.
+        FPRETCTL OFFSET(3) NUMBITS(3) [
+            Disable = 0b000,
+            Ticks_2 = 0b001,
+            Ticks_8 = 0b010,
+            Ticks_32 = 0b011,
+            Ticks_64 = 0b100,
+            Ticks_128 = 0b101,
+            Ticks_256 = 0b110,
+            Ticks_512 = 0b111
+        ],
+
+        /// CPU retention control. The number of Architectural Timer ticks required before the core
+        /// enters retention.
+        CPURETCTL OFFSET(0) NUMBITS(3) [
+            Disable = 0b000,
+            Ticks_2 = 0b001,
+            Ticks_8 = 0b010,
+            Ticks_32 = 0b011,
+            Ticks_64 = 0b100,
+            Ticks_128 = 0b101,
+            Ticks_256 = 0b110,
+            Ticks_512 = 0b111
+        ]
+    ]
+}
+
+register_bitfields! {u64,
+    /// L2 Control Register - EL1
+    pub L2CTLR_EL1 [
+        /// Number of cores in the cluster, minus one. This field is read-only.
+        NumCores OFFSET(24) NUMBITS(2) [],
+
+        /// CPU Cache Protection. This field is read-only.
+        CPUCacheProtection OFFSET(22) NUMBITS(1) [],
+
+        /// SCU-L2 Cache Protection. This field is read-only.
+        SCUL2CacheProtection OFFSET(21) NUMBITS(1) [],
+
+        /// L2 data RAM input latency.
+        DataRAMInputLatency OFFSET(5) NUMBITS(1) [
+            Cycles_1 = 0,
+            Cycles_2 = 1
+        ],
+
+        /// L2 data RAM output latency.
+        DataRAMOutputLatency OFFSET(0) NUMBITS(1) [
+            Cycles_2 = 0,
+            Cycles_3 = 1
+        ]
+    ]
+}
+
+register_bitfields! {u64,
+    /// L2 Extended Control Register - EL1
+    pub L2ECTLR_EL1 [
+        /// AXI or CHI asynchronous error indication. Write 0 to clear.
+        AsyncErrorAXI OFFSET(30) NUMBITS(1) [],
+
+        /// L2 internal asynchronous error caused by an L2 RAM double-bit ECC error. Write 0 to
+        /// clear.
+        AsyncErrorInternal OFFSET(29) NUMBITS(1) [],
+
+        /// L2 dynamic retention control. The number of Architectural Timer ticks required before
+        /// the L2 memory system enters retention.
+        L2RETCTL OFFSET(0) NUMBITS(3) [
+            Disable = 0b000,
+            Ticks_2 = 0b001,
+            Ticks_8 = 0b010,
+            Ticks_32 = 0b011,
+            Ticks_64 = 0b100,
+            Ticks_128 = 0b101,
+            Ticks_256 = 0b110,
+            Ticks_512 = 0b111
+        ]
+    ]
+}
+
+impdef_regs! {"Cortex-A53", CortexA53,
+    cpuactlr_el1: CPUACTLR_EL1<3, 1, 15, 2, 0>,
+    cpuectlr_el1: CPUECTLR_EL1<3, 1, 15, 2, 1>,
+    l2ctlr_el1: L2CTLR_EL1<3, 1, 11, 0, 2>,
+    l2ectlr_el1: L2ECTLR_EL1<3, 1, 11, 0, 3>
+}
@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Cortex-A72 IMPLEMENTATION DEFINED registers
+//!
+//! Descriptions are taken from the Arm Cortex-A72 MPCore Processor Technical Reference Manual.
+
+use register::register_bitfields;
+
+register_bitfields! {u64,
+    /// CPU Auxiliary Control Register - EL1
+    pub CPUACTLR_EL1 [
+        /// Disables L1 data cache hardware prefetcher.
+        DisL1DCacheHWPrefetch OFFSET(56) NUMBITS(1) [],
+
+        /// Disables load-pass-store.
+        DisLoadPassStore OFFSET(55) NUMBITS(1) [],
+
+        /// Disables allocation of Write-Back Write-Allocate lines on a write miss.
+        NoAllocWBWA OFFSET(49) NUMBITS(1) [],
+
+        /// Executes data cache clean operations as data cache clean and invalidate.
+        DCCAsDCCI OFFSET(44) NUMBITS(1) [],
+
+        /// Disables the instruction prefetch.
+        DisInstrPrefetch OFFSET(32) NUMBITS(1) []
+    ]
+}
+
+register_bitfields! {u64,
+    /// CPU Extended Control Register - EL1
+    pub CPUECTLR_EL1 [
+        /// Disables table walk descriptor access prefetch.
+        DisTableWalkPrefetch OFFSET(38) NUMBITS(1) [],
+
+        /// L2 instruction fetch prefetch distance.
+        L2InstrPrefetchDist OFFSET(35) NUMBITS(2) [
+            Disabled = 0b00,
+            Requests_1 = 0b01,
+            Requests_2 = 0b10,
+            Requests_3 = 0b11
+        ],
+
+        /// L2 load/store data prefetch distance.
+        L2DataPrefetchDist OFFSET(32) NUMBITS(2) [
+            Requests_16 = 0b00,
+            Requests_18 = 0b01,
+            Requests_20 = 0b10,
+            Requests_22 = 0b11
+        ],
+
+        /// Enables the processor to receive instruction cache and TLB maintenance operations
+        /// broadcast from other processors in the cluster.
+        ///
+        /// This bit must be set before the caches and MMU are enabled, or any cache and TLB
+        /// maintenance operations are performed. It must be cleared during a processor power down
+        /// sequence.
+        SMPEN OFFSET(6) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// CPU retention control. The number of Architectural Timer ticks required before the core
+        /// enters retention.
+        CPURETCTL OFFSET(0) NUMBITS(3) [
+            Disable = 0b000,
+            Ticks_2 = 0b001,
+            Ticks_8 = 0b010,
+            Ticks_32 = 0b011,
+            Ticks_64 = 0b100,
+            Ticks_128 = 0b101,
+            Ticks_256 = 0b110,
+            Ticks_512 = 0b111
+        ]
+    ]
+}
+
+register_bitfields! {u64,
+    /// L2 Control Register - EL1
+    pub L2CTLR_EL1 [
+        /// Number of cores in the cluster, minus one. This field is read-only.
+        NumCores OFFSET(24) NUMBITS(2) [],
+
+        /// ECC and parity enable.
+        ECCParityEnable OFFSET(21) NUMBITS(1) [],
+
+        /// L2 tag RAM setup.
+        TagRAMSetup OFFSET(9) NUMBITS(1) [],
+
+        /// L2 tag RAM latency.
+        TagRAMLatency OFFSET(6) NUMBITS(3) [
+            Cycles_2 = 0b000,
+            Cycles_3 = 0b010,
+            Cycles_4 = 0b011,
+            Cycles_5 = 0b100
+        ],
+
+        /// L2 data RAM setup.
+        DataRAMSetup OFFSET(5) NUMBITS(1) [],
+
+        /// L2 data RAM latency.
+        DataRAMLatency OFFSET(0) NUMBITS(3) [
+            Cycles_2 = 0b000,
+            Cycles_3 = 0b010,
+            Cycles_4 = 0b011,
+            Cycles_5 = 0b100,
+            Cycles_6 = 0b101
+        ]
+    ]
+}
+
+register_bitfields! {u64,
+    /// L2 Extended Control Register - EL1
+    pub L2ECTLR_EL1 [
+        /// AXI or CHI asynchronous error indication. Write 0 to clear.
+        AsyncErrorAXI OFFSET(30) NUMBITS(1) [],
+
+        /// L2 internal asynchronous error caused by an L2 RAM double-bit ECC error. Write 0 to
+        /// clear.
+        AsyncErrorInternal OFFSET(29) NUMBITS(1) [],
+
+        /// L2 dynamic retention control. The number of Architectural Timer ticks required before
+        /// the L2 memory system enters retention.
+        L2RETCTL OFFSET(0) NUMBITS(3) [
+            Disable = 0b000,
+            Ticks_2 = 0b001,
+            Ticks_8 = 0b010,
+            Ticks_32 = 0b011,
+            Ticks_64 = 0b100,
+            Ticks_128 = 0b101,
+            Ticks_256 = 0b110,
+            Ticks_512 = 0b111
+        ]
+    ]
+}
+
+impdef_regs! {"Cortex-A72", CortexA72,
+    cpuactlr_el1: CPUACTLR_EL1<3, 1, 15, 2, 0>,
+    cpuectlr_el1: CPUECTLR_EL1<3, 1, 15, 2, 1>,
+    l2ctlr_el1: L2CTLR_EL1<3, 1, 11, 0, 2>,
+    l2ectlr_el1: L2ECTLR_EL1<3, 1, 11, 0, 3>
+}
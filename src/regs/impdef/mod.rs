@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! IMPLEMENTATION DEFINED registers of Arm Cortex-A cores
+//!
+//! The registers in these modules only exist on the respective core. Executing an access to one of
+//! them on any other core is UNDEFINED and usually traps. Therefore, the registers are only handed
+//! out through a `Regs` token, which is obtained with `Regs::probe()` after `MIDR_EL1` has been
+//! checked to match the core.
+//!
+//! ```ignore
+//! use cortex_a::regs::{impdef::cortex_a53, *};
+//!
+//! if let Some(regs) = cortex_a53::Regs::probe() {
+//!     regs.cpuectlr_el1().modify(cortex_a53::CPUECTLR_EL1::SMPEN::SET);
+//! }
+//! ```
+
+/// Defines the `Regs` token of a core and the accessors of its registers.
+macro_rules! impdef_regs {
+    (
+        $core:literal, $model:ident,
+        $($accessor:ident: $reg:ident<$op0:literal, $op1:literal, $crn:literal, $crm:literal, $op2:literal>),*
+    ) => {
+        $(
+            static $reg: $crate::regs::SysReg<$reg::Register, $op0, $op1, $crn, $crm, $op2> =
+                $crate::regs::SysReg::new();
+        )*
+
+        #[doc = concat!(" Access token for the ", $core, " IMPLEMENTATION DEFINED registers.")]
+        ///
+        /// The token is neither `Send` nor `Sync`, so that it cannot be handed to another core.
+        pub struct Regs {
+            _not_send: core::marker::PhantomData<*const ()>,
+        }
+
+        impl Regs {
+            #[doc = concat!(" Returns the registers if the executing core is a ", $core, ", `None` otherwise.")]
+            #[inline]
+            pub fn probe() -> Option<Regs> {
+                if $crate::cpuid::CpuInfo::current().model == $crate::cpuid::CpuModel::$model {
+                    Some(Regs {
+                        _not_send: core::marker::PhantomData,
+                    })
+                } else {
+                    None
+                }
+            }
+
+            /// Returns the registers without checking `MIDR_EL1`.
+            ///
+            /// # Safety
+            ///
+            #[doc = concat!(" - The caller must ensure that the code only ever executes on a ", $core, ".")]
+            #[inline]
+            pub const unsafe fn new_unchecked() -> Regs {
+                Regs {
+                    _not_send: core::marker::PhantomData,
+                }
+            }
+
+            $(
+                #[inline]
+                pub fn $accessor(
+                    &self,
+                ) -> &'static $crate::regs::SysReg<$reg::Register, $op0, $op1, $crn, $crm, $op2> {
+                    &$reg
+                }
+            )*
+        }
+    };
+}
+
+pub mod cortex_a53;
+pub mod cortex_a57;
+pub mod cortex_a72;
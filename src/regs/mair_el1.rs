@@ -11,6 +11,7 @@
 //! Provides the memory attribute encodings corresponding to the possible AttrIndx values in a
 //! Long-descriptor format translation table entry for stage 1 translations at EL1.
 
+use super::ContextSync;
 use register::{cpu::RegisterReadWrite, register_bitfields};
 
 register_bitfields! {u64,
@@ -44,6 +45,9 @@ register_bitfields! {u64,
             nonGathering_Reordering_EarlyWriteAck = 0b0000_1000,
             Gathering_Reordering_EarlyWriteAck = 0b0000_1100
         ],
+        Attr7_Tagged OFFSET(56) NUMBITS(8) [
+            Normal_Tagged = 0b1111_0000
+        ],
         Attr7_Normal_Inner OFFSET(56) NUMBITS(4) [
             WriteThrough_Transient = 0x0000,
             WriteThrough_Transient_WriteAlloc = 0x0001,
@@ -95,6 +99,9 @@ register_bitfields! {u64,
             nonGathering_Reordering_EarlyWriteAck = 0b0000_1000,
             Gathering_Reordering_EarlyWriteAck = 0b0000_1100
         ],
+        Attr6_Tagged OFFSET(48) NUMBITS(8) [
+            Normal_Tagged = 0b1111_0000
+        ],
         Attr6_Normal_Inner OFFSET(48) NUMBITS(4) [
             WriteThrough_Transient = 0x0000,
             WriteThrough_Transient_WriteAlloc = 0x0001,
@@ -146,6 +153,9 @@ register_bitfields! {u64,
             nonGathering_Reordering_EarlyWriteAck = 0b0000_1000,
             Gathering_Reordering_EarlyWriteAck = 0b0000_1100
         ],
+        Attr5_Tagged OFFSET(40) NUMBITS(8) [
+            Normal_Tagged = 0b1111_0000
+        ],
         Attr5_Normal_Inner OFFSET(40) NUMBITS(4) [
             WriteThrough_Transient = 0x0000,
             WriteThrough_Transient_WriteAlloc = 0x0001,
@@ -197,6 +207,9 @@ register_bitfields! {u64,
             nonGathering_Reordering_EarlyWriteAck = 0b0000_1000,
             Gathering_Reordering_EarlyWriteAck = 0b0000_1100
         ],
+        Attr4_Tagged OFFSET(32) NUMBITS(8) [
+            Normal_Tagged = 0b1111_0000
+        ],
         Attr4_Normal_Inner OFFSET(32) NUMBITS(4) [
             WriteThrough_Transient = 0x0000,
             WriteThrough_Transient_WriteAlloc = 0x0001,
@@ -248,6 +261,9 @@ register_bitfields! {u64,
             nonGathering_Reordering_EarlyWriteAck = 0b0000_1000,
             Gathering_Reordering_EarlyWriteAck = 0b0000_1100
         ],
+        Attr3_Tagged OFFSET(24) NUMBITS(8) [
+            Normal_Tagged = 0b1111_0000
+        ],
         Attr3_Normal_Inner OFFSET(24) NUMBITS(4) [
             WriteThrough_Transient = 0x0000,
             WriteThrough_Transient_WriteAlloc = 0x0001,
@@ -299,6 +315,9 @@ register_bitfields! {u64,
             nonGathering_Reordering_EarlyWriteAck = 0b0000_1000,
             Gathering_Reordering_EarlyWriteAck = 0b0000_1100
         ],
+        Attr2_Tagged OFFSET(16) NUMBITS(8) [
+            Normal_Tagged = 0b1111_0000
+        ],
         Attr2_Normal_Inner OFFSET(16) NUMBITS(4) [
             WriteThrough_Transient = 0x0000,
             WriteThrough_Transient_WriteAlloc = 0x0001,
@@ -350,6 +369,9 @@ register_bitfields! {u64,
             nonGathering_Reordering_EarlyWriteAck = 0b0000_1000,
             Gathering_Reordering_EarlyWriteAck = 0b0000_1100
         ],
+        Attr1_Tagged OFFSET(8) NUMBITS(8) [
+            Normal_Tagged = 0b1111_0000
+        ],
         Attr1_Normal_Inner OFFSET(8) NUMBITS(4) [
             WriteThrough_Transient = 0x0000,
             WriteThrough_Transient_WriteAlloc = 0x0001,
@@ -401,6 +423,9 @@ register_bitfields! {u64,
             nonGathering_Reordering_EarlyWriteAck = 0b0000_1000,
             Gathering_Reordering_EarlyWriteAck = 0b0000_1100
         ],
+        Attr0_Tagged OFFSET(0) NUMBITS(8) [
+            Normal_Tagged = 0b1111_0000
+        ],
         Attr0_Normal_Inner OFFSET(0) NUMBITS(4) [
             WriteThrough_Transient = 0x0000,
             WriteThrough_Transient_WriteAlloc = 0x0001,
@@ -432,4 +457,6 @@ impl RegisterReadWrite<u64, MAIR_EL1::Register> for Reg {
     sys_coproc_write_raw!(u64, "MAIR_EL1");
 }
 
+impl ContextSync<u64, MAIR_EL1::Register> for Reg {}
+
 pub static MAIR_EL1: Reg = Reg {};
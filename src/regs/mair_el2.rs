@@ -11,6 +11,7 @@
 //! Provides the memory attribute encodings corresponding to the possible AttrIndx values in a
 //! Long-descriptor format translation table entry for stage 1 translations at EL2.
 
+use super::ContextSync;
 use register::{cpu::RegisterReadWrite, register_bitfields};
 
 register_bitfields! {u64,
@@ -432,4 +433,6 @@ impl RegisterReadWrite<u64, MAIR_EL2::Register> for Reg {
     sys_coproc_write_raw!(u64, "MAIR_EL2");
 }
 
+impl ContextSync<u64, MAIR_EL2::Register> for Reg {}
+
 pub static MAIR_EL2: Reg = Reg {};
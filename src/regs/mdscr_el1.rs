@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Monitor Debug System Control Register - EL1
+//!
+//! Main control register for the debug implementation.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u32,
+    pub MDSCR_EL1 [
+        /// Used for save/restore of EDSCR.RXfull.
+        RXfull OFFSET(30) NUMBITS(1) [],
+
+        /// Used for save/restore of EDSCR.TXfull.
+        TXfull OFFSET(29) NUMBITS(1) [],
+
+        /// Used for save/restore of EDSCR.RXO.
+        RXO    OFFSET(27) NUMBITS(1) [],
+
+        /// Used for save/restore of EDSCR.TXU.
+        TXU    OFFSET(26) NUMBITS(1) [],
+
+        /// Used for save/restore of EDSCR.INTdis.
+        INTdis OFFSET(22) NUMBITS(2) [],
+
+        /// Used for save/restore of EDSCR.TDA.
+        TDA    OFFSET(21) NUMBITS(1) [],
+
+        /// Monitor debug events. Enable Breakpoint, Watchpoint, and Vector Catch exceptions.
+        ///
+        /// 0 Breakpoint, Watchpoint, and Vector Catch exceptions disabled.
+        /// 1 Breakpoint, Watchpoint, and Vector Catch exceptions enabled.
+        MDE    OFFSET(15) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Used for save/restore of EDSCR.HDE.
+        HDE    OFFSET(14) NUMBITS(1) [],
+
+        /// Local (kernel) debug enable. If EL_D is using AArch64, enable Software debug events
+        /// within EL_D, where EL_D is the debug target Exception level.
+        ///
+        /// 0 Software debug events, other than Software breakpoint instructions, disabled within
+        ///   EL_D.
+        /// 1 Software debug events enabled within EL_D.
+        KDE    OFFSET(13) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Traps EL0 accesses to the DCC registers to EL1.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL0 using AArch64: EL0 accesses to the MDCCSR_EL0, DBGDTR_EL0, DBGDTRTX_EL0, and
+        ///   DBGDTRRX_EL0 registers are trapped to EL1.
+        TDCC   OFFSET(12) NUMBITS(1) [],
+
+        /// Used for save/restore of EDSCR.ERR.
+        ERR    OFFSET(6) NUMBITS(1) [],
+
+        /// Software step control bit. If EL_D is using AArch64, enable Software step.
+        ///
+        /// 0 Software step disabled.
+        /// 1 Software step enabled.
+        SS     OFFSET(0) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u32, MDSCR_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u32, "MDSCR_EL1");
+    sys_coproc_write_raw!(u32, "MDSCR_EL1");
+}
+
+pub static MDSCR_EL1: Reg = Reg {};
@@ -3,29 +3,79 @@
 #[macro_use]
 mod macros;
 
+mod afsr0_el1;
+mod afsr1_el1;
+mod amair_el1;
+mod apkey_el1;
 mod cntfrq_el0;
 mod cnthctl_el2;
+mod cnthp_ctl_el2;
+mod cnthp_cval_el2;
+mod cnthp_tval_el2;
+mod cntkctl_el1;
 mod cntp_ctl_el0;
+mod cntp_cval_el0;
 mod cntp_tval_el0;
 mod cntpct_el0;
+mod cntps_ctl_el1;
+mod cntps_cval_el1;
+mod cntps_tval_el1;
 mod cntv_ctl_el0;
+mod cntv_cval_el0;
 mod cntv_tval_el0;
 mod cntvct_el0;
 mod cntvoff_el2;
+mod contextidr_el1;
+mod cpacr_el1;
 mod currentel;
 mod daif;
+mod dbgbcr_el1;
+mod dbgbvr_el1;
+mod dbgwcr_el1;
+mod dbgwvr_el1;
 mod elr_el1;
 mod elr_el2;
 mod esr_el1;
 mod esr_el2;
 mod far_el1;
 mod far_el2;
+mod gcr_el1;
 mod hcr_el2;
+mod hpfar_el2;
+mod id_aa64dfr0_el1;
+mod id_aa64isar0_el1;
+mod id_aa64isar1_el1;
+mod id_aa64isar2_el1;
 mod id_aa64mmfr0_el1;
+mod id_aa64mmfr1_el1;
+mod id_aa64mmfr2_el1;
+mod id_aa64pfr0_el1;
+mod id_aa64pfr1_el1;
+mod id_aa64zfr0_el1;
 mod lr;
 mod mair_el1;
 mod mair_el2;
+mod mdscr_el1;
+mod midr_el1;
 mod mpidr_el1;
+mod osdlr_el1;
+mod oslar_el1;
+mod oslsr_el1;
+mod par_el1;
+mod pmccntr_el0;
+mod pmcntenclr_el0;
+mod pmcntenset_el0;
+mod pmcr_el0;
+mod pmevcntr_el0;
+mod pmevtyper_el0;
+mod pmintenclr_el1;
+mod pmintenset_el1;
+mod pmovsclr_el0;
+mod pmselr_el0;
+mod pmuserenr_el0;
+mod pmxevcntr_el0;
+mod pmxevtyper_el0;
+mod rgsr_el1;
 mod sctlr_el1;
 mod sctlr_el2;
 mod sp;
@@ -36,45 +86,108 @@ mod spsr_el1;
 mod spsr_el2;
 mod tcr_el1;
 mod tcr_el2;
+mod tfsr_el1;
+mod tfsre0_el1;
+mod tpidr_el0;
+mod tpidr_el1;
+mod tpidrro_el0;
 mod ttbr0_el1;
 mod ttbr1_el1;
 mod ttbr0_el2;
+mod ttbr1_el2;
 mod vbar_el1;
 mod vbar_el2;
+mod vhe;
+mod vsesr_el2;
 mod vtcr_el2;
 mod vttbr_el2;
 mod hstr_el2;
 mod csselr_el1;
 mod ccsidr_el1;
+mod sysreg;
+mod reserved;
+mod sync;
+
+pub mod impdef;
 
 // Export only the R/W traits and the static reg definitions
 pub use register::cpu::*;
 
+pub use self::afsr0_el1::AFSR0_EL1;
+pub use self::afsr1_el1::AFSR1_EL1;
+pub use self::amair_el1::AMAIR_EL1;
+pub use self::apkey_el1::*;
 pub use self::cntfrq_el0::CNTFRQ_EL0;
 pub use self::cnthctl_el2::CNTHCTL_EL2;
+pub use self::cnthp_ctl_el2::CNTHP_CTL_EL2;
+pub use self::cnthp_cval_el2::CNTHP_CVAL_EL2;
+pub use self::cnthp_tval_el2::CNTHP_TVAL_EL2;
+pub use self::cntkctl_el1::CNTKCTL_EL1;
 pub use self::cntp_ctl_el0::CNTP_CTL_EL0;
+pub use self::cntp_cval_el0::CNTP_CVAL_EL0;
 pub use self::cntp_tval_el0::CNTP_TVAL_EL0;
 pub use self::cntpct_el0::CNTPCT_EL0;
+pub use self::cntps_ctl_el1::CNTPS_CTL_EL1;
+pub use self::cntps_cval_el1::CNTPS_CVAL_EL1;
+pub use self::cntps_tval_el1::CNTPS_TVAL_EL1;
 pub use self::cntv_ctl_el0::CNTV_CTL_EL0;
+pub use self::cntv_cval_el0::CNTV_CVAL_EL0;
 pub use self::cntv_tval_el0::CNTV_TVAL_EL0;
 pub use self::cntvct_el0::CNTVCT_EL0;
 pub use self::cntvoff_el2::CNTVOFF_EL2;
+pub use self::contextidr_el1::CONTEXTIDR_EL1;
+pub use self::cpacr_el1::CPACR_EL1;
 pub use self::currentel::CurrentEL;
 pub use self::daif::DAIF;
+pub use self::dbgbcr_el1::*;
+pub use self::dbgbvr_el1::*;
+pub use self::dbgwcr_el1::*;
+pub use self::dbgwvr_el1::*;
 pub use self::elr_el1::ELR_EL1;
 pub use self::elr_el2::ELR_EL2;
 pub use self::esr_el1::ESR_EL1;
 pub use self::esr_el2::ESR_EL2;
 pub use self::far_el1::FAR_EL1;
 pub use self::far_el2::FAR_EL2;
+pub use self::gcr_el1::GCR_EL1;
 pub use self::hcr_el2::HCR_EL2;
+pub use self::hpfar_el2::HPFAR_EL2;
+pub use self::id_aa64dfr0_el1::ID_AA64DFR0_EL1;
+pub use self::id_aa64isar0_el1::ID_AA64ISAR0_EL1;
+pub use self::id_aa64isar1_el1::ID_AA64ISAR1_EL1;
+pub use self::id_aa64isar2_el1::ID_AA64ISAR2_EL1;
 pub use self::id_aa64mmfr0_el1::ID_AA64MMFR0_EL1;
+pub use self::id_aa64mmfr1_el1::ID_AA64MMFR1_EL1;
+pub use self::id_aa64mmfr2_el1::ID_AA64MMFR2_EL1;
+pub use self::id_aa64pfr0_el1::ID_AA64PFR0_EL1;
+pub use self::id_aa64pfr1_el1::ID_AA64PFR1_EL1;
+pub use self::id_aa64zfr0_el1::ID_AA64ZFR0_EL1;
 pub use self::lr::LR;
 pub use self::mair_el1::MAIR_EL1;
 pub use self::mair_el2::MAIR_EL2;
+pub use self::mdscr_el1::MDSCR_EL1;
+pub use self::midr_el1::MIDR_EL1;
 pub use self::mpidr_el1::MPIDR_EL1;
+pub use self::osdlr_el1::OSDLR_EL1;
+pub use self::oslar_el1::OSLAR_EL1;
+pub use self::oslsr_el1::OSLSR_EL1;
+pub use self::par_el1::PAR_EL1;
+pub use self::pmccntr_el0::PMCCNTR_EL0;
+pub use self::pmcntenclr_el0::PMCNTENCLR_EL0;
+pub use self::pmcntenset_el0::PMCNTENSET_EL0;
+pub use self::pmcr_el0::PMCR_EL0;
+pub use self::pmevcntr_el0::*;
+pub use self::pmevtyper_el0::*;
+pub use self::pmintenclr_el1::PMINTENCLR_EL1;
+pub use self::pmintenset_el1::PMINTENSET_EL1;
+pub use self::pmovsclr_el0::PMOVSCLR_EL0;
+pub use self::pmselr_el0::PMSELR_EL0;
+pub use self::pmuserenr_el0::PMUSERENR_EL0;
+pub use self::pmxevcntr_el0::PMXEVCNTR_EL0;
+pub use self::pmxevtyper_el0::PMXEVTYPER_EL0;
+pub use self::rgsr_el1::RGSR_EL1;
 pub use self::sctlr_el1::SCTLR_EL1;
-pub use self::sctlr_el2::SCTLR_EL2;
+pub use self::sctlr_el2::{SCTLR_EL2, SCTLR_EL2_E2H};
 pub use self::sp::SP;
 pub use self::sp_el0::SP_EL0;
 pub use self::sp_el1::SP_EL1;
@@ -82,15 +195,26 @@ pub use self::spsel::SPSel;
 pub use self::spsr_el1::SPSR_EL1;
 pub use self::spsr_el2::SPSR_EL2;
 pub use self::tcr_el1::TCR_EL1;
-pub use self::tcr_el2::TCR_EL2;
+pub use self::tcr_el2::{TCR_EL2, TCR_EL2_E2H};
+pub use self::tfsr_el1::TFSR_EL1;
+pub use self::tfsre0_el1::TFSRE0_EL1;
+pub use self::tpidr_el0::TPIDR_EL0;
+pub use self::tpidr_el1::TPIDR_EL1;
+pub use self::tpidrro_el0::TPIDRRO_EL0;
 pub use self::ttbr0_el1::TTBR0_EL1;
 pub use self::ttbr1_el1::TTBR1_EL1;
 pub use self::ttbr0_el2::TTBR0_EL2;
+pub use self::ttbr1_el2::TTBR1_EL2;
 pub use self::vbar_el1::VBAR_EL1;
 pub use self::vbar_el2::VBAR_EL2;
+pub use self::vhe::*;
+pub use self::vsesr_el2::VSESR_EL2;
 pub use self::vtcr_el2::VTCR_EL2;
 pub use self::vttbr_el2::VTTBR_EL2;
 pub use self::hstr_el2::HSTR_EL2;
 pub use self::csselr_el1::CSSELR_EL1;
 pub use self::ccsidr_el1::CCSIDR_EL1;
+pub use self::sysreg::SysReg;
+pub use self::reserved::ReservedBits;
+pub use self::sync::ContextSync;
 
@@ -10,11 +10,50 @@
 //! In a multiprocessor system, provides an additional PE identification mechanism for scheduling
 //! purposes.
 
-use register::cpu::RegisterReadOnly;
+use register::{cpu::RegisterReadOnly, register_bitfields};
+
+register_bitfields! {u64,
+    pub MPIDR_EL1 [
+        /// Affinity level 3. See the description of Aff0 for more information.
+        Aff3 OFFSET(32) NUMBITS(8) [],
+
+        /// Indicates a Uniprocessor system, as distinct from PE 0 in a multiprocessor system. The
+        /// possible values of this bit are:
+        ///
+        /// 0 Processor is part of a multiprocessor system.
+        /// 1 Processor is part of a uniprocessor system.
+        U OFFSET(30) NUMBITS(1) [
+            Multiprocessor = 0,
+            Uniprocessor = 1
+        ],
+
+        /// Indicates whether the lowest level of affinity consists of logical PEs that are
+        /// implemented using a multithreading type approach. The possible values of this bit are:
+        ///
+        /// 0 Performance of PEs at the lowest affinity level is largely independent.
+        /// 1 Performance of PEs at the lowest affinity level is very interdependent.
+        MT OFFSET(24) NUMBITS(1) [
+            Independent = 0,
+            Multithreaded = 1
+        ],
+
+        /// Affinity level 2. See the description of Aff0 for more information.
+        Aff2 OFFSET(16) NUMBITS(8) [],
+
+        /// Affinity level 1. See the description of Aff0 for more information.
+        Aff1 OFFSET(8) NUMBITS(8) [],
+
+        /// Affinity level 0. This is the affinity level that is most significant for determining
+        /// PE behavior. Higher affinity levels are increasingly less significant in determining PE
+        /// behavior. The assigned value of the MPIDR.{Aff2, Aff1, Aff0} or MPIDR_EL1.{Aff3, Aff2,
+        /// Aff1, Aff0} set of fields of each PE must be unique within the system as a whole.
+        Aff0 OFFSET(0) NUMBITS(8) []
+    ]
+}
 
 pub struct Reg;
 
-impl RegisterReadOnly<u64, ()> for Reg {
+impl RegisterReadOnly<u64, MPIDR_EL1::Register> for Reg {
     sys_coproc_read_raw!(u64, "MPIDR_EL1");
 }
 
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! OS Double Lock Register - EL1
+//!
+//! Used to control the OS Double Lock.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u32,
+    pub OSDLR_EL1 [
+        /// OS Double Lock control bit.
+        ///
+        /// 0 OS Double Lock unlocked.
+        /// 1 OS Double Lock locked, if DBGPRCR_EL1.CORENPDRQ (Core no powerdown request) bit is
+        ///   set to 0 and the PE is in Non-debug state.
+        DLK OFFSET(0) NUMBITS(1) [
+            Unlocked = 0,
+            Locked = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u32, OSDLR_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u32, "OSDLR_EL1");
+    sys_coproc_write_raw!(u32, "OSDLR_EL1");
+}
+
+pub static OSDLR_EL1: Reg = Reg {};
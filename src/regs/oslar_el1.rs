@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! OS Lock Access Register - EL1
+//!
+//! Used to lock or unlock the OS Lock. While the OS Lock is locked, access to the debug registers
+//! that are used for saving and restoring state is permitted, and the generation of most debug
+//! events is prohibited.
+
+use register::{cpu::RegisterWriteOnly, register_bitfields};
+
+register_bitfields! {u32,
+    pub OSLAR_EL1 [
+        /// On writes to OSLAR_EL1, bit[0] is copied to the OS Lock.
+        ///
+        /// 0 Unlock the OS Lock.
+        /// 1 Lock the OS Lock.
+        OSLK OFFSET(0) NUMBITS(1) [
+            Unlocked = 0,
+            Locked = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterWriteOnly<u32, OSLAR_EL1::Register> for Reg {
+    sys_coproc_write_raw!(u32, "OSLAR_EL1");
+}
+
+pub static OSLAR_EL1: Reg = Reg {};
@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! OS Lock Status Register - EL1
+//!
+//! Provides the status of the OS Lock.
+
+use register::{cpu::RegisterReadOnly, register_bitfields};
+
+register_bitfields! {u32,
+    pub OSLSR_EL1 [
+        /// OS Lock Status.
+        ///
+        /// 0 OS Lock unlocked.
+        /// 1 OS Lock locked.
+        OSLK OFFSET(1) NUMBITS(1) [
+            Unlocked = 0,
+            Locked = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadOnly<u32, OSLSR_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u32, "OSLSR_EL1");
+}
+
+pub static OSLSR_EL1: Reg = Reg {};
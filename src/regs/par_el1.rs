@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Physical Address Register - EL1
+//!
+//! Returns the output address (OA) from an Address translation instruction that executed
+//! successfully, or fault information if the instruction did not execute successfully.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+// The layout depends on PAR_EL1.F. Both variants are provided, the fields that are only valid for
+// one of the two layouts say so.
+register_bitfields! {u64,
+    pub PAR_EL1 [
+        /// When PAR_EL1.F == 0, the memory attributes for the returned output address, in the
+        /// encoding of MAIR_EL1.
+        ATTR OFFSET(56) NUMBITS(8) [],
+
+        /// When PAR_EL1.F == 0, bits[51:12] of the output address. Bits[51:48] are RES0 if 52-bit
+        /// physical addresses are not implemented.
+        PA   OFFSET(12) NUMBITS(40) [],
+
+        /// When PAR_EL1.F == 0, the Non-secure attribute of the output address. When PAR_EL1.F ==
+        /// 1, S, the stage of translation that generated the fault:
+        ///
+        /// 0 Translation fault on stage 1.
+        /// 1 Translation fault on stage 2.
+        NS   OFFSET(9) NUMBITS(1) [],
+
+        /// When PAR_EL1.F == 0, the Shareability attribute for the returned output address.
+        SH   OFFSET(7) NUMBITS(2) [
+            None = 0b00,
+            Outer = 0b10,
+            Inner = 0b11
+        ],
+
+        /// When PAR_EL1.F == 1, indicates a stage 2 fault during a stage 1 translation table walk.
+        PTW  OFFSET(8) NUMBITS(1) [],
+
+        /// When PAR_EL1.F == 1, the fault status code, as shown in the Data Abort ESR encoding.
+        FST  OFFSET(1) NUMBITS(6) [],
+
+        /// Indicates whether the instruction performed a successful address translation.
+        ///
+        /// 0 Address translation completed successfully.
+        /// 1 Address translation aborted.
+        F    OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, PAR_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u64, "PAR_EL1");
+    sys_coproc_write_raw!(u64, "PAR_EL1");
+}
+
+pub static PAR_EL1: Reg = Reg {};
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Cycle Count Register - EL0
+//!
+//! Holds the value of the processor Cycle Counter, CCNT, that counts processor clock cycles.
+
+use register::cpu::RegisterReadWrite;
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, ()> for Reg {
+    sys_coproc_read_raw!(u64, "PMCCNTR_EL0");
+    sys_coproc_write_raw!(u64, "PMCCNTR_EL0");
+}
+
+pub static PMCCNTR_EL0: Reg = Reg {};
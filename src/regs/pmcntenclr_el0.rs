@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Count Enable Clear register - EL0
+//!
+//! Disables the Cycle Count Register, PMCCNTR_EL0, and any implemented event counters
+//! PMEVCNTR<n>_EL0. Reading this register shows which counters are enabled.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u32,
+    pub PMCNTENCLR_EL0 [
+        /// PMCCNTR_EL0 enable bit. On writes, writing 1 clears the bit, writing 0 has no effect.
+        C OFFSET(31) NUMBITS(1) [],
+
+        /// Event counter enable bits for PMEVCNTR<n>_EL0, one bit per counter. On writes, writing 1
+        /// to bit n clears the bit for counter n, writing 0 has no effect.
+        P OFFSET(0) NUMBITS(31) []
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u32, PMCNTENCLR_EL0::Register> for Reg {
+    sys_coproc_read_raw!(u32, "PMCNTENCLR_EL0");
+    sys_coproc_write_raw!(u32, "PMCNTENCLR_EL0");
+}
+
+pub static PMCNTENCLR_EL0: Reg = Reg {};
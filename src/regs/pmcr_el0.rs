@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Control Register - EL0
+//!
+//! Provides details of the Performance Monitors implementation, including the number of counters
+//! implemented, and configures and controls the counters.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u32,
+    pub PMCR_EL0 [
+        /// Implementer code. This field is read-only.
+        IMP    OFFSET(24) NUMBITS(8) [],
+
+        /// Identification code. This field is read-only.
+        IDCODE OFFSET(16) NUMBITS(8) [],
+
+        /// Indicates the number of event counters implemented. This field is read-only.
+        ///
+        /// The value is in the range of 0b00000-0b11111. If the value is 0b00000, then only
+        /// PMCCNTR_EL0 is implemented.
+        N      OFFSET(11) NUMBITS(5) [],
+
+        /// Long event counter enable. Determines when unsigned overflow is recorded by an event
+        /// counter overflow bit. Only implemented if ARMv8.5-PMU is implemented.
+        ///
+        /// 0 Event counter overflow on increment that causes unsigned overflow of
+        ///   PMEVCNTR<n>_EL0[31:0].
+        /// 1 Event counter overflow on increment that causes unsigned overflow of
+        ///   PMEVCNTR<n>_EL0[63:0].
+        LP     OFFSET(7) NUMBITS(1) [],
+
+        /// Long cycle counter enable. Determines when unsigned overflow is recorded by the cycle
+        /// counter overflow bit.
+        ///
+        /// 0 Cycle counter overflow on increment that causes unsigned overflow of
+        ///   PMCCNTR_EL0[31:0].
+        /// 1 Cycle counter overflow on increment that causes unsigned overflow of
+        ///   PMCCNTR_EL0[63:0].
+        LC     OFFSET(6) NUMBITS(1) [],
+
+        /// Disable cycle counter when event counting is prohibited.
+        ///
+        /// 0 PMCCNTR_EL0, if enabled, counts when event counting is prohibited.
+        /// 1 PMCCNTR_EL0 does not count when event counting is prohibited.
+        DP     OFFSET(5) NUMBITS(1) [],
+
+        /// Enable export of events in an IMPLEMENTATION DEFINED PMU event export bus.
+        X      OFFSET(4) NUMBITS(1) [],
+
+        /// Clock divider.
+        ///
+        /// 0 When enabled, PMCCNTR_EL0 counts every clock cycle.
+        /// 1 When enabled, PMCCNTR_EL0 counts once every 64 clock cycles.
+        D      OFFSET(3) NUMBITS(1) [],
+
+        /// Cycle counter reset. This bit is WO, and reads as zero.
+        ///
+        /// 0 No action.
+        /// 1 Reset PMCCNTR_EL0 to zero.
+        C      OFFSET(2) NUMBITS(1) [],
+
+        /// Event counter reset. This bit is WO, and reads as zero.
+        ///
+        /// 0 No action.
+        /// 1 Reset all event counters accessible in the current Exception level, not including
+        ///   PMCCNTR_EL0, to zero.
+        P      OFFSET(1) NUMBITS(1) [],
+
+        /// Enable.
+        ///
+        /// 0 All counters that are accessible at Non-secure EL1, including PMCCNTR_EL0, are
+        ///   disabled.
+        /// 1 All counters that are accessible at Non-secure EL1 are enabled by PMCNTENSET_EL0.
+        E      OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u32, PMCR_EL0::Register> for Reg {
+    sys_coproc_read_raw!(u32, "PMCR_EL0");
+    sys_coproc_write_raw!(u32, "PMCR_EL0");
+}
+
+pub static PMCR_EL0: Reg = Reg {};
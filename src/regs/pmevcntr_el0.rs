@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Event Count Registers - EL0
+//!
+//! Hold the event counts of the event counters. PMEVTYPER<n>_EL0 selects the event counted by
+//! PMEVCNTR<n>_EL0.
+
+use crate::regs::SysReg;
+
+macro_rules! pmevcntr {
+    ($($name:ident = $n:expr,)*) => {
+        $(
+            pub static $name: SysReg<(), 3, 3, 14, { 0b1000 | ($n >> 3) }, { $n & 0b111 }> =
+                SysReg::new();
+        )*
+    };
+}
+
+pmevcntr! {
+    PMEVCNTR0_EL0 = 0,
+    PMEVCNTR1_EL0 = 1,
+    PMEVCNTR2_EL0 = 2,
+    PMEVCNTR3_EL0 = 3,
+    PMEVCNTR4_EL0 = 4,
+    PMEVCNTR5_EL0 = 5,
+    PMEVCNTR6_EL0 = 6,
+    PMEVCNTR7_EL0 = 7,
+    PMEVCNTR8_EL0 = 8,
+    PMEVCNTR9_EL0 = 9,
+    PMEVCNTR10_EL0 = 10,
+    PMEVCNTR11_EL0 = 11,
+    PMEVCNTR12_EL0 = 12,
+    PMEVCNTR13_EL0 = 13,
+    PMEVCNTR14_EL0 = 14,
+    PMEVCNTR15_EL0 = 15,
+    PMEVCNTR16_EL0 = 16,
+    PMEVCNTR17_EL0 = 17,
+    PMEVCNTR18_EL0 = 18,
+    PMEVCNTR19_EL0 = 19,
+    PMEVCNTR20_EL0 = 20,
+    PMEVCNTR21_EL0 = 21,
+    PMEVCNTR22_EL0 = 22,
+    PMEVCNTR23_EL0 = 23,
+    PMEVCNTR24_EL0 = 24,
+    PMEVCNTR25_EL0 = 25,
+    PMEVCNTR26_EL0 = 26,
+    PMEVCNTR27_EL0 = 27,
+    PMEVCNTR28_EL0 = 28,
+    PMEVCNTR29_EL0 = 29,
+    PMEVCNTR30_EL0 = 30,
+}
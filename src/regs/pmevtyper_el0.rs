@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Event Type Registers - EL0
+//!
+//! Configure which event increments the event counter PMEVCNTR<n>_EL0, and the modes in which it
+//! is counted. The field layout is shared with PMXEVTYPER_EL0.
+
+use crate::regs::SysReg;
+use register::register_bitfields;
+
+register_bitfields! {u64,
+    pub PMEVTYPER_EL0 [
+        /// Privileged filtering bit. Controls counting in EL1.
+        ///
+        /// 0 Count events in EL1.
+        /// 1 Do not count events in EL1.
+        P        OFFSET(31) NUMBITS(1) [],
+
+        /// User filtering bit. Controls counting in EL0.
+        ///
+        /// 0 Count events in EL0.
+        /// 1 Do not count events in EL0.
+        U        OFFSET(30) NUMBITS(1) [],
+
+        /// Non-secure kernel modes filtering bit. If the value of this bit is equal to the value
+        /// of P, events in Non-secure EL1 are counted, otherwise they are not.
+        NSK      OFFSET(29) NUMBITS(1) [],
+
+        /// Non-secure user modes filtering bit. If the value of this bit is equal to the value of
+        /// U, events in Non-secure EL0 are counted, otherwise they are not.
+        NSU      OFFSET(28) NUMBITS(1) [],
+
+        /// EL2 (Hypervisor) filtering bit.
+        ///
+        /// 0 Do not count events in EL2.
+        /// 1 Count events in EL2.
+        NSH      OFFSET(27) NUMBITS(1) [],
+
+        /// Secure EL3 filtering bit. If the value of this bit is equal to the value of P, events in
+        /// Secure EL3 are counted, otherwise they are not.
+        M        OFFSET(26) NUMBITS(1) [],
+
+        /// Event to count. The event number of the event that is counted by the selected event
+        /// counter. Bits [15:10] are RES0 if ARMv8.1-PMU is not implemented.
+        EVTCOUNT OFFSET(0) NUMBITS(16) []
+    ]
+}
+
+macro_rules! pmevtyper {
+    ($($name:ident = $n:expr,)*) => {
+        $(
+            pub static $name: SysReg<
+                PMEVTYPER_EL0::Register, 3, 3, 14, { 0b1100 | ($n >> 3) }, { $n & 0b111 }
+            > = SysReg::new();
+        )*
+    };
+}
+
+pmevtyper! {
+    PMEVTYPER0_EL0 = 0,
+    PMEVTYPER1_EL0 = 1,
+    PMEVTYPER2_EL0 = 2,
+    PMEVTYPER3_EL0 = 3,
+    PMEVTYPER4_EL0 = 4,
+    PMEVTYPER5_EL0 = 5,
+    PMEVTYPER6_EL0 = 6,
+    PMEVTYPER7_EL0 = 7,
+    PMEVTYPER8_EL0 = 8,
+    PMEVTYPER9_EL0 = 9,
+    PMEVTYPER10_EL0 = 10,
+    PMEVTYPER11_EL0 = 11,
+    PMEVTYPER12_EL0 = 12,
+    PMEVTYPER13_EL0 = 13,
+    PMEVTYPER14_EL0 = 14,
+    PMEVTYPER15_EL0 = 15,
+    PMEVTYPER16_EL0 = 16,
+    PMEVTYPER17_EL0 = 17,
+    PMEVTYPER18_EL0 = 18,
+    PMEVTYPER19_EL0 = 19,
+    PMEVTYPER20_EL0 = 20,
+    PMEVTYPER21_EL0 = 21,
+    PMEVTYPER22_EL0 = 22,
+    PMEVTYPER23_EL0 = 23,
+    PMEVTYPER24_EL0 = 24,
+    PMEVTYPER25_EL0 = 25,
+    PMEVTYPER26_EL0 = 26,
+    PMEVTYPER27_EL0 = 27,
+    PMEVTYPER28_EL0 = 28,
+    PMEVTYPER29_EL0 = 29,
+    PMEVTYPER30_EL0 = 30,
+}
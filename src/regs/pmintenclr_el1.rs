@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Interrupt Enable Clear register - EL1
+//!
+//! Disables the generation of interrupt requests on overflows from the cycle count register,
+//! PMCCNTR_EL0, and the event counters PMEVCNTR<n>_EL0.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u32,
+    pub PMINTENCLR_EL1 [
+        /// PMCCNTR_EL0 overflow interrupt request enable bit. On writes, writing 1 clears the bit, writing 0 has no effect.
+        C OFFSET(31) NUMBITS(1) [],
+
+        /// Event counter overflow interrupt request enable bits for PMEVCNTR<n>_EL0, one bit per counter. On writes, writing 1
+        /// to bit n clears the bit for counter n, writing 0 has no effect.
+        P OFFSET(0) NUMBITS(31) []
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u32, PMINTENCLR_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u32, "PMINTENCLR_EL1");
+    sys_coproc_write_raw!(u32, "PMINTENCLR_EL1");
+}
+
+pub static PMINTENCLR_EL1: Reg = Reg {};
@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Overflow Flag Status Clear Register - EL0
+//!
+//! Contains the state of the overflow bit for the Cycle Count Register, PMCCNTR_EL0, and each of
+//! the implemented event counters PMEVCNTR<n>_EL0. Writing to this register clears these bits.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u32,
+    pub PMOVSCLR_EL0 [
+        /// PMCCNTR_EL0 overflow bit. On writes, writing 1 clears the bit, writing 0 has no effect.
+        C OFFSET(31) NUMBITS(1) [],
+
+        /// Event counter overflow bits for PMEVCNTR<n>_EL0, one bit per counter. On writes, writing 1
+        /// to bit n clears the bit for counter n, writing 0 has no effect.
+        P OFFSET(0) NUMBITS(31) []
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u32, PMOVSCLR_EL0::Register> for Reg {
+    sys_coproc_read_raw!(u32, "PMOVSCLR_EL0");
+    sys_coproc_write_raw!(u32, "PMOVSCLR_EL0");
+}
+
+pub static PMOVSCLR_EL0: Reg = Reg {};
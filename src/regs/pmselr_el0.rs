@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Event Counter Selection Register - EL0
+//!
+//! Selects the current event counter PMEVCNTR<n>_EL0 or the cycle counter, CCNT, for accesses
+//! through PMXEVTYPER_EL0 and PMXEVCNTR_EL0.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u32,
+    pub PMSELR_EL0 [
+        /// Selects event counter, PMEVCNTR<n>_EL0, where n is the value held in this field. This
+        /// value identifies which event counter is accessed when a subsequent access to
+        /// PMXEVTYPER_EL0 or PMXEVCNTR_EL0 occurs.
+        ///
+        /// When PMSELR_EL0.SEL is 0b11111, it selects the cycle counter filter register
+        /// PMCCFILTR_EL0.
+        SEL OFFSET(0) NUMBITS(5) []
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u32, PMSELR_EL0::Register> for Reg {
+    sys_coproc_read_raw!(u32, "PMSELR_EL0");
+    sys_coproc_write_raw!(u32, "PMSELR_EL0");
+}
+
+pub static PMSELR_EL0: Reg = Reg {};
@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors User Enable Register - EL0
+//!
+//! Enables or disables EL0 access to the Performance Monitors.
+
+use register::{cpu::RegisterReadWrite, register_bitfields};
+
+register_bitfields! {u32,
+    pub PMUSERENR_EL0 [
+        /// Event counter read trap control.
+        ///
+        /// 0 EL0 using AArch64: EL0 reads of the PMXEVCNTR_EL0 and PMEVCNTR<n>_EL0, and EL0 read/
+        ///   write accesses to the PMSELR_EL0, are trapped if PMUSERENR_EL0.EN is also 0.
+        /// 1 Overrides PMUSERENR_EL0.EN and enables EL0 reads of the event counters and read/write
+        ///   of PMSELR_EL0.
+        ER OFFSET(3) NUMBITS(1) [],
+
+        /// Cycle counter read trap control.
+        ///
+        /// 0 EL0 reads of the PMCCNTR_EL0 are trapped if PMUSERENR_EL0.EN is also 0.
+        /// 1 Overrides PMUSERENR_EL0.EN and enables EL0 reads of the PMCCNTR_EL0.
+        CR OFFSET(2) NUMBITS(1) [],
+
+        /// Software Increment write trap control.
+        ///
+        /// 0 EL0 writes to the PMSWINC_EL0 are trapped if PMUSERENR_EL0.EN is also 0.
+        /// 1 Overrides PMUSERENR_EL0.EN and enables EL0 writes to the PMSWINC_EL0.
+        SW OFFSET(1) NUMBITS(1) [],
+
+        /// Traps EL0 accesses to the Performance Monitors registers to EL1.
+        ///
+        /// 0 EL0 accesses to the Performance Monitors registers are trapped, unless overridden by
+        ///   ER, CR or SW.
+        /// 1 This control does not cause any instructions to be trapped.
+        EN OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl RegisterReadWrite<u32, PMUSERENR_EL0::Register> for Reg {
+    sys_coproc_read_raw!(u32, "PMUSERENR_EL0");
+    sys_coproc_write_raw!(u32, "PMUSERENR_EL0");
+}
+
+pub static PMUSERENR_EL0: Reg = Reg {};
@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Selected Event Count Register - EL0
+//!
+//! Reads or writes the value of the selected event counter, PMEVCNTR<n>_EL0. PMSELR_EL0.SEL
+//! determines which event counter is selected.
+
+use register::cpu::RegisterReadWrite;
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, ()> for Reg {
+    sys_coproc_read_raw!(u64, "PMXEVCNTR_EL0");
+    sys_coproc_write_raw!(u64, "PMXEVCNTR_EL0");
+}
+
+pub static PMXEVCNTR_EL0: Reg = Reg {};
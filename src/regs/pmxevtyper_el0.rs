@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Selected Event Type Register - EL0
+//!
+//! When PMSELR_EL0.SEL selects an event counter, this accesses a PMEVTYPER<n>_EL0 register. When
+//! PMSELR_EL0.SEL selects the cycle counter, this accesses PMCCFILTR_EL0.
+
+use super::pmevtyper_el0::PMEVTYPER_EL0;
+use register::cpu::RegisterReadWrite;
+
+pub struct Reg;
+
+impl RegisterReadWrite<u64, PMEVTYPER_EL0::Register> for Reg {
+    sys_coproc_read_raw!(u64, "PMXEVTYPER_EL0");
+    sys_coproc_write_raw!(u64, "PMXEVTYPER_EL0");
+}
+
+pub static PMXEVTYPER_EL0: Reg = Reg {};
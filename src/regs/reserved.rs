@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Reserved bits
+//!
+//! `write()` clears every bit that is not named in its argument, including bits that are RES1.
+//! Depending on the implemented features, such a bit either must be written as one, or selects a
+//! behavior that differs from ARMv8.0, e.g. `SCTLR_EL1.EIS` making exception entry non-context
+//! synchronizing. Conversely, `FieldValue::new()` and `set()` can put ones into RES0 bits, whose
+//! meaning may be defined by a later version of the architecture.
+//!
+//! Registers with reserved bits implement [`ReservedBits`], whose writes set RES1 bits unless the
+//! caller names them explicitly, and always clear RES0 bits. Modifies keep the current value of the
+//! bits that are not named, so that a feature the caller disabled stays disabled, and only set the
+//! bits that are RES1 in all versions of the architecture. In debug builds, naming a RES0 bit or
+//! clearing a bit that is RES1 in all versions of the architecture panics:
+//!
+//! ```ignore
+//! use cortex_a::regs::*;
+//!
+//! SCTLR_EL2.write_with_reserved(SCTLR_EL2::M::Enable + SCTLR_EL2::C::Cacheable);
+//! ```
+//!
+//! Registers that require a Context synchronization event after a write also provide
+//! `write_with_reserved_sync()` and `modify_with_reserved_sync()`, see [`ContextSync`].
+//!
+//! [`ContextSync`]: super::ContextSync
+//!
+//! RES0 masks cover the bits that are reserved up to ARMv8.8.
+
+use register::{cpu::RegisterReadWrite, FieldValue, IntLike, RegisterLongName};
+
+/// Writes that respect the reserved bits of a register.
+pub trait ReservedBits<T: IntLike, R: RegisterLongName>: RegisterReadWrite<T, R> {
+    /// Bits that are RES1, or that are RES1 when the feature they control is not implemented and
+    /// select the ARMv8.0 behavior when set.
+    const RES1: T;
+
+    /// The bits of `RES1` that are RES1 regardless of the implemented features.
+    const FIXED_RES1: T;
+
+    /// Bits that are RES0.
+    const RES0: T;
+
+    /// Writes `field`, with all RES1 bits that `field` does not cover set to one, and all RES0
+    /// bits cleared.
+    #[inline]
+    fn write_with_reserved(&self, field: FieldValue<T, R>) {
+        debug_assert!(field.value & Self::RES0 == T::zero(), "write to RES0 bits");
+        debug_assert!(
+            field.mask & Self::FIXED_RES1 & !field.value == T::zero(),
+            "write of zero to RES1 bits"
+        );
+
+        self.set((field.value | (Self::RES1 & !field.mask)) & !Self::RES0);
+    }
+
+    /// Modifies the bits covered by `field`, sets all `FIXED_RES1` bits, and clears all RES0 bits.
+    /// The other bits keep their current value.
+    #[inline]
+    fn modify_with_reserved(&self, field: FieldValue<T, R>) {
+        debug_assert!(field.value & Self::RES0 == T::zero(), "write to RES0 bits");
+        debug_assert!(
+            field.mask & Self::FIXED_RES1 & !field.value == T::zero(),
+            "write of zero to RES1 bits"
+        );
+
+        self.set((field.modify(self.get()) | Self::FIXED_RES1) & !Self::RES0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::regs::{SCTLR_EL1, TCR_EL2};
+    use core::cell::Cell;
+
+    /// A register held in memory, with the reserved bits of `SCTLR_EL1` or `TCR_EL2`.
+    struct Fake(Cell<u64>);
+
+    impl RegisterReadWrite<u64, SCTLR_EL1::Register> for Fake {
+        fn get(&self) -> u64 {
+            self.0.get()
+        }
+
+        fn set(&self, value: u64) {
+            self.0.set(value)
+        }
+    }
+
+    impl ReservedBits<u64, SCTLR_EL1::Register> for Fake {
+        const RES1: u64 = <super::super::sctlr_el1::Reg as ReservedBits<_, _>>::RES1;
+        const FIXED_RES1: u64 = <super::super::sctlr_el1::Reg as ReservedBits<_, _>>::FIXED_RES1;
+        const RES0: u64 = <super::super::sctlr_el1::Reg as ReservedBits<_, _>>::RES0;
+    }
+
+    impl RegisterReadWrite<u64, TCR_EL2::Register> for Fake {
+        fn get(&self) -> u64 {
+            self.0.get()
+        }
+
+        fn set(&self, value: u64) {
+            self.0.set(value)
+        }
+    }
+
+    impl ReservedBits<u64, TCR_EL2::Register> for Fake {
+        const RES1: u64 = <super::super::tcr_el2::Reg as ReservedBits<_, _>>::RES1;
+        const FIXED_RES1: u64 = <super::super::tcr_el2::Reg as ReservedBits<_, _>>::FIXED_RES1;
+        const RES0: u64 = <super::super::tcr_el2::Reg as ReservedBits<_, _>>::RES0;
+    }
+
+    const SCTLR_RES1: u64 = 0x30D0_0800;
+    const SPAN: u64 = 1 << 23;
+
+    #[test]
+    fn write_sets_res1() {
+        let reg = Fake(Cell::new(0));
+
+        ReservedBits::<_, SCTLR_EL1::Register>::write_with_reserved(&reg, SCTLR_EL1::M::Enable);
+        assert_eq!(reg.0.get(), SCTLR_RES1 | 1);
+
+        // Named RES1 bits are written as given.
+        ReservedBits::<_, SCTLR_EL1::Register>::write_with_reserved(
+            &reg,
+            SCTLR_EL1::M::Enable + SCTLR_EL1::SPAN::CLEAR,
+        );
+        assert_eq!(reg.0.get(), SCTLR_RES1 & !SPAN | 1);
+    }
+
+    #[test]
+    fn write_clears_res0() {
+        let reg = Fake(Cell::new(0));
+
+        ReservedBits::<_, TCR_EL2::Register>::write_with_reserved(&reg, TCR_EL2::T0SZ.val(16));
+        assert_eq!(reg.0.get(), 0x8080_0000 | 16);
+    }
+
+    #[test]
+    fn modify_keeps_cleared_features() {
+        // SPAN was cleared by the caller, and bit 17 is RES0.
+        let reg = Fake(Cell::new(SCTLR_RES1 & !SPAN | 1 << 17));
+
+        ReservedBits::<_, SCTLR_EL1::Register>::modify_with_reserved(&reg, SCTLR_EL1::C::Cacheable);
+        assert_eq!(reg.0.get(), SCTLR_RES1 & !SPAN | 1 << 2);
+    }
+
+    #[test]
+    fn modify_sets_fixed_res1() {
+        let reg = Fake(Cell::new(0));
+
+        ReservedBits::<_, TCR_EL2::Register>::modify_with_reserved(&reg, TCR_EL2::T0SZ.val(16));
+        assert_eq!(reg.0.get(), 0x8080_0000 | 16);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "write to RES0 bits")]
+    fn res0_asserted() {
+        let reg = Fake(Cell::new(0));
+        let field = register::FieldValue::<u64, TCR_EL2::Register>::new(1, 7, 1);
+
+        ReservedBits::<_, TCR_EL2::Register>::write_with_reserved(&reg, field);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "write of zero to RES1 bits")]
+    fn fixed_res1_asserted() {
+        let reg = Fake(Cell::new(0));
+        let field = register::FieldValue::<u64, TCR_EL2::Register>::new(1, 31, 0);
+
+        ReservedBits::<_, TCR_EL2::Register>::modify_with_reserved(&reg, field);
+    }
+}
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Random Allocation Tag Seed Register - EL1
+//!
+//! Holds the seed and the last generated Allocation Tag of the pseudorandom tag generator used
+//! by IRG when GCR_EL1.RRND is 0.
+
+use crate::regs::SysReg;
+use register::register_bitfields;
+
+register_bitfields! {u64,
+    pub RGSR_EL1 [
+        /// Seed of the pseudorandom tag generator. Must be non-zero for random tags to be
+        /// generated.
+        SEED OFFSET(8) NUMBITS(16) [],
+
+        /// The tag generated by the most recent IRG instruction.
+        TAG  OFFSET(0) NUMBITS(4) []
+    ]
+}
+
+pub static RGSR_EL1: SysReg<RGSR_EL1::Register, 3, 0, 1, 0, 5> = SysReg::new();
@@ -8,11 +8,326 @@
 //! System Control Register - EL1
 //!
 //! Provides top level control of the system, including its memory system, at EL1 and EL0.
+//!
+//! The register is accessed as `u64`, as the fields of ARMv8.5-MemTag and later extensions are
+//! above bit 31. Before, it was accessed as `u32`, so code that calls `get()` or `set()` with a
+//! `u32` value, or builds a `FieldValue<u32, _>`, needs to be changed to `u64`.
 
+use super::{ContextSync, ReservedBits};
 use register::{cpu::RegisterReadWrite, register_bitfields};
 
-register_bitfields! {u32,
+register_bitfields! {u64,
     pub SCTLR_EL1 [
+        /// Trap IMPLEMENTATION DEFINED functionality. When ARMv8.8-TIDCP1 is implemented:
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL0 accesses to the System register encodings reserved for IMPLEMENTATION DEFINED
+        ///   functionality are trapped to EL1.
+        ///
+        /// Otherwise, this bit is RES0.
+        TIDCP OFFSET(63) NUMBITS(1) [],
+
+        /// SP Interrupt Mask enable. When SCTLR_EL1.NMI is 1, controls whether PSTATE.SP acts as an
+        /// interrupt mask, and controls the value of PSTATE.ALLINT on taking an exception to EL1.
+        ///
+        /// When ARMv8.8-NMI is not implemented, this bit is RES0.
+        SPINTMASK OFFSET(62) NUMBITS(1) [],
+
+        /// Non-maskable Interrupt enable.
+        ///
+        /// 0 This control does not affect interrupt masking behavior.
+        /// 1 This control enables all of the PSTATE.ALLINT, superpriority and SP_ELx interrupt
+        ///   masking functionality.
+        ///
+        /// When ARMv8.8-NMI is not implemented, this bit is RES0.
+        NMI OFFSET(61) NUMBITS(1) [],
+
+        /// Traps instructions executed at EL0 that access TPIDR2_EL0 to EL1 when 0.
+        ///
+        /// When SME is not implemented, this bit is RES0.
+        EnTP2 OFFSET(60) NUMBITS(1) [],
+
+        /// Enhanced Privileged Access Never. When PSTATE.PAN is 1, determines whether an EL1 data
+        /// access to a page with EL0 instruction access permission generates a Permission fault.
+        ///
+        /// 0 No additional Permission faults are generated by this mechanism.
+        /// 1 An EL1 data access to a page with stage 1 EL0 instruction access permission generates
+        ///   a Permission fault.
+        ///
+        /// When ARMv8.7-PAN3 is not implemented, this bit is RES0.
+        EPAN OFFSET(57) NUMBITS(1) [],
+
+        /// When 0, traps execution of an LD64B or ST64B instruction at EL0 to EL1.
+        ///
+        /// When ARMv8.7-LS64 is not implemented, this bit is RES0.
+        EnALS OFFSET(56) NUMBITS(1) [],
+
+        /// When 0, traps execution of an ST64BV0 instruction at EL0 to EL1.
+        ///
+        /// When ARMv8.7-LS64_ACCDATA is not implemented, this bit is RES0.
+        EnAS0 OFFSET(55) NUMBITS(1) [],
+
+        /// When 0, traps execution of an ST64BV instruction at EL0 to EL1.
+        ///
+        /// When ARMv8.7-LS64_V is not implemented, this bit is RES0.
+        EnASR OFFSET(54) NUMBITS(1) [],
+
+        /// Enables the Transactional Memory Extension at EL1. When 0, TSTART at EL1 is UNDEFINED.
+        ///
+        /// When TME is not implemented, this bit is RES0.
+        TME OFFSET(53) NUMBITS(1) [],
+
+        /// Enables the Transactional Memory Extension at EL0. When 0, TSTART at EL0 is UNDEFINED.
+        ///
+        /// When TME is not implemented, this bit is RES0.
+        TME0 OFFSET(52) NUMBITS(1) [],
+
+        /// Forces a trivial implementation of the Transactional Memory Extension at EL1, in which
+        /// every transaction fails.
+        ///
+        /// When TME is not implemented, this bit is RES0.
+        TMT OFFSET(51) NUMBITS(1) [],
+
+        /// Forces a trivial implementation of the Transactional Memory Extension at EL0, in which
+        /// every transaction fails.
+        ///
+        /// When TME is not implemented, this bit is RES0.
+        TMT0 OFFSET(50) NUMBITS(1) [],
+
+        /// TWE Delay. The delay before a WFE instruction at EL0 is trapped, when SCTLR_EL1.TWEDEn
+        /// is 1, is at least 2^(TWEDEL + 8) cycles.
+        ///
+        /// When ARMv8.6-TWED is not implemented, this field is RES0.
+        TWEDEL OFFSET(46) NUMBITS(4) [],
+
+        /// TWE Delay Enable. Enables a configurable delayed trap of the WFE instruction executed at
+        /// EL0.
+        ///
+        /// When ARMv8.6-TWED is not implemented, this bit is RES0.
+        TWEDEn OFFSET(45) NUMBITS(1) [],
+
+        /// Default PSTATE.SSBS value on Exception Entry to EL1.
+        ///
+        /// When ARMv8.5-SSBS is not implemented, this bit is RES0.
+        DSSBS OFFSET(44) NUMBITS(1) [],
+
+        /// Allocation Tag Access in EL1. Controls EL1 access to Allocation Tags.
+        ///
+        /// 0 Access to Allocation Tags is prevented.
+        /// 1 Access to Allocation Tags is not prevented.
+        ///
+        /// When ARMv8.5-MemTag is not implemented, this bit is RES0.
+        ATA OFFSET(43) NUMBITS(1) [],
+
+        /// Allocation Tag Access in EL0. Controls EL0 access to Allocation Tags.
+        ///
+        /// 0 Access to Allocation Tags is prevented.
+        /// 1 Access to Allocation Tags is not prevented.
+        ///
+        /// When ARMv8.5-MemTag is not implemented, this bit is RES0.
+        ATA0 OFFSET(42) NUMBITS(1) [],
+
+        /// Tag Check Fault in EL1. Controls the effect of Tag Check Faults due to Loads and Stores
+        /// in EL1.
+        ///
+        /// 00 Tag Check Faults have no effect on the PE.
+        /// 01 Tag Check Faults cause a synchronous exception.
+        /// 10 Tag Check Faults are asynchronously accumulated in TFSR_EL1.TF1 or TFSR_EL1.TF0.
+        /// 11 When ARMv8.7-MTE3 is implemented, Tag Check Faults cause a synchronous exception on
+        ///    reads, and are asynchronously accumulated on writes.
+        ///
+        /// When ARMv8.5-MemTag is not implemented, this field is RES0.
+        TCF OFFSET(40) NUMBITS(2) [
+            None = 0b00,
+            Sync = 0b01,
+            Async = 0b10,
+            Asymmetric = 0b11
+        ],
+
+        /// Tag Check Fault in EL0. Controls the effect of Tag Check Faults due to Loads and Stores
+        /// in EL0.
+        ///
+        /// 00 Tag Check Faults have no effect on the PE.
+        /// 01 Tag Check Faults cause a synchronous exception.
+        /// 10 Tag Check Faults are asynchronously accumulated in TFSRE0_EL1.TF0.
+        /// 11 When ARMv8.7-MTE3 is implemented, Tag Check Faults cause a synchronous exception on
+        ///    reads, and are asynchronously accumulated on writes.
+        ///
+        /// When ARMv8.5-MemTag is not implemented, this field is RES0.
+        TCF0 OFFSET(38) NUMBITS(2) [
+            None = 0b00,
+            Sync = 0b01,
+            Async = 0b10,
+            Asymmetric = 0b11
+        ],
+
+        /// When synchronous exceptions are not being generated by Tag Check Faults, this field
+        /// controls whether on exception entry into EL1, all Tag Check Faults due to instructions
+        /// executed before exception entry, that are reported asynchronously, are synchronized
+        /// into TFSRE0_EL1 and TFSR_EL1 registers.
+        ///
+        /// When ARMv8.5-MemTag is not implemented, this bit is RES0.
+        ITFSB OFFSET(37) NUMBITS(1) [],
+
+        /// PAC Branch Type compatibility at EL1.
+        ///
+        /// 0 When the PE is executing at EL1, PACIASP and PACIBSP are compatible with
+        ///   PSTATE.BTYPE == 0b11.
+        /// 1 When the PE is executing at EL1, PACIASP and PACIBSP are not compatible with
+        ///   PSTATE.BTYPE == 0b11.
+        ///
+        /// When ARMv8.5-BTI is not implemented, this bit is RES0.
+        BT1 OFFSET(36) NUMBITS(1) [],
+
+        /// PAC Branch Type compatibility at EL0.
+        ///
+        /// 0 When the PE is executing at EL0, PACIASP and PACIBSP are compatible with
+        ///   PSTATE.BTYPE == 0b11.
+        /// 1 When the PE is executing at EL0, PACIASP and PACIBSP are not compatible with
+        ///   PSTATE.BTYPE == 0b11.
+        ///
+        /// When ARMv8.5-BTI is not implemented, this bit is RES0.
+        BT0 OFFSET(35) NUMBITS(1) [],
+
+        /// Controls enabling of pointer authentication (using the APIAKey_EL1 key) of instruction
+        /// addresses in the EL1&0 translation regime.
+        ///
+        /// 0 Pointer authentication (using the APIAKey_EL1 key) of instruction addresses is not
+        ///   enabled.
+        /// 1 Pointer authentication (using the APIAKey_EL1 key) of instruction addresses is
+        ///   enabled.
+        ///
+        /// When ARMv8.3-PAuth is not implemented, this bit is RES0.
+        EnIA OFFSET(31) NUMBITS(1) [],
+
+        /// Controls enabling of pointer authentication (using the APIBKey_EL1 key) of instruction
+        /// addresses in the EL1&0 translation regime.
+        ///
+        /// 0 Pointer authentication (using the APIBKey_EL1 key) of instruction addresses is not
+        ///   enabled.
+        /// 1 Pointer authentication (using the APIBKey_EL1 key) of instruction addresses is
+        ///   enabled.
+        ///
+        /// When ARMv8.3-PAuth is not implemented, this bit is RES0.
+        EnIB OFFSET(30) NUMBITS(1) [],
+
+        /// Load Multiple and Store Multiple Atomicity and Ordering Enable, for AArch32 EL0.
+        ///
+        /// When ARMv8.2-LSMAOC is not implemented, this bit is RES1.
+        LSMAOE OFFSET(29) NUMBITS(1) [],
+
+        /// No Trap Load Multiple and Store Multiple to Device-nGRE/Device-nGnRE/Device-nGnRnE
+        /// memory, for AArch32 EL0.
+        ///
+        /// When ARMv8.2-LSMAOC is not implemented, this bit is RES1.
+        nTLSMD OFFSET(28) NUMBITS(1) [],
+
+        /// Controls enabling of pointer authentication (using the APDAKey_EL1 key) of data
+        /// addresses in the EL1&0 translation regime.
+        ///
+        /// 0 Pointer authentication (using the APDAKey_EL1 key) of data addresses is not enabled.
+        /// 1 Pointer authentication (using the APDAKey_EL1 key) of data addresses is enabled.
+        ///
+        /// When ARMv8.3-PAuth is not implemented, this bit is RES0.
+        EnDA OFFSET(27) NUMBITS(1) [],
+
+        /// Traps EL0 execution of cache maintenance instructions to EL1:
+        ///
+        /// 0 EL0 execution of DC CVAU, DC CIVAC, DC CVAC, DC CVAP, DC CVADP and IC IVAU is trapped.
+        /// 1 This control does not cause any instructions to be trapped.
+        UCI OFFSET(26) NUMBITS(1) [],
+
+        /// Endianness of data accesses at EL1, and stage 1 translation table walks in the EL1&0
+        /// translation regime.
+        EE OFFSET(25) NUMBITS(1) [
+            LittleEndian = 0,
+            BigEndian = 1
+        ],
+
+        /// Endianness of data accesses at EL0.
+        E0E OFFSET(24) NUMBITS(1) [
+            LittleEndian = 0,
+            BigEndian = 1
+        ],
+
+        /// Set Privileged Access Never, on taking an exception to EL1.
+        ///
+        /// 0 The value of PSTATE.PAN is set to 1 on taking an exception to EL1.
+        /// 1 The value of PSTATE.PAN is left unchanged on taking an exception to EL1.
+        ///
+        /// When ARMv8.1-PAN is not implemented, this bit is RES1.
+        SPAN OFFSET(23) NUMBITS(1) [],
+
+        /// Exception Entry is Context Synchronizing.
+        ///
+        /// 0 The taking of an exception to EL1 is not a context synchronizing event.
+        /// 1 The taking of an exception to EL1 is a context synchronizing event.
+        ///
+        /// When ARMv8.5-CSEH is not implemented, this bit is RES1.
+        EIS OFFSET(22) NUMBITS(1) [],
+
+        /// Implicit Error Synchronization event enable.
+        ///
+        /// 0 Disabled.
+        /// 1 An implicit error synchronization event is added at each exception taken to EL1, each
+        ///   ERET from EL1, and each DCPS entry to EL1.
+        ///
+        /// When ARMv8.2-IESB is not implemented, this bit is RES0.
+        IESB OFFSET(21) NUMBITS(1) [],
+
+        /// Trap EL0 Access to the SCXTNUM_EL0 register, when EL0 is using AArch64.
+        ///
+        /// 0 EL0 access to SCXTNUM_EL0 is not disabled by this mechanism.
+        /// 1 EL0 access to SCXTNUM_EL0 is disabled, causing an exception to EL1.
+        ///
+        /// When ARMv8.0-CSV2 context number support is not implemented, this bit is RES1.
+        TSCXT OFFSET(20) NUMBITS(1) [],
+
+        /// Write permission implies XN (Execute-never). For the EL1&0 translation regime, this bit
+        /// can force all memory regions that are writable to be treated as XN.
+        ///
+        /// 0 This control has no effect on memory access permissions.
+        /// 1 Any region that is writable in the EL1&0 translation regime is forced to XN for
+        ///   accesses from software executing at EL1 or EL0.
+        WXN OFFSET(19) NUMBITS(1) [],
+
+        /// Traps EL0 execution of WFE instructions to EL1, from both Execution states:
+        ///
+        /// 0 Any attempt to execute a WFE instruction at EL0 is trapped to EL1, if the instruction
+        ///   would otherwise have caused the PE to enter a low-power state.
+        /// 1 This control does not cause any instructions to be trapped.
+        nTWE OFFSET(18) NUMBITS(1) [],
+
+        /// Traps EL0 execution of WFI instructions to EL1, from both Execution states:
+        ///
+        /// 0 Any attempt to execute a WFI instruction at EL0 is trapped to EL1, if the instruction
+        ///   would otherwise have caused the PE to enter a low-power state.
+        /// 1 This control does not cause any instructions to be trapped.
+        nTWI OFFSET(16) NUMBITS(1) [],
+
+        /// Traps EL0 accesses to the CTR_EL0 to EL1:
+        ///
+        /// 0 Accesses to the CTR_EL0 from EL0 using AArch64 are trapped.
+        /// 1 This control does not cause any instructions to be trapped.
+        UCT OFFSET(15) NUMBITS(1) [],
+
+        /// Traps EL0 execution of DC ZVA instructions to EL1:
+        ///
+        /// 0 Any attempt to execute a DC ZVA instruction at EL0 using AArch64 is trapped.
+        ///   Reading DCZID_EL0.DZP from EL0 returns 1, indicating that DC ZVA instructions are not
+        ///   supported.
+        /// 1 This control does not cause any instructions to be trapped.
+        DZE OFFSET(14) NUMBITS(1) [],
+
+        /// Controls enabling of pointer authentication (using the APDBKey_EL1 key) of data
+        /// addresses in the EL1&0 translation regime.
+        ///
+        /// 0 Pointer authentication (using the APDBKey_EL1 key) of data addresses is not enabled.
+        /// 1 Pointer authentication (using the APDBKey_EL1 key) of data addresses is enabled.
+        ///
+        /// When ARMv8.3-PAuth is not implemented, this bit is RES0.
+        EnDB OFFSET(13) NUMBITS(1) [],
+
         /// Instruction access Cacheability control, for accesses at EL0 and
         /// EL1:
         ///
@@ -42,6 +357,64 @@ register_bitfields! {u32,
             Cacheable = 1
         ],
 
+        /// Exception Exit is Context Synchronizing.
+        ///
+        /// 0 An exception return from EL1 is not a context synchronizing event.
+        /// 1 An exception return from EL1 is a context synchronizing event.
+        ///
+        /// When ARMv8.5-CSEH is not implemented, this bit is RES1.
+        EOS OFFSET(11) NUMBITS(1) [],
+
+        /// Enable EL0 access to the CFP RCTX, DVP RCTX and CPP RCTX instructions. When 0, they are
+        /// trapped to EL1.
+        ///
+        /// When ARMv8.0-PredInv is not implemented, this bit is RES0.
+        EnRCTX OFFSET(10) NUMBITS(1) [],
+
+        /// User Mask Access. Traps EL0 execution of MSR and MRS instructions that access the
+        /// PSTATE.{D, A, I, F} masks to EL1, when EL0 is using AArch64:
+        ///
+        /// 0 Any attempt at EL0 using AArch64 to execute an MRS, MSR(register), or MSR(immediate)
+        ///   instruction that accesses the DAIF is trapped.
+        /// 1 This control does not cause any instructions to be trapped.
+        UMA OFFSET(9) NUMBITS(1) [],
+
+        /// SETEND instruction disable. Disables SETEND instructions at EL0 using AArch32.
+        ///
+        /// When EL0 cannot use AArch32, this bit is RES1.
+        SED OFFSET(8) NUMBITS(1) [],
+
+        /// IT Disable. Disables some uses of IT instructions at EL0 using AArch32.
+        ///
+        /// When EL0 cannot use AArch32, this bit is RES1.
+        ITD OFFSET(7) NUMBITS(1) [],
+
+        /// Non-aligned access. This bit controls generation of Alignment faults at EL1 and EL0
+        /// under certain conditions:
+        ///
+        /// 0 Unaligned accesses by the specified instructions generate an Alignment fault.
+        /// 1 Unaligned accesses by the specified instructions do not generate an Alignment fault.
+        ///
+        /// The affected instructions are the Load-Acquire, Store-Release and atomic instructions
+        /// of ARMv8.4-LSE2. When ARMv8.4-LSE2 is not implemented, this bit is RES0.
+        nAA OFFSET(6) NUMBITS(1) [],
+
+        /// System instruction memory barrier enable, for AArch32 EL0. Enables the CP15DMB,
+        /// CP15DSB and CP15ISB barrier operations.
+        ///
+        /// When EL0 cannot use AArch32, this bit is RES0.
+        CP15BEN OFFSET(5) NUMBITS(1) [],
+
+        /// SP Alignment check enable for EL0. When set to 1, if a load or store instruction
+        /// executed at EL0 uses the SP as the base address and the SP is not aligned to a 16-byte
+        /// boundary, then a SP alignment fault exception is generated.
+        SA0 OFFSET(4) NUMBITS(1) [],
+
+        /// SP Alignment check enable. When set to 1, if a load or store instruction executed at EL1
+        /// uses the SP as the base address and the SP is not aligned to a 16-byte boundary, then a
+        /// SP alignment fault exception is generated.
+        SA OFFSET(3) NUMBITS(1) [],
+
         /// Cacheability control, for data accesses.
         ///
         /// 0 All data access to Normal memory from EL0 and EL1, and all Normal memory accesses to
@@ -64,6 +437,19 @@ register_bitfields! {u32,
             Cacheable = 1
         ],
 
+        /// Alignment check enable. This is the enable bit for Alignment fault checking at EL1 and
+        /// EL0.
+        ///
+        /// 0 Alignment fault checking disabled when executing at EL1 or EL0. Instructions that
+        ///   load or store one or more registers, other than load/store exclusive and
+        ///   load-acquire/store-release, do not check that the address being accessed is aligned
+        ///   to the size of the data element(s) being accessed.
+        /// 1 Alignment fault checking enabled when executing at EL1 or EL0. All instructions that
+        ///   load or store one or more registers have an alignment check that the address being
+        ///   accessed is aligned to the size of the data element(s) being accessed. If this check
+        ///   fails it causes an Alignment fault, which is taken as a Data Abort exception.
+        A OFFSET(1) NUMBITS(1) [],
+
         /// MMU enable for EL1 and EL0 stage 1 address translation. Possible values of this bit are:
         ///
         /// 0 EL1 and EL0 stage 1 address translation disabled.
@@ -79,9 +465,22 @@ register_bitfields! {u32,
 
 pub struct Reg;
 
-impl RegisterReadWrite<u32, SCTLR_EL1::Register> for Reg {
-    sys_coproc_read_raw!(u32, "SCTLR_EL1");
-    sys_coproc_write_raw!(u32, "SCTLR_EL1");
+impl RegisterReadWrite<u64, SCTLR_EL1::Register> for Reg {
+    sys_coproc_read_raw!(u64, "SCTLR_EL1");
+    sys_coproc_write_raw!(u64, "SCTLR_EL1");
+}
+
+impl ContextSync<u64, SCTLR_EL1::Register> for Reg {}
+
+impl ReservedBits<u64, SCTLR_EL1::Register> for Reg {
+    /// LSMAOE, nTLSMD, SPAN, EIS, TSCXT and EOS.
+    const RES1: u64 = 0x30D0_0800;
+
+    /// All of them are controlled by a feature.
+    const FIXED_RES1: u64 = 0;
+
+    /// Bits 59, 58, 34 and 17.
+    const RES0: u64 = 0x0C00_0004_0002_0000;
 }
 
 pub static SCTLR_EL1: Reg = Reg {};
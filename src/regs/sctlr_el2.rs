@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 //
-// Copyright (c) 2018-2019 by the author(s)
+// Copyright (c) 2018-2020 by the author(s)
 //
 // Author(s):
 //   - Andre Richter <andre.o.richter@gmail.com>
@@ -8,11 +8,115 @@
 //! System Control Register - EL2
 //!
 //! Provides top level control of the system, including its memory system, at EL2 and EL0.
+//!
+//! When `HCR_EL2.E2H` is set, the register has the layout of `SCTLR_EL1` and controls the EL2&0
+//! translation regime. Use `SCTLR_EL2_E2H` to access it in that configuration.
 
+use super::{ContextSync, ReservedBits, SCTLR_EL1};
 use register::{cpu::RegisterReadWrite, register_bitfields};
 
-register_bitfields! {u32,
+register_bitfields! {u64,
     pub SCTLR_EL2 [
+        /// SP Interrupt Mask enable. When SCTLR_EL2.NMI is 1, controls whether PSTATE.SP acts as an
+        /// interrupt mask, and controls the value of PSTATE.ALLINT on taking an exception to EL2.
+        ///
+        /// When ARMv8.8-NMI is not implemented, this bit is RES0.
+        SPINTMASK OFFSET(62) NUMBITS(1) [],
+
+        /// Non-maskable Interrupt enable.
+        ///
+        /// When ARMv8.8-NMI is not implemented, this bit is RES0.
+        NMI OFFSET(61) NUMBITS(1) [],
+
+        /// Enables the Transactional Memory Extension at EL2. When 0, TSTART at EL2 is UNDEFINED.
+        ///
+        /// When TME is not implemented, this bit is RES0.
+        TME OFFSET(53) NUMBITS(1) [],
+
+        /// Forces a trivial implementation of the Transactional Memory Extension at EL2, in which
+        /// every transaction fails.
+        ///
+        /// When TME is not implemented, this bit is RES0.
+        TMT OFFSET(51) NUMBITS(1) [],
+
+        /// Default PSTATE.SSBS value on Exception Entry to EL2.
+        ///
+        /// When ARMv8.5-SSBS is not implemented, this bit is RES0.
+        DSSBS OFFSET(44) NUMBITS(1) [],
+
+        /// Allocation Tag Access in EL2. When 0, access to Allocation Tags at EL2 is prevented.
+        ///
+        /// When ARMv8.5-MemTag is not implemented, this bit is RES0.
+        ATA OFFSET(43) NUMBITS(1) [],
+
+        /// Tag Check Fault in EL2. Controls the effect of Tag Check Faults due to loads and stores
+        /// in EL2.
+        ///
+        /// When ARMv8.5-MemTag is not implemented, this field is RES0.
+        TCF OFFSET(40) NUMBITS(2) [
+            None = 0b00,
+            Sync = 0b01,
+            Async = 0b10,
+            Asymmetric = 0b11
+        ],
+
+        /// Controls whether on exception entry into EL2, asynchronously reported Tag Check Faults
+        /// are synchronized into TFSR_EL2.
+        ///
+        /// When ARMv8.5-MemTag is not implemented, this bit is RES0.
+        ITFSB OFFSET(37) NUMBITS(1) [],
+
+        /// PAC Branch Type compatibility at EL2. When 1, PACIASP and PACIBSP are not compatible
+        /// with PSTATE.BTYPE == 0b11.
+        ///
+        /// When ARMv8.5-BTI is not implemented, this bit is RES0.
+        BT OFFSET(36) NUMBITS(1) [],
+
+        /// Controls enabling of pointer authentication (using the APIAKey_EL1 key) of instruction
+        /// addresses in the EL2 translation regime.
+        ///
+        /// When ARMv8.3-PAuth is not implemented, this bit is RES0.
+        EnIA OFFSET(31) NUMBITS(1) [],
+
+        /// Controls enabling of pointer authentication (using the APIBKey_EL1 key) of instruction
+        /// addresses in the EL2 translation regime.
+        ///
+        /// When ARMv8.3-PAuth is not implemented, this bit is RES0.
+        EnIB OFFSET(30) NUMBITS(1) [],
+
+        /// Controls enabling of pointer authentication (using the APDAKey_EL1 key) of data
+        /// addresses in the EL2 translation regime.
+        ///
+        /// When ARMv8.3-PAuth is not implemented, this bit is RES0.
+        EnDA OFFSET(27) NUMBITS(1) [],
+
+        /// Endianness of data accesses at EL2, and stage 1 translation table walks in the EL2
+        /// translation regime.
+        EE OFFSET(25) NUMBITS(1) [
+            LittleEndian = 0,
+            BigEndian = 1
+        ],
+
+        /// Exception Entry is Context Synchronizing.
+        ///
+        /// When ARMv8.5-CSEH is not implemented, this bit is RES1.
+        EIS OFFSET(22) NUMBITS(1) [],
+
+        /// Implicit Error Synchronization event enable.
+        ///
+        /// When ARMv8.2-IESB is not implemented, this bit is RES0.
+        IESB OFFSET(21) NUMBITS(1) [],
+
+        /// Write permission implies XN (Execute-never). For the EL2 translation regime, this bit
+        /// can force all memory regions that are writable to be treated as XN.
+        WXN OFFSET(19) NUMBITS(1) [],
+
+        /// Controls enabling of pointer authentication (using the APDBKey_EL1 key) of data
+        /// addresses in the EL2 translation regime.
+        ///
+        /// When ARMv8.3-PAuth is not implemented, this bit is RES0.
+        EnDB OFFSET(13) NUMBITS(1) [],
+
         /// Instruction access Cacheability control, for accesses at EL0 and
         /// EL2:
         ///
@@ -42,6 +146,22 @@ register_bitfields! {u32,
             Cacheable = 1
         ],
 
+        /// Exception Exit is Context Synchronizing.
+        ///
+        /// When ARMv8.5-CSEH is not implemented, this bit is RES1.
+        EOS OFFSET(11) NUMBITS(1) [],
+
+        /// Non-aligned access. Controls generation of Alignment faults at EL2 for the Load-Acquire,
+        /// Store-Release and atomic instructions of ARMv8.4-LSE2.
+        ///
+        /// When ARMv8.4-LSE2 is not implemented, this bit is RES0.
+        nAA OFFSET(6) NUMBITS(1) [],
+
+        /// SP Alignment check enable. When set to 1, if a load or store instruction executed at EL2
+        /// uses the SP as the base address and the SP is not aligned to a 16-byte boundary, then a
+        /// SP alignment fault exception is generated.
+        SA OFFSET(3) NUMBITS(1) [],
+
         /// Cacheability control, for data accesses.
         ///
         /// 0 All data access to Normal memory from EL0 and EL2, and all Normal memory accesses to
@@ -64,6 +184,9 @@ register_bitfields! {u32,
             Cacheable = 1
         ],
 
+        /// Alignment check enable. This is the enable bit for Alignment fault checking at EL2.
+        A OFFSET(1) NUMBITS(1) [],
+
         /// MMU enable for EL2 and EL0 stage 1 address translation. Possible values of this bit are:
         ///
         /// 0 EL2 and EL0 stage 1 address translation disabled.
@@ -79,9 +202,41 @@ register_bitfields! {u32,
 
 pub struct Reg;
 
-impl RegisterReadWrite<u32, SCTLR_EL2::Register> for Reg {
-    sys_coproc_read_raw!(u32, "SCTLR_EL2");
-    sys_coproc_write_raw!(u32, "SCTLR_EL2");
+impl RegisterReadWrite<u64, SCTLR_EL2::Register> for Reg {
+    sys_coproc_read_raw!(u64, "SCTLR_EL2");
+    sys_coproc_write_raw!(u64, "SCTLR_EL2");
+}
+
+impl ContextSync<u64, SCTLR_EL2::Register> for Reg {}
+
+impl ReservedBits<u64, SCTLR_EL2::Register> for Reg {
+    /// LSMAOE, nTLSMD, SPAN, EIS, nTWE, nTWI, EOS, CP15BEN and SA0 of the EL1 layout.
+    const RES1: u64 = 0x30C5_0830;
+
+    /// All but EIS and EOS, which are controlled by ARMv8.5-CSEH.
+    const FIXED_RES1: u64 = 0x3085_0030;
+
+    /// All bits that are neither named in `SCTLR_EL2` nor RES1.
+    const RES0: u64 = 0x9FD7_E4CF_0512_C780;
 }
 
 pub static SCTLR_EL2: Reg = Reg {};
+
+/// `SCTLR_EL2` with `HCR_EL2.E2H` set.
+pub struct RegE2H;
+
+impl RegisterReadWrite<u64, SCTLR_EL1::Register> for RegE2H {
+    sys_coproc_read_raw!(u64, "SCTLR_EL2");
+    sys_coproc_write_raw!(u64, "SCTLR_EL2");
+}
+
+impl ContextSync<u64, SCTLR_EL1::Register> for RegE2H {}
+
+impl ReservedBits<u64, SCTLR_EL1::Register> for RegE2H {
+    const RES1: u64 = <super::sctlr_el1::Reg as ReservedBits<u64, SCTLR_EL1::Register>>::RES1;
+    const FIXED_RES1: u64 =
+        <super::sctlr_el1::Reg as ReservedBits<u64, SCTLR_EL1::Register>>::FIXED_RES1;
+    const RES0: u64 = <super::sctlr_el1::Reg as ReservedBits<u64, SCTLR_EL1::Register>>::RES0;
+}
+
+pub static SCTLR_EL2_E2H: RegE2H = RegE2H {};
@@ -9,6 +9,7 @@
 //!
 //! Holds the saved process state when an exception is taken to EL2.
 
+use super::ReservedBits;
 use register::{cpu::RegisterReadWrite, register_bitfields};
 
 register_bitfields! {u32,
@@ -48,6 +49,27 @@ register_bitfields! {u32,
         /// example a signed overflow on an addition.
         V OFFSET(28) NUMBITS(1) [],
 
+        /// Tag Check Override. Set to 1 on exception entry to EL2 when ARMv8.5-MemTag is
+        /// implemented.
+        ///
+        /// When ARMv8.5-MemTag is not implemented, this bit is RES0.
+        TCO OFFSET(25) NUMBITS(1) [],
+
+        /// Data Independent Timing. Preserved on exception entry.
+        ///
+        /// When ARMv8.4-DIT is not implemented, this bit is RES0.
+        DIT OFFSET(24) NUMBITS(1) [],
+
+        /// User Access Override. Set to 0 on exception entry.
+        ///
+        /// When ARMv8.2-UAO is not implemented, this bit is RES0.
+        UAO OFFSET(23) NUMBITS(1) [],
+
+        /// Privileged Access Never. Set to 1 on exception entry to EL2 if SCTLR_EL2.SPAN is 0.
+        ///
+        /// When ARMv8.1-PAN is not implemented, this bit is RES0.
+        PAN OFFSET(22) NUMBITS(1) [],
+
         /// Software step. Shows the value of PSTATE.SS immediately before the exception was taken.
         SS OFFSET(21) NUMBITS(1) [],
 
@@ -55,6 +77,19 @@ register_bitfields! {u32,
         /// exception was taken.
         IL OFFSET(20) NUMBITS(1) [],
 
+        /// Speculative Store Bypass Safe. Set to SCTLR_EL2.DSSBS on exception entry to EL2.
+        ///
+        /// When ARMv8.5-SSBS is not implemented, this bit is RES0.
+        SSBS OFFSET(12) NUMBITS(1) [],
+
+        /// Branch Type. Shows the value of PSTATE.BTYPE immediately before the exception was taken.
+        ///
+        /// Software that changes ELR_EL2 to skip or emulate an instruction must clear this field,
+        /// so that the returned to instruction is not checked as a branch target.
+        ///
+        /// When ARMv8.5-BTI is not implemented, this field is RES0.
+        BTYPE OFFSET(10) NUMBITS(2) [],
+
         /// Process state D mask. The possible values of this bit are:
         ///
         /// 0 Watchpoint, Breakpoint, and Software Step exceptions targeted at the current Exception
@@ -168,4 +203,13 @@ impl RegisterReadWrite<u32, SPSR_EL2::Register> for Reg {
     sys_coproc_write_raw!(u32, "SPSR_EL2");
 }
 
+impl ReservedBits<u32, SPSR_EL2::Register> for Reg {
+    const RES1: u32 = 0;
+    const FIXED_RES1: u32 = 0;
+
+    /// Bits 27:26, 19:14 and 5, for exception returns to AArch64. States of AArch32 callers
+    /// assign these bits and must be written with `set()`.
+    const RES0: u32 = 0x0C0F_C020;
+}
+
 pub static SPSR_EL2: Reg = Reg {};
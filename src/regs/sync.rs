@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Context synchronization
+//!
+//! A direct write to a System register that controls the execution context, e.g. `SCTLR_EL1`,
+//! `TCR_EL1` or `VBAR_EL1`, is only guaranteed to affect instructions that follow a Context
+//! synchronization event. Until then, the PE may keep using the old value, which e.g. makes the
+//! instructions after enabling the MMU run with translation in an UNKNOWN state.
+//!
+//! Registers with this requirement implement [`ContextSync`], whose writes are followed by an
+//! `ISB`:
+//!
+//! ```ignore
+//! use cortex_a::regs::*;
+//!
+//! TTBR0_EL1.set_sync(table_addr);
+//! SCTLR_EL1.modify_sync(SCTLR_EL1::M::Enable + SCTLR_EL1::C::Cacheable);
+//! ```
+//!
+//! Registers that also have reserved bits provide the writes of [`ReservedBits`] followed by an
+//! `ISB`, e.g. `SCTLR_EL2.write_with_reserved_sync()`.
+//!
+//! To update several registers with a single `ISB`, use the plain accessors for all but the last
+//! write.
+
+use super::ReservedBits;
+use crate::barrier;
+use register::{cpu::RegisterReadWrite, FieldValue, IntLike, RegisterLongName};
+
+/// Writes followed by a Context synchronization event.
+pub trait ContextSync<T: IntLike, R: RegisterLongName>: RegisterReadWrite<T, R> {
+    /// Sets the raw value and synchronizes the context.
+    #[inline(always)]
+    fn set_sync(&self, value: T) {
+        self.set(value);
+        unsafe { barrier::isb(barrier::SY) };
+    }
+
+    /// Writes `field` and synchronizes the context.
+    #[inline(always)]
+    fn write_sync(&self, field: FieldValue<T, R>) {
+        self.write(field);
+        unsafe { barrier::isb(barrier::SY) };
+    }
+
+    /// Modifies the bits covered by `field` and synchronizes the context.
+    #[inline(always)]
+    fn modify_sync(&self, field: FieldValue<T, R>) {
+        self.modify(field);
+        unsafe { barrier::isb(barrier::SY) };
+    }
+
+    /// Writes `field` with the reserved bits set as in [`ReservedBits::write_with_reserved`] and
+    /// synchronizes the context.
+    #[inline(always)]
+    fn write_with_reserved_sync(&self, field: FieldValue<T, R>)
+    where
+        Self: ReservedBits<T, R>,
+    {
+        self.write_with_reserved(field);
+        unsafe { barrier::isb(barrier::SY) };
+    }
+
+    /// Modifies the bits covered by `field` as in [`ReservedBits::modify_with_reserved`] and
+    /// synchronizes the context.
+    #[inline(always)]
+    fn modify_with_reserved_sync(&self, field: FieldValue<T, R>)
+    where
+        Self: ReservedBits<T, R>,
+    {
+        self.modify_with_reserved(field);
+        unsafe { barrier::isb(barrier::SY) };
+    }
+}
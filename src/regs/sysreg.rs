@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Generic System register
+//!
+//! Accesses a System register by its architectural encoding `S<op0>_<op1>_C<n>_C<m>_<op2>`, for
+//! registers that have no named wrapper in this crate, e.g. IMPLEMENTATION DEFINED registers or
+//! registers from newer versions of the architecture.
+//!
+//! The MRS/MSR instruction is emitted directly from the encoding, so no assembler support for the
+//! register name is needed. Registers in this crate that were introduced after ARMv8.0 use
+//! `SysReg` for this reason.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use cortex_a::regs::*;
+//! use register::register_bitfields;
+//!
+//! register_bitfields! {u64,
+//!     pub CPUECTLR_EL1 [
+//!         SMPEN OFFSET(6) NUMBITS(1) []
+//!     ]
+//! }
+//!
+//! pub static CPUECTLR_EL1: SysReg<CPUECTLR_EL1::Register, 3, 1, 15, 2, 1> = SysReg::new();
+//!
+//! CPUECTLR_EL1.modify(CPUECTLR_EL1::SMPEN::SET);
+//! ```
+//!
+//! 32-bit registers take `u32` as the last parameter, e.g. `SysReg<(), 3, 5, 14, 2, 0, u32>` for
+//! `CNTP_TVAL_EL02`.
+
+use core::marker::PhantomData;
+use register::{cpu::RegisterReadWrite, RegisterLongName};
+
+/// A System register identified by its encoding.
+///
+/// `R` is the register type generated by `register_bitfields!`, or `()` if the register has no
+/// bitfields. `T` is the width of the register, `u64` or `u32`.
+pub struct SysReg<
+    R,
+    const OP0: u8,
+    const OP1: u8,
+    const CRN: u8,
+    const CRM: u8,
+    const OP2: u8,
+    T = u64,
+> {
+    associated_register: PhantomData<(R, T)>,
+}
+
+impl<R, const OP0: u8, const OP1: u8, const CRN: u8, const CRM: u8, const OP2: u8, T>
+    SysReg<R, OP0, OP1, CRN, CRM, OP2, T>
+{
+    /// Rejects encodings whose fields do not fit the MRS/MSR instruction at compile time, instead
+    /// of silently accessing a different register.
+    const VALID: () = assert!(
+        (OP0 == 2 || OP0 == 3) && OP1 <= 0b111 && CRN <= 0b1111 && CRM <= 0b1111 && OP2 <= 0b111,
+        "System register encoding out of range"
+    );
+
+    /// The `op0:op1:CRn:CRm:op2` fields as they are placed in an MRS/MSR instruction.
+    const ENCODING: u32 = ((OP0 as u32 & 0b1) << 19)
+        | ((OP1 as u32 & 0b111) << 16)
+        | ((CRN as u32 & 0b1111) << 12)
+        | ((CRM as u32 & 0b1111) << 8)
+        | ((OP2 as u32 & 0b111) << 5);
+
+    /// `MRS X0, <reg>`
+    #[allow(dead_code)]
+    const MRS_X0: u32 = 0xD530_0000 | Self::ENCODING;
+
+    /// `MSR <reg>, X0`
+    #[allow(dead_code)]
+    const MSR_X0: u32 = 0xD510_0000 | Self::ENCODING;
+
+    pub const fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::VALID;
+
+        SysReg {
+            associated_register: PhantomData,
+        }
+    }
+
+    /// Reads the register into a 64-bit general-purpose register.
+    #[inline(always)]
+    fn mrs(&self) -> u64 {
+        match () {
+            #[cfg(target_arch = "aarch64")]
+            () => {
+                let reg;
+                unsafe {
+                    llvm_asm!(".inst $1" : "={x0}"(reg) : "i"(Self::MRS_X0) :: "volatile");
+                }
+                reg
+            }
+
+            #[cfg(not(target_arch = "aarch64"))]
+            () => unimplemented!(),
+        }
+    }
+
+    /// Writes the register from a 64-bit general-purpose register.
+    #[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+    #[inline(always)]
+    fn msr(&self, value: u64) {
+        match () {
+            #[cfg(target_arch = "aarch64")]
+            () => unsafe {
+                llvm_asm!(".inst $1" :: "{x0}"(value), "i"(Self::MSR_X0) :: "volatile")
+            },
+
+            #[cfg(not(target_arch = "aarch64"))]
+            () => unimplemented!(),
+        }
+    }
+}
+
+impl<R, const OP0: u8, const OP1: u8, const CRN: u8, const CRM: u8, const OP2: u8, T> Default
+    for SysReg<R, OP0, OP1, CRN, CRM, OP2, T>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<
+        R: RegisterLongName,
+        const OP0: u8,
+        const OP1: u8,
+        const CRN: u8,
+        const CRM: u8,
+        const OP2: u8,
+    > RegisterReadWrite<u64, R> for SysReg<R, OP0, OP1, CRN, CRM, OP2, u64>
+{
+    /// Reads the raw bits of the CPU register.
+    #[inline]
+    fn get(&self) -> u64 {
+        self.mrs()
+    }
+
+    /// Writes raw bits to the CPU register.
+    #[inline]
+    fn set(&self, value: u64) {
+        self.msr(value)
+    }
+}
+
+impl<
+        R: RegisterLongName,
+        const OP0: u8,
+        const OP1: u8,
+        const CRN: u8,
+        const CRM: u8,
+        const OP2: u8,
+    > RegisterReadWrite<u32, R> for SysReg<R, OP0, OP1, CRN, CRM, OP2, u32>
+{
+    /// Reads the raw bits of the CPU register.
+    #[inline]
+    fn get(&self) -> u32 {
+        self.mrs() as u32
+    }
+
+    /// Writes raw bits to the CPU register.
+    #[inline]
+    fn set(&self, value: u32) {
+        self.msr(value as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mrs_encoding() {
+        assert_eq!(SysReg::<(), 3, 1, 15, 2, 0>::MRS_X0, 0xD539_F200);
+        assert_eq!(SysReg::<(), 3, 0, 0, 0, 0>::MRS_X0, 0xD538_0000); // MIDR_EL1
+        assert_eq!(SysReg::<(), 2, 0, 0, 2, 2>::MRS_X0, 0xD530_0240); // MDSCR_EL1
+        assert_eq!(SysReg::<(), 3, 5, 14, 2, 0, u32>::MRS_X0, 0xD53D_E200); // CNTP_TVAL_EL02
+    }
+
+    #[test]
+    fn msr_encoding() {
+        assert_eq!(SysReg::<(), 3, 0, 2, 0, 0>::MSR_X0, 0xD518_2000); // TTBR0_EL1
+        assert_eq!(SysReg::<(), 3, 4, 1, 1, 0>::MSR_X0, 0xD51C_1100); // HCR_EL2
+    }
+}
@@ -9,10 +9,28 @@
 //!
 //! The control register for stage 1 of the EL1&0 translation regime.
 
+use super::ContextSync;
 use register::{cpu::RegisterReadWrite, register_bitfields};
 
 register_bitfields! {u64,
     pub TCR_EL1 [
+        /// Controls the generation of Unchecked accesses at EL1, and at EL0 if HCR_EL2.{E2H,TGE}
+        /// is not {1,1}, when address[59:55] = 0b11111.
+        ///
+        /// 0 This control has no effect on the PE.
+        /// 1 All accesses are Unchecked.
+        ///
+        /// When ARMv8.5-MemTag is not implemented, this bit is RES0.
+        TCMA1 OFFSET(58) NUMBITS(1) [],
+
+        /// Controls the generation of Unchecked accesses at EL1, and at EL0 if HCR_EL2.{E2H,TGE}
+        /// is not {1,1}, when address[59:55] = 0b00000.
+        ///
+        /// 0 This control has no effect on the PE.
+        /// 1 All accesses are Unchecked.
+        ///
+        /// When ARMv8.5-MemTag is not implemented, this bit is RES0.
+        TCMA0 OFFSET(57) NUMBITS(1) [],
 
         /// Top Byte ignored - indicates whether the top byte of an address is used for address
         /// match for the TTBR1_EL1 region, or ignored and used for tagged addresses. Defined values
@@ -36,6 +54,20 @@ register_bitfields! {u64,
             Ignored = 1
         ],
 
+        /// ASID Size.
+        ///
+        /// 0 8 bit - the upper 8 bits of TTBR0_EL1 and TTBR1_EL1 are ignored by hardware for every
+        ///   purpose except reading back the register, and are treated as if they are all zeros
+        ///   when used for allocation and matching entries in the TLB.
+        /// 1 16 bit - the upper 8 bits of TTBR0_EL1 and TTBR1_EL1 are used for allocation and
+        ///   matching in the TLB.
+        ///
+        /// If the implementation has only 8 bits of ASID, this field is RES0.
+        AS    OFFSET(36) NUMBITS(1) [
+            ASID8Bits = 0,
+            ASID16Bits = 1
+        ],
+
         /// Intermediate Physical Address Size.
         ///
         /// 000 32 bits, 4GiB.
@@ -71,9 +103,9 @@ register_bitfields! {u64,
 
         /// Granule size for the TTBR1_EL1.
         ///
-        /// 00 4KiB
-        /// 01 64KiB
-        /// 10 16KiB
+        /// 01 16KiB
+        /// 10 4KiB
+        /// 11 64KiB
         ///
         /// Other values are reserved.
         ///
@@ -85,9 +117,9 @@ register_bitfields! {u64,
         /// It is IMPLEMENTATION DEFINED whether the value read back is the value programmed or the
         /// value that corresponds to the size chosen.
         TG1   OFFSET(30) NUMBITS(2) [
-            KiB_4 = 0b00,
-            KiB_16 = 0b10,
-            KiB_64 = 0b01
+            KiB_4 = 0b10,
+            KiB_16 = 0b01,
+            KiB_64 = 0b11
         ],
 
         /// Shareability attribute for memory associated with translation table walks using
@@ -151,6 +183,15 @@ register_bitfields! {u64,
             DisableTTBR1Walks = 1
         ],
 
+        /// Selects whether TTBR0_EL1 or TTBR1_EL1 defines the ASID.
+        ///
+        /// 0 TTBR0_EL1.ASID defines the ASID.
+        /// 1 TTBR1_EL1.ASID defines the ASID.
+        A1    OFFSET(22) NUMBITS(1) [
+            TTBR0 = 0,
+            TTBR1 = 1
+        ],
+
         /// The size offset of the memory region addressed by TTBR0_EL1. The region size is
         /// 2^(64-T0SZ) bytes.
         ///
@@ -258,4 +299,6 @@ impl RegisterReadWrite<u64, TCR_EL1::Register> for Reg {
     sys_coproc_write_raw!(u64, "TCR_EL1");
 }
 
+impl ContextSync<u64, TCR_EL1::Register> for Reg {}
+
 pub static TCR_EL1: Reg = Reg {};
@@ -1,29 +1,60 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 //
-// Copyright (c) 2018-2019 by the author(s)
+// Copyright (c) 2018-2020 by the author(s)
 //
 // Author(s):
 //   - Andre Richter <andre.o.richter@gmail.com>
 
 //! Translation Control Register - EL2
 //!
-//! The control register for stage 1 of the EL2&0 translation regime.
+//! The control register for stage 1 of the EL2 translation regime.
+//!
+//! When `HCR_EL2.E2H` is set, the register controls the EL2&0 translation regime, which has two
+//! VA ranges like the EL1&0 regime, and has the layout of `TCR_EL1`. Use `TCR_EL2_E2H` to access it
+//! in that configuration.
+//!
+//! The fields of the E2H layout, `TBI0`, `TBI1`, `IPS`, `TG1`, `SH1`, `ORGN1`, `IRGN1`, `EPD1`,
+//! `T1SZ` and `EPD0`, are not part of `TCR_EL2`. Use the `TCR_EL1` fields with `TCR_EL2_E2H`, or
+//! `TBI` and `PS` with `HCR_EL2.E2H` clear.
 
+use super::{ContextSync, ReservedBits, TCR_EL1};
 use register::{cpu::RegisterReadWrite, register_bitfields};
 
 register_bitfields! {u64,
     pub TCR_EL2 [
+        /// Accesses with the Allocation Tag 0b1111 are not Tag checked, i.e. behave as if they were
+        /// Unchecked, when 1.
+        ///
+        /// When ARMv8.5-MemTag is not implemented, this bit is RES0.
+        TCMA  OFFSET(30) NUMBITS(1) [],
 
-        /// Top Byte ignored - indicates whether the top byte of an address is used for address
-        /// match for the TTBR1_EL2 region, or ignored and used for tagged addresses. Defined values
-        /// are:
+        /// Controls the use of the top byte of instruction addresses for address matching. When 1,
+        /// TCR_EL2.TBI applies only to data accesses.
         ///
-        /// 0 Top Byte used in the address calculation.
-        /// 1 Top Byte ignored in the address calculation.
-        TBI1  OFFSET(38) NUMBITS(1) [
-            Used = 0,
-            Ignored = 1
-        ],
+        /// When ARMv8.3-PAuth is not implemented, this bit is RES0.
+        TBID  OFFSET(29) NUMBITS(1) [],
+
+        /// Hardware Use. Indicates IMPLEMENTATION DEFINED hardware use of bits[62:59] of the stage
+        /// 1 translation table Block or Page entry, when TCR_EL2.HPD is 1.
+        ///
+        /// When ARMv8.2-TTPBHA is not implemented, this field is RES0.
+        HWU   OFFSET(25) NUMBITS(4) [],
+
+        /// Hierarchical Permission Disables. When 1, the APTable and PXNTable fields of the stage 1
+        /// translation table descriptors are ignored.
+        ///
+        /// When ARMv8.1-HPD is not implemented, this bit is RES0.
+        HPD   OFFSET(24) NUMBITS(1) [],
+
+        /// Hardware management of dirty state in stage 1 translations from EL2.
+        ///
+        /// When ARMv8.1-TTHM is not implemented, this bit is RES0.
+        HD    OFFSET(22) NUMBITS(1) [],
+
+        /// Hardware Access flag update in stage 1 translations from EL2.
+        ///
+        /// When ARMv8.1-TTHM is not implemented, this bit is RES0.
+        HA    OFFSET(21) NUMBITS(1) [],
 
         /// Top Byte ignored - indicates whether the top byte of an address is used for address
         /// match for the TTBR0_EL2 region, or ignored and used for tagged addresses. Defined values
@@ -31,12 +62,12 @@ register_bitfields! {u64,
         ///
         /// 0 Top Byte used in the address calculation.
         /// 1 Top Byte ignored in the address calculation.
-        TBI0  OFFSET(37) NUMBITS(1) [
+        TBI   OFFSET(20) NUMBITS(1) [
             Used = 0,
             Ignored = 1
         ],
 
-        /// Intermediate Physical Address Size.
+        /// Physical Address Size.
         ///
         /// 000 32 bits, 4GiB.
         /// 001 36 bits, 64GiB.
@@ -59,7 +90,7 @@ register_bitfields! {u64,
         /// then bits[51:48] of every translation table base address for the stage of translation
         /// controlled by TCR_EL2 are 0000
         /// .
-        IPS   OFFSET(32) NUMBITS(3) [
+        PS    OFFSET(16) NUMBITS(3) [
             Bits_32 = 0b000,
             Bits_36 = 0b001,
             Bits_40 = 0b010,
@@ -69,96 +100,6 @@ register_bitfields! {u64,
             Bits_52 = 0b110
         ],
 
-        /// Granule size for the TTBR1_EL2.
-        ///
-        /// 00 4KiB
-        /// 01 64KiB
-        /// 10 16KiB
-        ///
-        /// Other values are reserved.
-        ///
-        /// If the value is programmed to either a reserved value, or a size that has not been
-        /// implemented, then the hardware will treat the field as if it has been programmed to an
-        /// IMPLEMENTATION DEFINED choice of the sizes that has been implemented for all purposes
-        /// other than the value read back from this register.
-        ///
-        /// It is IMPLEMENTATION DEFINED whether the value read back is the value programmed or the
-        /// value that corresponds to the size chosen.
-        TG1   OFFSET(30) NUMBITS(2) [
-            KiB_4 = 0b00,
-            KiB_16 = 0b10,
-            KiB_64 = 0b01
-        ],
-
-        /// Shareability attribute for memory associated with translation table walks using
-        /// TTBR1_EL2.
-        ///
-        /// 00 Non-shareable
-        /// 10 Outer Shareable
-        /// 11 Inner Shareable
-        ///
-        /// Other values are reserved.
-        SH1   OFFSET(28) NUMBITS(2) [
-            None = 0b00,
-            Outer = 0b10,
-            Inner = 0b11
-        ],
-
-        /// Outer cacheability attribute for memory associated with translation table walks using
-        /// TTBR1_EL2.
-        ///
-        /// 00 Normal memory, Outer Non-cacheable
-        ///
-        /// 01 Normal memory, Outer Write-Back Read-Allocate Write-Allocate Cacheable
-        ///
-        /// 10 Normal memory, Outer Write-Through Read-Allocate No Write-Allocate Cacheable
-        ///
-        /// 11 Normal memory, Outer Write-Back Read-Allocate No Write-Allocate Cacheable
-        ORGN1 OFFSET(26) NUMBITS(2) [
-            NonCacheable = 0b00,
-            WriteBack_ReadAlloc_WriteAlloc_Cacheable = 0b01,
-            WriteThrough_ReadAlloc_NoWriteAlloc_Cacheable = 0b10,
-            WriteBack_ReadAlloc_NoWriteAlloc_Cacheable = 0b11
-        ],
-
-        /// Inner cacheability attribute for memory associated with translation
-        /// table walks using TTBR1_EL2.
-        ///
-        /// 00 Normal memory, Inner Non-cacheable
-        ///
-        /// 01 Normal memory, Inner Write-Back Read-Allocate Write-Allocate Cacheable
-        ///
-        /// 10 Normal memory, Inner Write-Through Read-Allocate No Write-Allocate Cacheable
-        ///
-        /// 11 Normal memory, Inner Write-Back Read-Allocate No Write-Allocate Cacheable
-        IRGN1 OFFSET(24) NUMBITS(2) [
-            NonCacheable = 0b00,
-            WriteBack_ReadAlloc_WriteAlloc_Cacheable = 0b01,
-            WriteThrough_ReadAlloc_NoWriteAlloc_Cacheable = 0b10,
-            WriteBack_ReadAlloc_NoWriteAlloc_Cacheable = 0b11
-        ],
-
-        /// Translation table walk disable for translations using TTBR1_EL2. This bit controls
-        /// whether a translation table walk is performed on a TLB miss, for an address that is
-        /// translated using TTBR1_EL2. The encoding of this bit is:
-        ///
-        /// 0 Perform translation table walks using TTBR1_EL2.
-        ///
-        /// 1 A TLB miss on an address that is translated using TTBR1_EL2 generates a Translation
-        ///   fault. No translation table walk is performed.
-        EPD1  OFFSET(23) NUMBITS(1) [
-            EnableTTBR1Walks = 0,
-            DisableTTBR1Walks = 1
-        ],
-
-        /// The size offset of the memory region addressed by TTBR0_EL2. The region size is
-        /// 2^(64-T0SZ) bytes.
-        ///
-        /// The maximum and minimum possible values for T0SZ depend on the level of translation
-        /// table and the memory translation granule size, as described in the AArch64 Virtual
-        /// Memory System Architecture chapter.
-        T1SZ  OFFSET(16) NUMBITS(6) [],
-
         /// Granule size for the TTBR0_EL2.
         ///
         /// 00 4KiB
@@ -228,19 +169,6 @@ register_bitfields! {u64,
             WriteBack_ReadAlloc_NoWriteAlloc_Cacheable = 0b11
         ],
 
-        /// Translation table walk disable for translations using TTBR0_EL2. This bit controls
-        /// whether a translation table walk is performed on a TLB miss, for an address that is
-        /// translated using TTBR0_EL2. The encoding of this bit is:
-        ///
-        /// 0 Perform translation table walks using TTBR0_EL2.
-        ///
-        /// 1 A TLB miss on an address that is translated using TTBR0_EL2 generates a Translation
-        ///   fault. No translation table walk is performed.
-        EPD0  OFFSET(7) NUMBITS(1) [
-            EnableTTBR0Walks = 0,
-            DisableTTBR0Walks = 1
-        ],
-
         /// The size offset of the memory region addressed by TTBR0_EL2. The region size is
         /// 2^(64-T0SZ) bytes.
         ///
@@ -258,4 +186,27 @@ impl RegisterReadWrite<u64, TCR_EL2::Register> for Reg {
     sys_coproc_write_raw!(u64, "TCR_EL2");
 }
 
+impl ContextSync<u64, TCR_EL2::Register> for Reg {}
+
+impl ReservedBits<u64, TCR_EL2::Register> for Reg {
+    /// Bits 31 and 23.
+    const RES1: u64 = 0x8080_0000;
+    const FIXED_RES1: u64 = Self::RES1;
+
+    /// Bits 19, 7 and 6.
+    const RES0: u64 = 0x0008_00C0;
+}
+
 pub static TCR_EL2: Reg = Reg {};
+
+/// `TCR_EL2` with `HCR_EL2.E2H` set.
+pub struct RegE2H;
+
+impl RegisterReadWrite<u64, TCR_EL1::Register> for RegE2H {
+    sys_coproc_read_raw!(u64, "TCR_EL2");
+    sys_coproc_write_raw!(u64, "TCR_EL2");
+}
+
+impl ContextSync<u64, TCR_EL1::Register> for RegE2H {}
+
+pub static TCR_EL2_E2H: RegE2H = RegE2H {};
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Tag Fault Status Register (EL0) - EL1
+//!
+//! Holds accumulated Tag Check Faults occurring in EL0 when SCTLR_EL1.TCF0 is Async.
+
+use crate::regs::SysReg;
+use register::register_bitfields;
+
+register_bitfields! {u64,
+    pub TFSRE0_EL1 [
+        /// Tag Check Fault in the TTBR1_EL1 region, i.e. for virtual addresses with bit 55 set.
+        /// Set by hardware, cleared by software.
+        TF1 OFFSET(1) NUMBITS(1) [],
+
+        /// Tag Check Fault in the TTBR0_EL1 region, i.e. for virtual addresses with bit 55 clear.
+        /// Set by hardware, cleared by software.
+        TF0 OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+pub static TFSRE0_EL1: SysReg<TFSRE0_EL1::Register, 3, 0, 5, 6, 1> = SysReg::new();
@@ -11,6 +11,7 @@
 //! translation of an address from the higher VA range in the EL1&0 translation regime, and other
 //! information for this translation regime.
 
+use super::ContextSync;
 use register::{cpu::RegisterReadWrite, register_bitfields};
 
 register_bitfields! {u64,
@@ -37,6 +38,8 @@ impl RegisterReadWrite<u64, TTBR1_EL1::Register> for Reg {
     sys_coproc_write_raw!(u64, "TTBR1_EL1");
 }
 
+impl ContextSync<u64, TTBR1_EL1::Register> for Reg {}
+
 impl Reg {
     #[inline]
     pub fn get_baddr(&self) -> u64 {
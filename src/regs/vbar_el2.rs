@@ -9,6 +9,7 @@
 //!
 //! Holds the vector base address for any exception that is taken to EL2.
 
+use super::ContextSync;
 use register::cpu::RegisterReadWrite;
 
 pub struct Reg;
@@ -18,4 +19,6 @@ impl RegisterReadWrite<u64, ()> for Reg {
     sys_coproc_write_raw!(u64, "VBAR_EL2");
 }
 
+impl ContextSync<u64, ()> for Reg {}
+
 pub static VBAR_EL2: Reg = Reg {};
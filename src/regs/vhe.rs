@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! EL12 and EL02 Register Aliases
+//!
+//! When `HCR_EL2.E2H` is set, accesses from EL2 to the EL1 and EL0 register names are redirected
+//! to the corresponding EL2 registers. The EL1 and EL0 registers of the guest remain accessible
+//! through the `_EL12` and `_EL02` aliases, which have the layout of the aliased register:
+//!
+//! ```ignore
+//! use cortex_a::regs::*;
+//!
+//! let sctlr = SCTLR_EL12.get();
+//! SPSR_EL12.write(SPSR_EL1::M::EL1h + SPSR_EL1::D::Masked);
+//! ```
+//!
+//! The aliases are UNDEFINED at EL1, and at EL2 with `HCR_EL2.E2H` clear.
+
+use super::{
+    SysReg, CNTKCTL_EL1, CNTP_CTL_EL0, CNTV_CTL_EL0, CONTEXTIDR_EL1, CPACR_EL1, ESR_EL1, MAIR_EL1,
+    SCTLR_EL1, SPSR_EL1, TCR_EL1, TFSR_EL1, TTBR0_EL1, TTBR1_EL1,
+};
+
+pub static SCTLR_EL12: SysReg<SCTLR_EL1::Register, 3, 5, 1, 0, 0> = SysReg::new();
+pub static CPACR_EL12: SysReg<CPACR_EL1::Register, 3, 5, 1, 0, 2> = SysReg::new();
+pub static TTBR0_EL12: SysReg<TTBR0_EL1::Register, 3, 5, 2, 0, 0> = SysReg::new();
+pub static TTBR1_EL12: SysReg<TTBR1_EL1::Register, 3, 5, 2, 0, 1> = SysReg::new();
+pub static TCR_EL12: SysReg<TCR_EL1::Register, 3, 5, 2, 0, 2> = SysReg::new();
+pub static SPSR_EL12: SysReg<SPSR_EL1::Register, 3, 5, 4, 0, 0, u32> = SysReg::new();
+pub static ELR_EL12: SysReg<(), 3, 5, 4, 0, 1> = SysReg::new();
+pub static AFSR0_EL12: SysReg<(), 3, 5, 5, 1, 0> = SysReg::new();
+pub static AFSR1_EL12: SysReg<(), 3, 5, 5, 1, 1> = SysReg::new();
+pub static ESR_EL12: SysReg<ESR_EL1::Register, 3, 5, 5, 2, 0, u32> = SysReg::new();
+pub static TFSR_EL12: SysReg<TFSR_EL1::Register, 3, 5, 5, 6, 0> = SysReg::new();
+pub static FAR_EL12: SysReg<(), 3, 5, 6, 0, 0> = SysReg::new();
+pub static MAIR_EL12: SysReg<MAIR_EL1::Register, 3, 5, 10, 2, 0> = SysReg::new();
+pub static AMAIR_EL12: SysReg<(), 3, 5, 10, 3, 0> = SysReg::new();
+pub static VBAR_EL12: SysReg<(), 3, 5, 12, 0, 0> = SysReg::new();
+pub static CONTEXTIDR_EL12: SysReg<CONTEXTIDR_EL1::Register, 3, 5, 13, 0, 1> = SysReg::new();
+pub static CNTKCTL_EL12: SysReg<CNTKCTL_EL1::Register, 3, 5, 14, 1, 0, u32> = SysReg::new();
+pub static CNTP_TVAL_EL02: SysReg<(), 3, 5, 14, 2, 0, u32> = SysReg::new();
+pub static CNTP_CTL_EL02: SysReg<CNTP_CTL_EL0::Register, 3, 5, 14, 2, 1, u32> = SysReg::new();
+pub static CNTP_CVAL_EL02: SysReg<(), 3, 5, 14, 2, 2> = SysReg::new();
+pub static CNTV_TVAL_EL02: SysReg<(), 3, 5, 14, 3, 0, u32> = SysReg::new();
+pub static CNTV_CTL_EL02: SysReg<CNTV_CTL_EL0::Register, 3, 5, 14, 3, 1, u32> = SysReg::new();
+pub static CNTV_CVAL_EL02: SysReg<(), 3, 5, 14, 3, 2> = SysReg::new();
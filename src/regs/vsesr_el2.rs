@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Virtual SError Exception Syndrome Register - EL2
+//!
+//! Provides the syndrome value reported to software on taking a virtual SError interrupt exception
+//! to EL1, when the virtual SError is pended through HCR_EL2.VSE.
+
+use super::SysReg;
+use register::register_bitfields;
+
+register_bitfields! {u64,
+    pub VSESR_EL2 [
+        /// Indicates whether the deferred SError interrupt was of an IMPLEMENTATION DEFINED type.
+        /// Reported in ESR_EL1.IDS on taking the virtual SError interrupt to EL1 using AArch64.
+        IDS OFFSET(24) NUMBITS(1) [],
+
+        /// Syndrome information. Reported in ESR_EL1.ISS[23:0] on taking the virtual SError
+        /// interrupt to EL1 using AArch64.
+        ISS OFFSET(0) NUMBITS(24) []
+    ]
+}
+
+pub static VSESR_EL2: SysReg<VSESR_EL2::Register, 3, 4, 5, 2, 3> = SysReg::new();
@@ -9,6 +9,7 @@
 //!
 //! The control register for stage 2 of the EL1&0 translation regime.
 
+use super::ContextSync;
 use register::{cpu::RegisterReadWrite, register_bitfields};
 
 register_bitfields! {u32,
@@ -159,4 +160,6 @@ impl RegisterReadWrite<u32, VTCR_EL2::Register> for Reg {
     sys_coproc_write_raw!(u32, "VTCR_EL2");
 }
 
+impl ContextSync<u32, VTCR_EL2::Register> for Reg {}
+
 pub static VTCR_EL2: Reg = Reg {};
@@ -11,6 +11,7 @@
 //! address translation in the Non-secure EL1&0 translation regime, and other information
 //! for this translation regime.
 
+use super::ContextSync;
 use register::{cpu::RegisterReadWrite, register_bitfields};
 
 register_bitfields! {u64,
@@ -47,6 +48,8 @@ impl RegisterReadWrite<u64, VTTBR_EL2::Register> for Reg {
     sys_coproc_write_raw!(u64, "VTTBR_EL2");
 }
 
+impl ContextSync<u64, VTTBR_EL2::Register> for Reg {}
+
 impl Reg {
     #[inline]
     pub fn get_baddr(&self) -> u64 {
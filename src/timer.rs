@@ -0,0 +1,512 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Generic Timer
+//!
+//! Monotonic time and one-shot deadlines on top of the architected timers:
+//!
+//! ```ignore
+//! use core::time::Duration;
+//! use cortex_a::timer::{self, Instant, Timer, Virtual};
+//!
+//! let start = Instant::<Virtual>::now();
+//! timer::delay(Duration::from_micros(10));
+//!
+//! Virtual::arm_at(start + Duration::from_millis(10));
+//! // ... wait for the interrupt, then
+//! Virtual::disable();
+//! ```
+//!
+//! `CNTFRQ_EL0` must have been programmed by firmware.
+
+use crate::{
+    barrier,
+    regs::{
+        RegisterReadOnly, RegisterReadWrite, CNTFRQ_EL0, CNTHP_CTL_EL2, CNTHP_CVAL_EL2,
+        CNTHP_TVAL_EL2, CNTPCT_EL0, CNTPS_CTL_EL1, CNTPS_CVAL_EL1, CNTPS_TVAL_EL1, CNTP_CTL_EL0,
+        CNTP_CVAL_EL0, CNTP_TVAL_EL0, CNTVCT_EL0, CNTV_CTL_EL0, CNTV_CVAL_EL0, CNTV_TVAL_EL0,
+    },
+};
+use core::{
+    cmp::Ordering,
+    fmt,
+    marker::PhantomData,
+    ops::{Add, AddAssign, Sub, SubAssign},
+    time::Duration,
+};
+use register::LocalRegisterCopy;
+
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+
+mod sealed {
+    pub trait Timer {
+        fn count() -> u64;
+        fn read_ctl() -> u32;
+        fn write_ctl(value: u32);
+        fn write_tval(value: u32);
+        fn write_cval(value: u64);
+    }
+}
+
+/// The EL1 physical timer, counting `CNTPCT_EL0`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Physical;
+
+/// The virtual timer, counting `CNTVCT_EL0`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Virtual;
+
+/// The EL2 physical timer, counting `CNTPCT_EL0`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HypPhysical;
+
+/// The secure physical timer, counting `CNTPCT_EL0`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SecurePhysical;
+
+macro_rules! timer {
+    ($timer:ident, $count:ident, $ctl:ident, $tval:ident, $cval:ident) => {
+        impl sealed::Timer for $timer {
+            #[inline(always)]
+            fn count() -> u64 {
+                $count.get()
+            }
+
+            #[inline(always)]
+            fn read_ctl() -> u32 {
+                $ctl.get()
+            }
+
+            #[inline(always)]
+            fn write_ctl(value: u32) {
+                $ctl.set(value)
+            }
+
+            #[inline(always)]
+            fn write_tval(value: u32) {
+                $tval.set(value)
+            }
+
+            #[inline(always)]
+            fn write_cval(value: u64) {
+                $cval.set(value)
+            }
+        }
+
+        impl Timer for $timer {}
+    };
+}
+
+timer!(
+    Physical,
+    CNTPCT_EL0,
+    CNTP_CTL_EL0,
+    CNTP_TVAL_EL0,
+    CNTP_CVAL_EL0
+);
+timer!(
+    Virtual,
+    CNTVCT_EL0,
+    CNTV_CTL_EL0,
+    CNTV_TVAL_EL0,
+    CNTV_CVAL_EL0
+);
+timer!(
+    HypPhysical,
+    CNTPCT_EL0,
+    CNTHP_CTL_EL2,
+    CNTHP_TVAL_EL2,
+    CNTHP_CVAL_EL2
+);
+timer!(
+    SecurePhysical,
+    CNTPCT_EL0,
+    CNTPS_CTL_EL1,
+    CNTPS_TVAL_EL1,
+    CNTPS_CVAL_EL1
+);
+
+/// The largest number of ticks that can be programmed through `CNTx_TVAL_EL0`, a signed 32-bit
+/// value.
+const TVAL_MAX: u64 = i32::MAX as u64;
+
+/// Operations common to all timers.
+///
+/// The control registers of all timers share the layout of `CNTP_CTL_EL0`.
+pub trait Timer: sealed::Timer + Sized {
+    /// Returns the current value of the counter of the timer.
+    #[inline(always)]
+    fn now() -> Instant<Self> {
+        Instant::now()
+    }
+
+    /// Fires the timer once after `duration` has passed.
+    ///
+    /// Short durations are programmed relative to the counter through `TVAL`, longer ones as an
+    /// absolute deadline through `CVAL`.
+    fn arm_after(duration: Duration) {
+        let ticks = duration_to_ticks(duration);
+
+        if ticks <= TVAL_MAX {
+            Self::write_tval(ticks as u32);
+            enable::<Self>();
+        } else {
+            Self::arm_at(Instant::from_ticks(Self::now().ticks.saturating_add(ticks)));
+        }
+    }
+
+    /// Fires the timer once at `deadline`, or immediately if `deadline` has already passed.
+    fn arm_at(deadline: Instant<Self>) {
+        Self::write_cval(deadline.ticks);
+        enable::<Self>();
+    }
+
+    /// Disables the timer.
+    #[inline]
+    fn disable() {
+        Self::write_ctl(0);
+    }
+
+    /// Returns whether the timer is enabled.
+    #[inline]
+    fn is_enabled() -> bool {
+        ctl::<Self>().is_set(CNTP_CTL_EL0::ENABLE)
+    }
+
+    /// Returns whether the timer is enabled and its condition is met, regardless of `IMASK`.
+    #[inline]
+    fn is_expired() -> bool {
+        let ctl = ctl::<Self>();
+
+        ctl.is_set(CNTP_CTL_EL0::ENABLE) && ctl.is_set(CNTP_CTL_EL0::ISTATUS)
+    }
+
+    /// Returns whether the timer interrupt is asserted.
+    #[inline]
+    fn is_pending() -> bool {
+        Self::is_expired() && !ctl::<Self>().is_set(CNTP_CTL_EL0::IMASK)
+    }
+
+    /// Masks the timer interrupt without disabling the timer.
+    #[inline]
+    fn mask() {
+        Self::write_ctl(CNTP_CTL_EL0::IMASK::SET.modify(Self::read_ctl()));
+    }
+
+    /// Unmasks the timer interrupt.
+    #[inline]
+    fn unmask() {
+        Self::write_ctl(CNTP_CTL_EL0::IMASK::CLEAR.modify(Self::read_ctl()));
+    }
+}
+
+#[inline(always)]
+fn enable<T: Timer>() {
+    T::write_ctl((CNTP_CTL_EL0::ENABLE::SET + CNTP_CTL_EL0::IMASK::CLEAR).value);
+}
+
+#[inline(always)]
+fn ctl<T: Timer>() -> LocalRegisterCopy<u32, CNTP_CTL_EL0::Register> {
+    LocalRegisterCopy::new(T::read_ctl())
+}
+
+/// Returns the frequency of the system counter in Hz.
+///
+/// `CNTFRQ_EL0` is programmed by the firmware. On some firmware and emulators it is left at 0,
+/// which makes all conversions between ticks and time invalid.
+#[inline(always)]
+pub fn frequency() -> u64 {
+    CNTFRQ_EL0.get() as u64
+}
+
+/// Converts a number of counter ticks into a `Duration`, rounding down.
+///
+/// # Panics
+///
+/// Panics if `CNTFRQ_EL0` was not programmed and reads as 0.
+pub fn ticks_to_duration(ticks: u64) -> Duration {
+    let freq = frequency();
+    debug_assert!(freq != 0, "CNTFRQ_EL0 is not programmed");
+
+    ticks_to_duration_at(ticks, freq)
+}
+
+/// Converts a number of ticks of a counter running at `freq` Hz into a `Duration`, rounding down.
+///
+/// # Panics
+///
+/// Panics if `freq` is 0 or does not fit in 32 bits, like `CNTFRQ_EL0`.
+pub fn ticks_to_duration_at(ticks: u64, freq: u64) -> Duration {
+    assert!(
+        freq != 0 && freq <= u32::MAX as u64,
+        "counter frequency out of range"
+    );
+    let secs = ticks / freq;
+
+    // The remainder is smaller than the 32-bit frequency, so the product cannot overflow.
+    let nanos = (ticks % freq) * NANOS_PER_SEC / freq;
+
+    Duration::new(secs, nanos as u32)
+}
+
+/// Converts a `Duration` into a number of counter ticks, rounding up and saturating at `u64::MAX`.
+///
+/// `CNTFRQ_EL0` must have been programmed. If it reads as 0, every duration converts to 0 ticks,
+/// which debug builds catch with an assertion.
+pub fn duration_to_ticks(duration: Duration) -> u64 {
+    let freq = frequency();
+    debug_assert!(freq != 0, "CNTFRQ_EL0 is not programmed");
+
+    duration_to_ticks_at(duration, freq)
+}
+
+/// Converts a `Duration` into a number of ticks of a counter running at `freq` Hz, rounding up and
+/// saturating at `u64::MAX`.
+///
+/// Rounding up keeps the waits derived from the result at least as long as `duration`.
+///
+/// # Panics
+///
+/// Panics if `freq` does not fit in 32 bits, like `CNTFRQ_EL0`.
+pub fn duration_to_ticks_at(duration: Duration, freq: u64) -> u64 {
+    assert!(freq <= u32::MAX as u64, "counter frequency out of range");
+
+    // Nanoseconds are below 10^9 and the frequency below 2^32, so the product cannot overflow.
+    let sub_sec_nanos = duration.subsec_nanos() as u64 * freq;
+    let sub_sec = match sub_sec_nanos % NANOS_PER_SEC {
+        0 => sub_sec_nanos / NANOS_PER_SEC,
+        _ => sub_sec_nanos / NANOS_PER_SEC + 1,
+    };
+
+    duration
+        .as_secs()
+        .checked_mul(freq)
+        .and_then(|ticks| ticks.checked_add(sub_sec))
+        .unwrap_or(u64::MAX)
+}
+
+/// Spins for at least `duration`.
+pub fn delay(duration: Duration) {
+    let start = Instant::<Virtual>::now();
+    let ticks = duration_to_ticks(duration);
+
+    while Instant::<Virtual>::now().ticks.wrapping_sub(start.ticks) < ticks {}
+}
+
+/// Spins for at least `us` microseconds.
+#[inline]
+pub fn delay_us(us: u64) {
+    delay(Duration::from_micros(us))
+}
+
+/// Spins for at least `ms` milliseconds.
+#[inline]
+pub fn delay_ms(ms: u64) {
+    delay(Duration::from_millis(ms))
+}
+
+/// A point in time, measured by the counter of timer `T`.
+///
+/// Instants of the physical and the virtual timer differ by `CNTVOFF_EL2` and cannot be compared
+/// with each other.
+pub struct Instant<T = Virtual> {
+    ticks: u64,
+    timer: PhantomData<T>,
+}
+
+impl<T: Timer> Instant<T> {
+    /// Returns the current counter value.
+    ///
+    /// The counter read is ordered after preceding instructions by an ISB.
+    #[inline(always)]
+    pub fn now() -> Instant<T> {
+        unsafe { barrier::isb(barrier::SY) };
+
+        Instant::from_ticks(T::count())
+    }
+}
+
+impl<T> Instant<T> {
+    pub const fn from_ticks(ticks: u64) -> Instant<T> {
+        Instant {
+            ticks,
+            timer: PhantomData,
+        }
+    }
+
+    /// Returns the raw counter value.
+    pub const fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    /// Returns the time passed since `earlier`, or zero if `earlier` is later than `self`.
+    pub fn duration_since(&self, earlier: Instant<T>) -> Duration {
+        ticks_to_duration(self.ticks.saturating_sub(earlier.ticks))
+    }
+
+    pub fn checked_add(&self, duration: Duration) -> Option<Instant<T>> {
+        self.ticks
+            .checked_add(duration_to_ticks(duration))
+            .map(Instant::from_ticks)
+    }
+
+    pub fn checked_sub(&self, duration: Duration) -> Option<Instant<T>> {
+        self.ticks
+            .checked_sub(duration_to_ticks(duration))
+            .map(Instant::from_ticks)
+    }
+}
+
+impl<T: Timer> Instant<T> {
+    /// Returns the time passed since `self`.
+    pub fn elapsed(&self) -> Duration {
+        Instant::<T>::now().duration_since(*self)
+    }
+}
+
+impl<T> Clone for Instant<T> {
+    fn clone(&self) -> Instant<T> {
+        *self
+    }
+}
+
+impl<T> Copy for Instant<T> {}
+
+impl<T> PartialEq for Instant<T> {
+    fn eq(&self, other: &Instant<T>) -> bool {
+        self.ticks == other.ticks
+    }
+}
+
+impl<T> Eq for Instant<T> {}
+
+impl<T> PartialOrd for Instant<T> {
+    fn partial_cmp(&self, other: &Instant<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Instant<T> {
+    fn cmp(&self, other: &Instant<T>) -> Ordering {
+        self.ticks.cmp(&other.ticks)
+    }
+}
+
+impl<T> fmt::Debug for Instant<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Instant").field(&self.ticks).finish()
+    }
+}
+
+impl<T> Add<Duration> for Instant<T> {
+    type Output = Instant<T>;
+
+    /// # Panics
+    ///
+    /// Panics if the result overflows the counter.
+    fn add(self, duration: Duration) -> Instant<T> {
+        self.checked_add(duration)
+            .expect("overflow when adding duration to instant")
+    }
+}
+
+impl<T> AddAssign<Duration> for Instant<T> {
+    fn add_assign(&mut self, duration: Duration) {
+        *self = *self + duration;
+    }
+}
+
+impl<T> Sub<Duration> for Instant<T> {
+    type Output = Instant<T>;
+
+    /// # Panics
+    ///
+    /// Panics if the result underflows the counter.
+    fn sub(self, duration: Duration) -> Instant<T> {
+        self.checked_sub(duration)
+            .expect("overflow when subtracting duration from instant")
+    }
+}
+
+impl<T> SubAssign<Duration> for Instant<T> {
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = *self - duration;
+    }
+}
+
+impl<T> Sub<Instant<T>> for Instant<T> {
+    type Output = Duration;
+
+    fn sub(self, earlier: Instant<T>) -> Duration {
+        self.duration_since(earlier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FREQ: u64 = 62_500_000;
+
+    #[test]
+    fn duration_rounds_up() {
+        // 16 ns per tick.
+        assert_eq!(duration_to_ticks_at(Duration::from_nanos(0), FREQ), 0);
+        assert_eq!(duration_to_ticks_at(Duration::from_nanos(1), FREQ), 1);
+        assert_eq!(duration_to_ticks_at(Duration::from_nanos(16), FREQ), 1);
+        assert_eq!(duration_to_ticks_at(Duration::from_nanos(17), FREQ), 2);
+        assert_eq!(duration_to_ticks_at(Duration::from_micros(1), FREQ), 63);
+        assert_eq!(duration_to_ticks_at(Duration::from_millis(1), FREQ), 62_500);
+        assert_eq!(
+            duration_to_ticks_at(Duration::new(2, 999_999_999), FREQ),
+            3 * FREQ
+        );
+    }
+
+    #[test]
+    fn duration_saturates() {
+        assert_eq!(duration_to_ticks_at(Duration::MAX, FREQ), u64::MAX);
+        assert_eq!(
+            duration_to_ticks_at(Duration::from_secs(u64::MAX / FREQ + 1), FREQ),
+            u64::MAX
+        );
+        assert_eq!(
+            duration_to_ticks_at(Duration::new(u64::MAX / FREQ, 999_999_999), FREQ),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn ticks_round_down() {
+        assert_eq!(ticks_to_duration_at(0, FREQ), Duration::from_nanos(0));
+        assert_eq!(ticks_to_duration_at(63, FREQ), Duration::from_nanos(1008));
+        assert_eq!(ticks_to_duration_at(FREQ + 1, FREQ), Duration::new(1, 16));
+
+        // 1 / 3 s is not a whole number of nanoseconds.
+        assert_eq!(
+            ticks_to_duration_at(1, 3),
+            Duration::from_nanos(333_333_333)
+        );
+        assert_eq!(
+            ticks_to_duration_at(u64::MAX, u32::MAX as u64),
+            Duration::new(u64::MAX / u32::MAX as u64, 0)
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        // Above 1GHz, a tick is shorter than the resolution of `Duration`.
+        for &freq in &[1, 3, 19_200_000, FREQ, NANOS_PER_SEC] {
+            for &ticks in &[0, 1, 7, freq - 1, freq, 123_456_789_012] {
+                let duration = ticks_to_duration_at(ticks, freq);
+
+                // Rounding down and then up gets back to the original tick.
+                assert_eq!(duration_to_ticks_at(duration, freq), ticks, "{} Hz", freq);
+            }
+        }
+    }
+}
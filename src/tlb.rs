@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! TLB maintenance
+//!
+//! Broadcast invalidations of whole translation regimes, each wrapped in the barriers that make
+//! it complete on all PEs in the Inner Shareable domain before the function returns:
+//!
+//! - `DSB ISHST` before the `TLBI`, so that prior translation table updates are observed by the
+//!   walks that refill the TLBs.
+//! - `DSB ISH` after the `TLBI`, which waits for the invalidation to complete.
+//! - `ISB`, so that the following instructions are fetched with the new translations.
+
+#[cfg(target_arch = "aarch64")]
+use crate::barrier;
+
+macro_rules! tlbi {
+    ($(#[$attr:meta])* $name:ident, $op:tt) => {
+        $(#[$attr])*
+        #[inline(always)]
+        pub fn $name() {
+            #[cfg(target_arch = "aarch64")]
+            unsafe {
+                barrier::dsb(barrier::ISHST);
+                llvm_asm!(concat!("TLBI ", $op) : : : "memory" : "volatile");
+                barrier::dsb(barrier::ISH);
+                barrier::isb(barrier::SY);
+            }
+
+            #[cfg(not(target_arch = "aarch64"))]
+            unimplemented!()
+        }
+    };
+}
+
+tlbi!(
+    /// Invalidates all stage 1 entries of the EL1&0 translation regime with the current VMID.
+    vmalle1is,
+    "VMALLE1IS"
+);
+
+tlbi!(
+    /// Invalidates all stage 1 and stage 2 entries of the EL1&0 translation regime with the
+    /// current VMID.
+    ///
+    /// Must be executed at EL2.
+    vmalls12e1is,
+    "VMALLS12E1IS"
+);
+
+tlbi!(
+    /// Invalidates all entries of the EL1&0 translation regime, for all VMIDs.
+    ///
+    /// Must be executed at EL2.
+    alle1is,
+    "ALLE1IS"
+);
+
+tlbi!(
+    /// Invalidates all entries of the EL2 and, with `HCR_EL2.E2H` set, the EL2&0 translation
+    /// regime.
+    ///
+    /// Must be executed at EL2.
+    alle2is,
+    "ALLE2IS"
+);
@@ -0,0 +1,271 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Multiprocessor topology
+//!
+//! Decodes the affinity fields of `MPIDR_EL1` and maps them to a dense, zero-based core index that
+//! can be used to index per-CPU data.
+//!
+//! The architecture does not define how the affinity levels map to threads, cores and clusters, so
+//! the mapping is described by a [`ClusterLayout`]:
+//!
+//! ```ignore
+//! use cortex_a::topology::{ClusterLayout, CpuId};
+//!
+//! // Two clusters of four cores each, with Aff0 numbering the cores and Aff1 the clusters.
+//! const LAYOUT: ClusterLayout = ClusterLayout::clusters(2, 4);
+//!
+//! let index = LAYOUT.index_of(CpuId::current()).unwrap();
+//! ```
+
+use crate::regs::{RegisterReadOnly, MPIDR_EL1};
+use register::LocalRegisterCopy;
+
+/// Affinity of a PE, packed as `Aff3:Aff2:Aff1:Aff0` with eight bits per level.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CpuId(u32);
+
+impl CpuId {
+    pub const fn new(aff3: u8, aff2: u8, aff1: u8, aff0: u8) -> CpuId {
+        CpuId((aff3 as u32) << 24 | (aff2 as u32) << 16 | (aff1 as u32) << 8 | aff0 as u32)
+    }
+
+    /// Returns the affinity of the executing PE.
+    #[inline]
+    pub fn current() -> CpuId {
+        CpuId::from_mpidr(MPIDR_EL1.get())
+    }
+
+    /// Extracts the affinity fields from a raw `MPIDR_EL1` value.
+    pub fn from_mpidr(mpidr: u64) -> CpuId {
+        let mpidr: LocalRegisterCopy<u64, MPIDR_EL1::Register> = LocalRegisterCopy::new(mpidr);
+
+        CpuId::new(
+            mpidr.read(MPIDR_EL1::Aff3) as u8,
+            mpidr.read(MPIDR_EL1::Aff2) as u8,
+            mpidr.read(MPIDR_EL1::Aff1) as u8,
+            mpidr.read(MPIDR_EL1::Aff0) as u8,
+        )
+    }
+
+    /// Returns the affinity fields in their `MPIDR_EL1` positions, as expected by e.g. PSCI
+    /// `CPU_ON` or the GIC affinity routing registers.
+    pub fn to_mpidr(&self) -> u64 {
+        (MPIDR_EL1::Aff3.val(self.aff3() as u64)
+            + MPIDR_EL1::Aff2.val(self.aff2() as u64)
+            + MPIDR_EL1::Aff1.val(self.aff1() as u64)
+            + MPIDR_EL1::Aff0.val(self.aff0() as u64))
+        .value
+    }
+
+    /// Returns the value of affinity level `level`, which must be in the range `0..=3`.
+    pub fn affinity(&self, level: u32) -> u8 {
+        assert!(level <= 3);
+
+        (self.0 >> (level * 8)) as u8
+    }
+
+    pub fn aff0(&self) -> u8 {
+        self.affinity(0)
+    }
+
+    pub fn aff1(&self) -> u8 {
+        self.affinity(1)
+    }
+
+    pub fn aff2(&self) -> u8 {
+        self.affinity(2)
+    }
+
+    pub fn aff3(&self) -> u8 {
+        self.affinity(3)
+    }
+
+    /// Returns the packed `Aff3:Aff2:Aff1:Aff0` value.
+    pub const fn packed(&self) -> u32 {
+        self.0
+    }
+}
+
+/// Describes how many distinct values each affinity level takes in a system.
+///
+/// Affinity values are assumed to be numbered densely from zero at every level.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ClusterLayout {
+    /// Number of entries per affinity level, lowest level first.
+    levels: [u32; 4],
+}
+
+impl ClusterLayout {
+    /// Creates a layout from the number of entries at affinity levels 0 to 3.
+    ///
+    /// # Panics
+    ///
+    /// - If a count is zero or larger than 256, the number of values of an eight bit affinity
+    ///   field.
+    pub const fn new(aff0: u32, aff1: u32, aff2: u32, aff3: u32) -> ClusterLayout {
+        assert!(
+            aff0 != 0 && aff0 <= 256,
+            "affinity level count out of range"
+        );
+        assert!(
+            aff1 != 0 && aff1 <= 256,
+            "affinity level count out of range"
+        );
+        assert!(
+            aff2 != 0 && aff2 <= 256,
+            "affinity level count out of range"
+        );
+        assert!(
+            aff3 != 0 && aff3 <= 256,
+            "affinity level count out of range"
+        );
+
+        ClusterLayout {
+            levels: [aff0, aff1, aff2, aff3],
+        }
+    }
+
+    /// The common layout without multithreading: Aff0 numbers the cores in a cluster, Aff1 numbers
+    /// the clusters.
+    ///
+    /// # Panics
+    ///
+    /// - See [`new`](ClusterLayout::new).
+    pub const fn clusters(clusters: u32, cores_per_cluster: u32) -> ClusterLayout {
+        ClusterLayout::new(cores_per_cluster, clusters, 1, 1)
+    }
+
+    /// The layout of multithreaded PEs, and of DynamIQ systems which report `MPIDR_EL1.MT == 1`
+    /// with a single thread: Aff0 numbers the threads, Aff1 the cores and Aff2 the clusters.
+    ///
+    /// # Panics
+    ///
+    /// - See [`new`](ClusterLayout::new).
+    pub const fn threads(
+        clusters: u32,
+        cores_per_cluster: u32,
+        threads_per_core: u32,
+    ) -> ClusterLayout {
+        ClusterLayout::new(threads_per_core, cores_per_cluster, clusters, 1)
+    }
+
+    /// Returns the total number of PEs described by the layout.
+    pub fn num_cpus(&self) -> usize {
+        self.levels.iter().map(|&n| n as usize).product()
+    }
+
+    /// Returns the dense index of `id`, or `None` if an affinity value exceeds the layout.
+    pub fn index_of(&self, id: CpuId) -> Option<usize> {
+        let mut index = 0;
+
+        for level in (0..4).rev() {
+            let aff = id.affinity(level) as u32;
+            let count = self.levels[level as usize];
+
+            if aff >= count {
+                return None;
+            }
+
+            index = index * count as usize + aff as usize;
+        }
+
+        Some(index)
+    }
+
+    /// Returns the affinity of the PE with dense index `index`, or `None` if `index` is out of
+    /// range. This is the inverse of [`index_of`](ClusterLayout::index_of), e.g. for waking up
+    /// secondary cores.
+    pub fn cpu_id(&self, index: usize) -> Option<CpuId> {
+        if index >= self.num_cpus() {
+            return None;
+        }
+
+        let mut aff = [0u8; 4];
+        let mut rest = index;
+
+        for (level, &count) in self.levels.iter().enumerate() {
+            aff[level] = (rest % count as usize) as u8;
+            rest /= count as usize;
+        }
+
+        Some(CpuId::new(aff[3], aff[2], aff[1], aff[0]))
+    }
+}
+
+/// Returns whether the executing PE is part of a uniprocessor system.
+#[inline]
+pub fn is_uniprocessor() -> bool {
+    MPIDR_EL1.matches_all(MPIDR_EL1::U::Uniprocessor)
+}
+
+/// Returns whether the lowest affinity level of the executing PE consists of logical PEs
+/// implemented using multithreading.
+#[inline]
+pub fn is_multithreaded() -> bool {
+    MPIDR_EL1.matches_all(MPIDR_EL1::MT::Multithreaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAYOUTS: [ClusterLayout; 5] = [
+        ClusterLayout::new(1, 1, 1, 1),
+        ClusterLayout::clusters(2, 4),
+        ClusterLayout::threads(3, 4, 2),
+        ClusterLayout::new(256, 1, 1, 1),
+        ClusterLayout::new(2, 3, 5, 7),
+    ];
+
+    #[test]
+    fn round_trip() {
+        for layout in &LAYOUTS {
+            for index in 0..layout.num_cpus() {
+                let id = layout.cpu_id(index).unwrap();
+
+                assert_eq!(layout.index_of(id), Some(index), "{:?} {}", layout, index);
+            }
+
+            assert_eq!(layout.cpu_id(layout.num_cpus()), None);
+        }
+    }
+
+    #[test]
+    fn index_of() {
+        let layout = ClusterLayout::clusters(2, 4);
+
+        assert_eq!(layout.num_cpus(), 8);
+        assert_eq!(layout.index_of(CpuId::new(0, 0, 1, 2)), Some(6));
+        assert_eq!(layout.cpu_id(6), Some(CpuId::new(0, 0, 1, 2)));
+        assert_eq!(layout.index_of(CpuId::new(0, 0, 0, 4)), None);
+        assert_eq!(layout.index_of(CpuId::new(0, 0, 2, 0)), None);
+        assert_eq!(layout.index_of(CpuId::new(0, 1, 0, 0)), None);
+    }
+
+    #[test]
+    fn largest() {
+        let layout = ClusterLayout::new(256, 256, 256, 256);
+        let last = CpuId::new(255, 255, 255, 255);
+
+        assert_eq!(layout.num_cpus(), 1 << 32);
+        assert_eq!(layout.index_of(last), Some((1 << 32) - 1));
+        assert_eq!(layout.cpu_id((1 << 32) - 1), Some(last));
+    }
+
+    #[test]
+    #[should_panic(expected = "affinity level count out of range")]
+    fn zero_count() {
+        ClusterLayout::clusters(0, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "affinity level count out of range")]
+    fn count_too_large() {
+        ClusterLayout::threads(1, 1, 257);
+    }
+}
@@ -0,0 +1,599 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Translation regime configuration
+//!
+//! [`TcrBuilder`] assembles the address space fields of `TCR_EL1` and `TCR_EL2` and checks them
+//! against the capabilities reported in `ID_AA64MMFR0_EL1` and `ID_AA64MMFR2_EL1` before anything
+//! is written to hardware. All incompatibilities are reported at once:
+//!
+//! ```ignore
+//! use cortex_a::{cpuid::CpuFeatures, regs::*, translation::{Granule, TcrBuilder}};
+//!
+//! let tcr = TcrBuilder::new(&CpuFeatures::detect())
+//!     .lower(Granule::KiB_64, 16)
+//!     .pa_bits(48)
+//!     .build_el1()
+//!     .unwrap_or_else(|errors| {
+//!         for e in errors.iter() {
+//!             // Report `e`.
+//!         }
+//!         panic!()
+//!     });
+//!
+//! TCR_EL1.write(tcr + TCR_EL1::SH0::Inner + TCR_EL1::TBI0::Ignored);
+//! ```
+//!
+//! Only the fields that depend on the processor capabilities are produced. Cacheability,
+//! shareability and the remaining controls are left to the caller.
+
+use crate::{
+    cpuid::CpuFeatures,
+    regs::{TCR_EL1, TCR_EL2},
+};
+use core::fmt;
+use register::FieldValue;
+
+/// A translation granule size.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Granule {
+    KiB_4,
+    KiB_16,
+    KiB_64,
+}
+
+impl Granule {
+    /// Returns the granule size in bytes.
+    pub const fn size(&self) -> usize {
+        match self {
+            Granule::KiB_4 => 4 * 1024,
+            Granule::KiB_16 => 16 * 1024,
+            Granule::KiB_64 => 64 * 1024,
+        }
+    }
+
+    fn is_supported(&self, features: &CpuFeatures) -> bool {
+        match self {
+            Granule::KiB_4 => features.has_granule_4k(),
+            Granule::KiB_16 => features.has_granule_16k(),
+            Granule::KiB_64 => features.has_granule_64k(),
+        }
+    }
+}
+
+/// One of the two virtual address ranges of a translation regime.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AddressRange {
+    /// The range translated using `TTBR0_ELx`, configured by `TG0` and `T0SZ`.
+    Lower,
+
+    /// The range translated using `TTBR1_ELx`, configured by `TG1` and `T1SZ`.
+    Upper,
+}
+
+/// An incompatibility between a requested configuration and the processor.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TcrError {
+    /// The granule is reported as not supported in `ID_AA64MMFR0_EL1.TGran{4,16,64}`.
+    UnsupportedGranule {
+        range: AddressRange,
+        granule: Granule,
+    },
+
+    /// `TxSZ` lies outside of `min..=max`, the range allowed for the granule.
+    RegionSize {
+        range: AddressRange,
+        tsz: u8,
+        min: u8,
+        max: u8,
+    },
+
+    /// The physical address size has no `IPS` encoding.
+    InvalidPhysicalAddressSize { bits: u32 },
+
+    /// The physical address size exceeds `ID_AA64MMFR0_EL1.PARange`.
+    PhysicalAddressSize { bits: u32, supported: u32 },
+
+    /// 52-bit physical addresses are only permitted with the 64KiB granule.
+    LargePhysicalAddressGranule {
+        range: AddressRange,
+        granule: Granule,
+    },
+
+    /// The upper range was configured for the EL2 translation regime, which has none without
+    /// `HCR_EL2.E2H`.
+    NoUpperRange,
+
+    /// The lower range was not configured for the EL2 translation regime, which cannot disable it
+    /// without `HCR_EL2.E2H`.
+    MissingLowerRange,
+}
+
+impl fmt::Display for TcrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TcrError::UnsupportedGranule { range, granule } => {
+                write!(f, "{:?} range: granule {:?} not supported", range, granule)
+            }
+            TcrError::RegionSize {
+                range,
+                tsz,
+                min,
+                max,
+            } => write!(
+                f,
+                "{:?} range: TxSZ {} outside of {}..={}",
+                range, tsz, min, max
+            ),
+            TcrError::InvalidPhysicalAddressSize { bits } => {
+                write!(f, "{}-bit physical addresses cannot be encoded", bits)
+            }
+            TcrError::PhysicalAddressSize { bits, supported } => write!(
+                f,
+                "{}-bit physical addresses requested, {} supported",
+                bits, supported
+            ),
+            TcrError::LargePhysicalAddressGranule { range, granule } => write!(
+                f,
+                "{:?} range: 52-bit physical addresses require the 64KiB granule, not {:?}",
+                range, granule
+            ),
+            TcrError::NoUpperRange => {
+                f.write_str("Upper range: not available in the EL2 translation regime")
+            }
+            TcrError::MissingLowerRange => {
+                f.write_str("Lower range: required in the EL2 translation regime")
+            }
+        }
+    }
+}
+
+/// Every incompatibility found by [`TcrBuilder`].
+#[derive(Copy, Clone, Debug)]
+pub struct TcrErrors {
+    errors: [Option<TcrError>; TcrErrors::CAPACITY],
+    len: usize,
+}
+
+impl TcrErrors {
+    /// Three errors per range, plus one for the physical address size and one for the upper range
+    /// of EL2. A missing lower range of EL2 replaces the errors of that range.
+    const CAPACITY: usize = 8;
+
+    const fn new() -> TcrErrors {
+        TcrErrors {
+            errors: [None; TcrErrors::CAPACITY],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, error: TcrError) {
+        self.errors[self.len] = Some(error);
+        self.len += 1;
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &TcrError> {
+        self.errors[..self.len].iter().filter_map(Option::as_ref)
+    }
+}
+
+impl fmt::Display for TcrErrors {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, e) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{}", e)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Region {
+    granule: Granule,
+    tsz: u8,
+}
+
+/// Raw field values of a validated configuration, shared by the EL1 and EL2 encodings.
+struct Fields {
+    ips: u64,
+    lower: Option<(u64, u64)>,
+    upper: Option<(u64, u64)>,
+}
+
+/// Builds the address space fields of `TCR_ELx`, checked against the processor capabilities.
+///
+/// A range that is not configured has its translation table walks disabled through `EPDx`.
+#[derive(Copy, Clone, Debug)]
+pub struct TcrBuilder {
+    features: CpuFeatures,
+    lower: Option<Region>,
+    upper: Option<Region>,
+    pa_bits: u32,
+}
+
+impl TcrBuilder {
+    /// Creates a builder for the processor described by `features`.
+    ///
+    /// The physical address size defaults to the size reported in `ID_AA64MMFR0_EL1.PARange`.
+    pub fn new(features: &CpuFeatures) -> TcrBuilder {
+        TcrBuilder {
+            features: *features,
+            lower: None,
+            upper: None,
+            pa_bits: features.pa_bits(),
+        }
+    }
+
+    /// Configures the `TTBR0_ELx` range, which covers `2^(64 - t0sz)` bytes.
+    pub fn lower(mut self, granule: Granule, t0sz: u8) -> TcrBuilder {
+        self.lower = Some(Region { granule, tsz: t0sz });
+        self
+    }
+
+    /// Configures the `TTBR1_ELx` range, which covers `2^(64 - t1sz)` bytes.
+    pub fn upper(mut self, granule: Granule, t1sz: u8) -> TcrBuilder {
+        self.upper = Some(Region { granule, tsz: t1sz });
+        self
+    }
+
+    /// Sets the intermediate physical address size in bits.
+    pub fn pa_bits(mut self, bits: u32) -> TcrBuilder {
+        self.pa_bits = bits;
+        self
+    }
+
+    /// Returns the range of `TxSZ` values permitted for `granule`.
+    ///
+    /// The minimum is 16, or 12 for the 64KiB granule if 52-bit virtual addresses are supported.
+    /// The maximum is 39, or up to 48 if small translation tables are supported.
+    pub fn tsz_range(&self, granule: Granule) -> (u8, u8) {
+        let min = if granule == Granule::KiB_64 && self.features.va_bits() == 52 {
+            12
+        } else {
+            16
+        };
+
+        let max = if !self.features.has_small_translation_tables() {
+            39
+        } else if granule == Granule::KiB_64 {
+            47
+        } else {
+            48
+        };
+
+        (min, max)
+    }
+
+    /// Checks the configuration without producing a register value.
+    pub fn validate(&self) -> Result<(), TcrErrors> {
+        self.fields().map(|_| ())
+    }
+
+    /// Returns the `TCR_EL1` fields for the configuration, or every incompatibility found.
+    pub fn build_el1(&self) -> Result<FieldValue<u64, TCR_EL1::Register>, TcrErrors> {
+        let fields = self.fields()?;
+        let mut value = TCR_EL1::IPS.val(fields.ips);
+
+        value = match fields.lower {
+            Some((tg, tsz)) => value + TCR_EL1::TG0.val(tg) + TCR_EL1::T0SZ.val(tsz),
+            None => value + TCR_EL1::EPD0::DisableTTBR0Walks,
+        };
+
+        value = match fields.upper {
+            Some((tg, tsz)) => value + TCR_EL1::TG1.val(tg) + TCR_EL1::T1SZ.val(tsz),
+            None => value + TCR_EL1::EPD1::DisableTTBR1Walks,
+        };
+
+        Ok(value)
+    }
+
+    /// Returns the `TCR_EL2` fields for the configuration, or every incompatibility found.
+    ///
+    /// Without `HCR_EL2.E2H`, EL2 only has the lower range, which cannot be disabled.
+    pub fn build_el2(&self) -> Result<FieldValue<u64, TCR_EL2::Register>, TcrErrors> {
+        let fields = self.fields();
+        let mut errors = fields
+            .as_ref()
+            .err()
+            .copied()
+            .unwrap_or_else(TcrErrors::new);
+
+        if self.lower.is_none() {
+            errors.push(TcrError::MissingLowerRange);
+        }
+        if self.upper.is_some() {
+            errors.push(TcrError::NoUpperRange);
+        }
+
+        match fields {
+            Ok(Fields {
+                ips,
+                lower: Some((tg, tsz)),
+                ..
+            }) if errors.is_empty() => {
+                Ok(TCR_EL2::PS.val(ips) + TCR_EL2::TG0.val(tg) + TCR_EL2::T0SZ.val(tsz))
+            }
+            _ => Err(errors),
+        }
+    }
+
+    /// Returns the `TCR_EL2_E2H` fields for the configuration, or every incompatibility found.
+    ///
+    /// With `HCR_EL2.E2H`, `TCR_EL2` has the layout of `TCR_EL1`.
+    pub fn build_el2_e2h(&self) -> Result<FieldValue<u64, TCR_EL1::Register>, TcrErrors> {
+        self.build_el1()
+    }
+
+    fn fields(&self) -> Result<Fields, TcrErrors> {
+        let mut errors = TcrErrors::new();
+
+        let ips = match self.pa_bits {
+            32 => Some(TCR_EL1::IPS::Value::Bits_32),
+            36 => Some(TCR_EL1::IPS::Value::Bits_36),
+            40 => Some(TCR_EL1::IPS::Value::Bits_40),
+            42 => Some(TCR_EL1::IPS::Value::Bits_42),
+            44 => Some(TCR_EL1::IPS::Value::Bits_44),
+            48 => Some(TCR_EL1::IPS::Value::Bits_48),
+            52 => Some(TCR_EL1::IPS::Value::Bits_52),
+            _ => None,
+        };
+
+        let supported = self.features.pa_bits();
+        if ips.is_none() {
+            errors.push(TcrError::InvalidPhysicalAddressSize { bits: self.pa_bits });
+        } else if self.pa_bits > supported {
+            errors.push(TcrError::PhysicalAddressSize {
+                bits: self.pa_bits,
+                supported,
+            });
+        }
+
+        let lower = self.check(AddressRange::Lower, self.lower, &mut errors);
+        let upper = self.check(AddressRange::Upper, self.upper, &mut errors);
+
+        match ips {
+            Some(ips) if errors.is_empty() => Ok(Fields {
+                ips: ips as u64,
+                lower: lower.map(|r| (tg0(r.granule), r.tsz as u64)),
+                upper: upper.map(|r| (tg1(r.granule), r.tsz as u64)),
+            }),
+            _ => Err(errors),
+        }
+    }
+
+    fn check(
+        &self,
+        range: AddressRange,
+        region: Option<Region>,
+        errors: &mut TcrErrors,
+    ) -> Option<Region> {
+        let region = region?;
+        let granule = region.granule;
+
+        if !granule.is_supported(&self.features) {
+            errors.push(TcrError::UnsupportedGranule { range, granule });
+        }
+
+        let (min, max) = self.tsz_range(granule);
+        if region.tsz < min || region.tsz > max {
+            errors.push(TcrError::RegionSize {
+                range,
+                tsz: region.tsz,
+                min,
+                max,
+            });
+        }
+
+        if self.pa_bits == 52 && granule != Granule::KiB_64 {
+            errors.push(TcrError::LargePhysicalAddressGranule { range, granule });
+        }
+
+        Some(region)
+    }
+}
+
+fn tg0(granule: Granule) -> u64 {
+    match granule {
+        Granule::KiB_4 => TCR_EL1::TG0::Value::KiB_4 as u64,
+        Granule::KiB_16 => TCR_EL1::TG0::Value::KiB_16 as u64,
+        Granule::KiB_64 => TCR_EL1::TG0::Value::KiB_64 as u64,
+    }
+}
+
+fn tg1(granule: Granule) -> u64 {
+    match granule {
+        Granule::KiB_4 => TCR_EL1::TG1::Value::KiB_4 as u64,
+        Granule::KiB_16 => TCR_EL1::TG1::Value::KiB_16 as u64,
+        Granule::KiB_64 => TCR_EL1::TG1::Value::KiB_64 as u64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 48-bit PAs, all granules, no small translation tables and 48-bit VAs.
+    fn features() -> CpuFeatures {
+        CpuFeatures {
+            mmfr0: 0x0010_0005,
+            ..CpuFeatures::default()
+        }
+    }
+
+    fn errors(result: Result<impl Sized, TcrErrors>) -> [Option<TcrError>; TcrErrors::CAPACITY] {
+        match result {
+            Ok(_) => panic!("configuration accepted"),
+            Err(errors) => errors.errors,
+        }
+    }
+
+    #[test]
+    fn el1() {
+        let tcr = TcrBuilder::new(&features())
+            .lower(Granule::KiB_4, 16)
+            .upper(Granule::KiB_64, 25)
+            .build_el1()
+            .unwrap();
+
+        let expected = TCR_EL1::IPS::Bits_48
+            + TCR_EL1::TG0::KiB_4
+            + TCR_EL1::T0SZ.val(16)
+            + TCR_EL1::TG1::KiB_64
+            + TCR_EL1::T1SZ.val(25);
+        assert_eq!(tcr.value, expected.value);
+        assert_eq!(tcr.mask, expected.mask);
+    }
+
+    #[test]
+    fn el1_disabled_ranges() {
+        let tcr = TcrBuilder::new(&features()).build_el1().unwrap();
+
+        let expected = TCR_EL1::IPS::Bits_48
+            + TCR_EL1::EPD0::DisableTTBR0Walks
+            + TCR_EL1::EPD1::DisableTTBR1Walks;
+        assert_eq!(tcr.value, expected.value);
+    }
+
+    #[test]
+    fn el2() {
+        let tcr = TcrBuilder::new(&features())
+            .lower(Granule::KiB_16, 20)
+            .pa_bits(40)
+            .build_el2()
+            .unwrap();
+
+        let expected = TCR_EL2::PS::Bits_40 + TCR_EL2::TG0::KiB_16 + TCR_EL2::T0SZ.val(20);
+        assert_eq!(tcr.value, expected.value);
+        assert_eq!(tcr.mask, expected.mask);
+    }
+
+    #[test]
+    fn el2_missing_lower_range() {
+        let errors = errors(TcrBuilder::new(&features()).build_el2());
+
+        assert_eq!(errors[0], Some(TcrError::MissingLowerRange));
+        assert_eq!(errors[1], None);
+    }
+
+    #[test]
+    fn el2_upper_range() {
+        let builder = TcrBuilder::new(&features())
+            .lower(Granule::KiB_4, 16)
+            .upper(Granule::KiB_4, 16);
+        let errors = errors(builder.build_el2());
+
+        assert_eq!(errors[0], Some(TcrError::NoUpperRange));
+        assert_eq!(errors[1], None);
+        assert!(builder.build_el2_e2h().is_ok());
+    }
+
+    #[test]
+    fn el2_all_errors() {
+        let builder = TcrBuilder::new(&features())
+            .upper(Granule::KiB_4, 8)
+            .pa_bits(52);
+        let errors = errors(builder.build_el2());
+
+        assert_eq!(
+            errors[..5],
+            [
+                Some(TcrError::PhysicalAddressSize {
+                    bits: 52,
+                    supported: 48
+                }),
+                Some(TcrError::RegionSize {
+                    range: AddressRange::Upper,
+                    tsz: 8,
+                    min: 16,
+                    max: 39
+                }),
+                Some(TcrError::LargePhysicalAddressGranule {
+                    range: AddressRange::Upper,
+                    granule: Granule::KiB_4
+                }),
+                Some(TcrError::MissingLowerRange),
+                Some(TcrError::NoUpperRange),
+            ]
+        );
+    }
+
+    #[test]
+    fn unsupported_granule() {
+        let features = CpuFeatures {
+            mmfr0: 0x0000_0005,
+            ..CpuFeatures::default()
+        };
+        let errors = errors(
+            TcrBuilder::new(&features)
+                .lower(Granule::KiB_16, 16)
+                .build_el1(),
+        );
+
+        assert_eq!(
+            errors[0],
+            Some(TcrError::UnsupportedGranule {
+                range: AddressRange::Lower,
+                granule: Granule::KiB_16
+            })
+        );
+        assert_eq!(errors[1], None);
+    }
+
+    #[test]
+    fn region_size() {
+        let errors = errors(
+            TcrBuilder::new(&features())
+                .lower(Granule::KiB_64, 40)
+                .build_el1(),
+        );
+
+        assert_eq!(
+            errors[0],
+            Some(TcrError::RegionSize {
+                range: AddressRange::Lower,
+                tsz: 40,
+                min: 16,
+                max: 39
+            })
+        );
+    }
+
+    #[test]
+    fn small_translation_tables() {
+        let features = CpuFeatures {
+            mmfr2: 0x1 << 28,
+            ..features()
+        };
+        let builder = TcrBuilder::new(&features);
+
+        assert_eq!(builder.tsz_range(Granule::KiB_4), (16, 48));
+        assert_eq!(builder.tsz_range(Granule::KiB_64), (16, 47));
+        assert!(builder.lower(Granule::KiB_4, 48).build_el2().is_ok());
+    }
+
+    #[test]
+    fn invalid_physical_address_size() {
+        let errors = errors(TcrBuilder::new(&features()).pa_bits(46).build_el1());
+
+        assert_eq!(
+            errors[0],
+            Some(TcrError::InvalidPhysicalAddressSize { bits: 46 })
+        );
+    }
+}
@@ -0,0 +1,273 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Guest EL1 System register context
+//!
+//! [`El1Context`] holds the EL1 and EL0 System registers of a vCPU while it is not running:
+//!
+//! ```ignore
+//! use cortex_a::virt::context::El1Context;
+//!
+//! // Switching the vCPU out:
+//! prev.el1.save();
+//!
+//! // Switching the vCPU in:
+//! next.el1.restore();
+//! ```
+//!
+//! The registers are accessed through a [`Backend`]. [`Hardware`] uses the EL1 register names, for
+//! hypervisors running with `HCR_EL2.E2H` clear, and [`HardwareE2H`] the `_EL12` aliases, for VHE
+//! hosts. `save()` and `restore()` select between the two by reading `HCR_EL2.E2H`, other backends
+//! allow to run the sequences on the host, e.g. in tests.
+//!
+//! Registers are restored in the order of [`ContextRegister::ALL`]: the translation table bases
+//! and memory attributes come before `TCR_EL1`, which comes before `SCTLR_EL1`, so the controls of
+//! the stage 1 translation never refer to the configuration of the previous guest. The restored
+//! state takes effect on the exception return to the guest, which is context synchronizing. All
+//! functions must be called at EL2.
+
+use crate::regs::*;
+
+/// Access to the registers held in an [`El1Context`].
+pub trait Backend {
+    fn read(&mut self, reg: ContextRegister) -> u64;
+    fn write(&mut self, reg: ContextRegister, value: u64);
+}
+
+/// Accesses the registers by their EL1 and EL0 names, with `HCR_EL2.E2H` clear.
+pub struct Hardware;
+
+/// Accesses the registers through the `_EL12` aliases where the EL1 name is redirected to EL2,
+/// with `HCR_EL2.E2H` set.
+pub struct HardwareE2H;
+
+macro_rules! context {
+    ($($field:ident: $reg:ident / $alias:ident as $width:ty,)*) => {
+        /// A System register held in an [`El1Context`].
+        #[allow(non_camel_case_types)]
+        #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+        pub enum ContextRegister {
+            $($reg,)*
+        }
+
+        impl ContextRegister {
+            /// All registers, in restore order.
+            pub const ALL: &'static [ContextRegister] = &[$(ContextRegister::$reg,)*];
+        }
+
+        /// Saved EL1 System register state of a vCPU, one field per [`ContextRegister`].
+        #[repr(C)]
+        #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+        pub struct El1Context {
+            $(pub $field: u64,)*
+        }
+
+        impl El1Context {
+            /// Returns the saved value of `reg`.
+            pub fn get(&self, reg: ContextRegister) -> u64 {
+                match reg {
+                    $(ContextRegister::$reg => self.$field,)*
+                }
+            }
+
+            /// Sets the saved value of `reg`.
+            pub fn set(&mut self, reg: ContextRegister, value: u64) {
+                match reg {
+                    $(ContextRegister::$reg => self.$field = value,)*
+                }
+            }
+        }
+
+        impl Backend for Hardware {
+            #[inline]
+            fn read(&mut self, reg: ContextRegister) -> u64 {
+                match reg {
+                    $(ContextRegister::$reg => $reg.get() as u64,)*
+                }
+            }
+
+            #[inline]
+            fn write(&mut self, reg: ContextRegister, value: u64) {
+                match reg {
+                    $(ContextRegister::$reg => $reg.set(value as $width),)*
+                }
+            }
+        }
+
+        impl Backend for HardwareE2H {
+            #[inline]
+            fn read(&mut self, reg: ContextRegister) -> u64 {
+                match reg {
+                    $(ContextRegister::$reg => $alias.get() as u64,)*
+                }
+            }
+
+            #[inline]
+            fn write(&mut self, reg: ContextRegister, value: u64) {
+                match reg {
+                    $(ContextRegister::$reg => $alias.set(value as $width),)*
+                }
+            }
+        }
+    };
+}
+
+context! {
+    // Stage 1 translation.
+    ttbr0_el1: TTBR0_EL1 / TTBR0_EL12 as u64,
+    ttbr1_el1: TTBR1_EL1 / TTBR1_EL12 as u64,
+    mair_el1: MAIR_EL1 / MAIR_EL12 as u64,
+    amair_el1: AMAIR_EL1 / AMAIR_EL12 as u64,
+    contextidr_el1: CONTEXTIDR_EL1 / CONTEXTIDR_EL12 as u64,
+    tcr_el1: TCR_EL1 / TCR_EL12 as u64,
+    sctlr_el1: SCTLR_EL1 / SCTLR_EL12 as u64,
+
+    // Exception handling.
+    cpacr_el1: CPACR_EL1 / CPACR_EL12 as u64,
+    vbar_el1: VBAR_EL1 / VBAR_EL12 as u64,
+    elr_el1: ELR_EL1 / ELR_EL12 as u64,
+    spsr_el1: SPSR_EL1 / SPSR_EL12 as u32,
+    esr_el1: ESR_EL1 / ESR_EL12 as u32,
+    far_el1: FAR_EL1 / FAR_EL12 as u64,
+    afsr0_el1: AFSR0_EL1 / AFSR0_EL12 as u64,
+    afsr1_el1: AFSR1_EL1 / AFSR1_EL12 as u64,
+
+    // PAR_EL1 has no _EL12 alias: EL2 has no PAR of its own and shares PAR_EL1 with EL1, so the
+    // EL1 name accesses the guest's value in both configurations.
+    par_el1: PAR_EL1 / PAR_EL1 as u64,
+
+    // Stack pointers and thread IDs, which are not redirected with HCR_EL2.E2H.
+    sp_el0: SP_EL0 / SP_EL0 as u64,
+    sp_el1: SP_EL1 / SP_EL1 as u64,
+    tpidr_el0: TPIDR_EL0 / TPIDR_EL0 as u64,
+    tpidrro_el0: TPIDRRO_EL0 / TPIDRRO_EL0 as u64,
+    tpidr_el1: TPIDR_EL1 / TPIDR_EL1 as u64,
+
+    // Timer.
+    cntkctl_el1: CNTKCTL_EL1 / CNTKCTL_EL12 as u32,
+}
+
+impl El1Context {
+    /// Saves the registers of the guest that last ran on the executing core.
+    pub fn save(&mut self) {
+        if HCR_EL2.is_set(HCR_EL2::E2H) {
+            self.save_from(&mut HardwareE2H);
+        } else {
+            self.save_from(&mut Hardware);
+        }
+    }
+
+    /// Loads the registers of the guest into the executing core.
+    pub fn restore(&self) {
+        if HCR_EL2.is_set(HCR_EL2::E2H) {
+            self.restore_to(&mut HardwareE2H);
+        } else {
+            self.restore_to(&mut Hardware);
+        }
+    }
+
+    /// Saves all registers from `backend`.
+    pub fn save_from<B: Backend>(&mut self, backend: &mut B) {
+        for &reg in ContextRegister::ALL {
+            self.set(reg, backend.read(reg));
+        }
+    }
+
+    /// Restores all registers to `backend`, in restore order.
+    pub fn restore_to<B: Backend>(&self, backend: &mut B) {
+        for &reg in ContextRegister::ALL {
+            backend.write(reg, self.get(reg));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::vec::Vec;
+
+    /// A backend holding the register values, recording every write.
+    struct Recorder {
+        values: El1Context,
+        writes: Vec<(ContextRegister, u64)>,
+    }
+
+    impl Recorder {
+        fn new() -> Recorder {
+            Recorder {
+                values: El1Context::default(),
+                writes: Vec::new(),
+            }
+        }
+    }
+
+    impl Backend for Recorder {
+        fn read(&mut self, reg: ContextRegister) -> u64 {
+            self.values.get(reg)
+        }
+
+        fn write(&mut self, reg: ContextRegister, value: u64) {
+            self.values.set(reg, value);
+            self.writes.push((reg, value));
+        }
+    }
+
+    /// A context with a distinct value in every register.
+    fn context() -> El1Context {
+        let mut context = El1Context::default();
+        for (i, &reg) in ContextRegister::ALL.iter().enumerate() {
+            context.set(reg, 0x1000 + i as u64);
+        }
+
+        context
+    }
+
+    #[test]
+    fn all_registers() {
+        assert_eq!(
+            ContextRegister::ALL.len(),
+            core::mem::size_of::<El1Context>() / 8
+        );
+
+        // Every register is held in a field of its own.
+        let context = context();
+        for (i, &reg) in ContextRegister::ALL.iter().enumerate() {
+            assert_eq!(context.get(reg), 0x1000 + i as u64, "{:?}", reg);
+        }
+    }
+
+    #[test]
+    fn restore_order() {
+        use ContextRegister::*;
+
+        let mut recorder = Recorder::new();
+        context().restore_to(&mut recorder);
+
+        let position = |reg| recorder.writes.iter().position(|&(r, _)| r == reg).unwrap();
+        for &reg in &[TTBR0_EL1, TTBR1_EL1, MAIR_EL1, AMAIR_EL1, CONTEXTIDR_EL1] {
+            assert!(position(reg) < position(TCR_EL1), "{:?}", reg);
+        }
+        assert!(position(TCR_EL1) < position(SCTLR_EL1));
+    }
+
+    #[test]
+    fn save_restore() {
+        let mut recorder = Recorder::new();
+        recorder.values = context();
+
+        let mut saved = El1Context::default();
+        saved.save_from(&mut recorder);
+        assert_eq!(saved, context());
+
+        let mut recorder = Recorder::new();
+        saved.restore_to(&mut recorder);
+        assert_eq!(recorder.values, context());
+        assert_eq!(recorder.writes.len(), ContextRegister::ALL.len());
+    }
+}
@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Guest stage 2 fault addresses
+//!
+//! On a stage 2 abort taken to EL2, `FAR_EL2` holds the guest VA, and `HPFAR_EL2` the page of the
+//! faulting IPA. [`FaultIpa`] combines them to the faulting IPA:
+//!
+//! ```ignore
+//! use cortex_a::{iss::data_abort::DataAbortIss, virt::fault::FaultIpa};
+//!
+//! let iss = DataAbortIss::new(ESR_EL2.read(ESR_EL2::ISS));
+//! match FaultIpa::read(&iss) {
+//!     FaultIpa::Access(ipa) => emulate_mmio(ipa, &iss),
+//!     fault => map_page(fault.page()),
+//! }
+//! ```
+//!
+//! `HPFAR_EL2` is only valid for Translation, Access flag and Permission faults and Address size
+//! faults on the stage 2 translation, so [`FaultIpa`] must not be used for other aborts.
+//! Instruction Aborts have the same FnV and S1PTW fields as Data Aborts, so their ISS can be
+//! decoded as a [`DataAbortIss`] for this purpose.
+
+use crate::{
+    iss::data_abort::{DataAbortIss, ISS},
+    regs::*,
+};
+use register::LocalRegisterCopy;
+
+const PAGE_OFFSET_MASK: u64 = 0xFFF;
+
+/// The faulting IPA of a stage 2 abort.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FaultIpa {
+    /// The IPA accessed by the faulting instruction.
+    Access(u64),
+
+    /// The address of the 4KiB page holding the IPA accessed by the faulting instruction, with
+    /// `FAR_EL2` not valid (`FnV` set).
+    Page(u64),
+
+    /// The address of the 4KiB page holding the stage 1 translation table descriptor whose access
+    /// faulted (`S1PTW` set). `FAR_EL2` holds the VA being translated, which has no relation to
+    /// the descriptor address.
+    TableWalk(u64),
+}
+
+impl FaultIpa {
+    /// Combines the raw `HPFAR_EL2` and `FAR_EL2` values of the abort with the syndrome `iss`.
+    pub fn new(iss: &DataAbortIss, hpfar: u64, far: u64) -> FaultIpa {
+        let hpfar: LocalRegisterCopy<u64, HPFAR_EL2::Register> = LocalRegisterCopy::new(hpfar);
+        let page = hpfar.read(HPFAR_EL2::FIPA) << 12;
+
+        if iss.is_set(ISS::S1PTW) {
+            FaultIpa::TableWalk(page)
+        } else if iss.matches_all(ISS::FnV::NotValid) {
+            FaultIpa::Page(page)
+        } else {
+            FaultIpa::Access(page | (far & PAGE_OFFSET_MASK))
+        }
+    }
+
+    /// Reads `HPFAR_EL2` and `FAR_EL2` for the abort with the syndrome `iss`.
+    ///
+    /// Must be called before anything else can cause an exception to EL2, which would overwrite the
+    /// registers.
+    #[inline]
+    pub fn read(iss: &DataAbortIss) -> FaultIpa {
+        FaultIpa::new(iss, HPFAR_EL2.get(), FAR_EL2.get())
+    }
+
+    /// Returns the address of the 4KiB page holding the faulting IPA.
+    pub fn page(&self) -> u64 {
+        match *self {
+            FaultIpa::Access(ipa) => ipa & !PAGE_OFFSET_MASK,
+            FaultIpa::Page(page) | FaultIpa::TableWalk(page) => page,
+        }
+    }
+
+    /// Returns the full IPA accessed by the faulting instruction, if it is known.
+    pub fn access(&self) -> Option<u64> {
+        match *self {
+            FaultIpa::Access(ipa) => Some(ipa),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FNV: u32 = 1 << 10;
+    const S1PTW: u32 = 1 << 7;
+
+    /// `HPFAR_EL2` for the page at `page`, from the Non-secure IPA space.
+    fn hpfar(page: u64) -> u64 {
+        1 << 63 | page >> 12 << 4
+    }
+
+    #[test]
+    fn access() {
+        let fault = FaultIpa::new(
+            &DataAbortIss::new(0),
+            hpfar(0x8_1234_5000),
+            0xFFFF_0000_0000_0ABC,
+        );
+
+        assert_eq!(fault, FaultIpa::Access(0x8_1234_5ABC));
+        assert_eq!(fault.page(), 0x8_1234_5000);
+        assert_eq!(fault.access(), Some(0x8_1234_5ABC));
+    }
+
+    #[test]
+    fn far_not_valid() {
+        let fault = FaultIpa::new(&DataAbortIss::new(FNV), hpfar(0x4000_0000), 0xABC);
+
+        assert_eq!(fault, FaultIpa::Page(0x4000_0000));
+        assert_eq!(fault.page(), 0x4000_0000);
+        assert_eq!(fault.access(), None);
+    }
+
+    #[test]
+    fn table_walk() {
+        // FAR_EL2 holds the VA being translated, also if it is valid.
+        for &iss in &[S1PTW, S1PTW | FNV] {
+            let fault = FaultIpa::new(&DataAbortIss::new(iss), hpfar(0x4000_0000), 0x1_2ABC);
+
+            assert_eq!(fault, FaultIpa::TableWalk(0x4000_0000));
+            assert_eq!(fault.page(), 0x4000_0000);
+            assert_eq!(fault.access(), None);
+        }
+    }
+
+    #[test]
+    fn widest_ipa() {
+        let fault = FaultIpa::new(&DataAbortIss::new(0), hpfar(0xF_FFFF_FFFF_F000), 0xFFF);
+
+        assert_eq!(fault, FaultIpa::Access(0xF_FFFF_FFFF_FFFF));
+    }
+}
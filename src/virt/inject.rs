@@ -0,0 +1,419 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Guest exception injection
+//!
+//! Delivers exceptions to the EL1 of a trapped guest, as if the PE had taken them from the guest
+//! state held in `ELR_EL2` and `SPSR_EL2`:
+//!
+//! ```ignore
+//! use cortex_a::virt::{context::Hardware, inject::{self, GuestReturn}};
+//!
+//! // In the handler of a guest data abort to an IPA that is not backed by anything:
+//! let mut ret = GuestReturn::read();
+//! inject::data_abort(&mut Hardware, &mut ret, far, iss);
+//! ret.write();
+//! ```
+//!
+//! Synchronous exceptions write the syndrome to the guest's `ESR_EL1`, `FAR_EL1`, `ELR_EL1` and
+//! `SPSR_EL1` through a [`Backend`], and redirect the exception return to the guest's vector in
+//! `VBAR_EL1`. Use [`HardwareE2H`](super::context::HardwareE2H) with `HCR_EL2.E2H` set.
+//!
+//! Virtual interrupts are pended in `HCR_EL2` and taken by the guest as soon as it unmasks them.
+//! Virtual IRQs and FIQs are only delivered while `HCR_EL2.IMO` and `HCR_EL2.FMO` are set, virtual
+//! SErrors while `HCR_EL2.AMO` is set.
+//!
+//! Only guests with EL1 using AArch64 are supported. `PSTATE.TCO` is not set on entry, guests
+//! using ARMv8.5-MemTag must not rely on it.
+
+use super::context::{Backend, ContextRegister};
+use crate::{iss::data_abort::ISS, regs::*};
+use register::{FieldValue, LocalRegisterCopy};
+
+/// The type of an exception, selecting the entry of a vector table quarter.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExceptionType {
+    Synchronous = 0x000,
+    Irq = 0x080,
+    Fiq = 0x100,
+    SError = 0x180,
+}
+
+/// The state an exception is taken from, selecting the quarter of the vector table.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    /// EL1 using SP_EL0.
+    CurrentElSp0 = 0x000,
+
+    /// EL1 using SP_EL1.
+    CurrentElSpx = 0x200,
+
+    /// EL0 using AArch64.
+    LowerElAArch64 = 0x400,
+
+    /// EL0 using AArch32.
+    LowerElAArch32 = 0x600,
+}
+
+impl Source {
+    /// Returns the source of an exception taken from the guest state `spsr`, as saved in
+    /// `SPSR_EL2`.
+    pub fn from_spsr(spsr: u32) -> Source {
+        let spsr: LocalRegisterCopy<u32, SPSR_EL2::Register> = LocalRegisterCopy::new(spsr);
+
+        if spsr.is_set(SPSR_EL2::M4) {
+            return Source::LowerElAArch32;
+        }
+
+        match spsr.read_as_enum(SPSR_EL2::M) {
+            Some(SPSR_EL2::M::Value::EL1t) => Source::CurrentElSp0,
+            Some(SPSR_EL2::M::Value::EL1h) => Source::CurrentElSpx,
+            _ => Source::LowerElAArch64,
+        }
+    }
+
+    /// Returns whether the exception is taken from EL0.
+    pub fn is_lower_el(&self) -> bool {
+        matches!(self, Source::LowerElAArch64 | Source::LowerElAArch32)
+    }
+}
+
+/// The exception return state of a trapped guest.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GuestReturn {
+    /// The guest PC, held in `ELR_EL2`.
+    pub elr: u64,
+
+    /// The guest PSTATE, held in `SPSR_EL2`.
+    pub spsr: u32,
+}
+
+impl GuestReturn {
+    /// Reads the state from `ELR_EL2` and `SPSR_EL2`.
+    #[inline]
+    pub fn read() -> GuestReturn {
+        GuestReturn {
+            elr: ELR_EL2.get(),
+            spsr: SPSR_EL2.get(),
+        }
+    }
+
+    /// Writes the state to `ELR_EL2` and `SPSR_EL2`, for the exception return to the guest.
+    #[inline]
+    pub fn write(&self) {
+        ELR_EL2.set(self.elr);
+        SPSR_EL2.set(self.spsr);
+    }
+}
+
+/// Returns the guest PSTATE on entry to EL1, for an exception taken from `spsr` with the guest's
+/// `SCTLR_EL1` value `sctlr`.
+fn entry_pstate(spsr: u32, sctlr: u64) -> u32 {
+    let sctlr: LocalRegisterCopy<u64, SCTLR_EL1::Register> = LocalRegisterCopy::new(sctlr);
+
+    let preserved = SPSR_EL2::N::SET
+        + SPSR_EL2::Z::SET
+        + SPSR_EL2::C::SET
+        + SPSR_EL2::V::SET
+        + SPSR_EL2::DIT::SET
+        + SPSR_EL2::PAN::SET;
+    let mut pstate = spsr & preserved.mask;
+
+    // SPAN is RES1 without ARMv8.1-PAN, so PAN is only set if it is implemented.
+    if !sctlr.is_set(SCTLR_EL1::SPAN) {
+        pstate |= SPSR_EL2::PAN::SET.value;
+    }
+
+    if sctlr.is_set(SCTLR_EL1::DSSBS) {
+        pstate |= SPSR_EL2::SSBS::SET.value;
+    }
+
+    let entry = SPSR_EL2::D::Masked
+        + SPSR_EL2::A::Masked
+        + SPSR_EL2::I::Masked
+        + SPSR_EL2::F::Masked
+        + SPSR_EL2::M::EL1h;
+
+    pstate | entry.value
+}
+
+/// Makes the guest take an exception of type `kind` to EL1 on the return to it.
+///
+/// The guest PC and PSTATE are saved in the guest's `ELR_EL1` and `SPSR_EL1`, and `ret` is changed
+/// to return to the vector for `kind`.
+pub fn exception<B: Backend>(backend: &mut B, ret: &mut GuestReturn, kind: ExceptionType) {
+    let source = Source::from_spsr(ret.spsr);
+    let vbar = backend.read(ContextRegister::VBAR_EL1);
+    let sctlr = backend.read(ContextRegister::SCTLR_EL1);
+
+    backend.write(ContextRegister::ELR_EL1, ret.elr);
+    backend.write(ContextRegister::SPSR_EL1, ret.spsr as u64);
+
+    ret.elr = vbar + source as u64 + kind as u64;
+    ret.spsr = entry_pstate(ret.spsr, sctlr);
+}
+
+/// Makes the guest take a synchronous exception with the exception class `ec` and the syndrome
+/// `iss` to EL1. `far` is written to the guest's `FAR_EL1`, if given.
+///
+/// `il` is written to `ESR_EL1.IL`. It is set for exceptions from AArch64 state and for exceptions
+/// that are not reported for a specific instruction. For an instruction executed at EL0 using
+/// AArch32, it is clear for a 16-bit T32 instruction; take it from the trapped syndrome.
+pub fn sync<B: Backend>(
+    backend: &mut B,
+    ret: &mut GuestReturn,
+    ec: ESR_EL1::EC::Value,
+    il: bool,
+    iss: u32,
+    far: Option<u64>,
+) {
+    let esr = ESR_EL1::EC.val(ec as u32) + ESR_EL1::IL.val(il as u32) + ESR_EL1::ISS.val(iss);
+
+    backend.write(ContextRegister::ESR_EL1, esr.value as u64);
+    if let Some(far) = far {
+        backend.write(ContextRegister::FAR_EL1, far);
+    }
+
+    exception(backend, ret, ExceptionType::Synchronous);
+}
+
+/// Makes the guest take an Undefined Instruction exception for the instruction at the guest PC.
+pub fn undefined<B: Backend>(backend: &mut B, ret: &mut GuestReturn) {
+    sync(backend, ret, ESR_EL1::EC::Value::Unknown, true, 0, None);
+}
+
+/// Makes the guest take a Data Abort for the virtual address `far`, with the syndrome `iss`.
+///
+/// `ESR_EL1.IL` is set. A syndrome with `ISV` set for a guest at EL0 using AArch32 describes an
+/// instruction that may be 16 bits long, inject it with [`sync`] instead.
+pub fn data_abort<B: Backend>(backend: &mut B, ret: &mut GuestReturn, far: u64, iss: u32) {
+    let source = Source::from_spsr(ret.spsr);
+    debug_assert!(
+        source != Source::LowerElAArch32 || iss & ISS::ISV::SET.value == 0,
+        "instruction length of an AArch32 syndrome"
+    );
+
+    let ec = if source.is_lower_el() {
+        ESR_EL1::EC::Value::DataAbortLowerEL
+    } else {
+        ESR_EL1::EC::Value::DataAbortCurrentEL
+    };
+
+    sync(backend, ret, ec, true, iss, Some(far));
+}
+
+/// Makes the guest take an Instruction Abort for the virtual address `far`, with the syndrome
+/// `iss`.
+pub fn instruction_abort<B: Backend>(backend: &mut B, ret: &mut GuestReturn, far: u64, iss: u32) {
+    let ec = if Source::from_spsr(ret.spsr).is_lower_el() {
+        ESR_EL1::EC::Value::InstrAbortLowerEL
+    } else {
+        ESR_EL1::EC::Value::InstrAbortCurrentEL
+    };
+
+    sync(backend, ret, ec, true, iss, Some(far));
+}
+
+/// Pends or clears a virtual IRQ.
+#[inline]
+pub fn set_virtual_irq(pending: bool) {
+    HCR_EL2.modify(HCR_EL2::VI.val(pending as u64));
+}
+
+/// Pends or clears a virtual FIQ.
+#[inline]
+pub fn set_virtual_fiq(pending: bool) {
+    HCR_EL2.modify(HCR_EL2::VF.val(pending as u64));
+}
+
+/// Pends a virtual SError interrupt, which reports `syndrome` in the guest's `ESR_EL1` if given.
+///
+/// `HCR_EL2.VSE` is cleared by the PE when the guest takes the virtual SError. Setting the syndrome
+/// requires ARMv8.2-RAS, otherwise the syndrome is IMPLEMENTATION DEFINED.
+#[inline]
+pub fn serror(syndrome: Option<FieldValue<u64, VSESR_EL2::Register>>) {
+    if let Some(syndrome) = syndrome {
+        VSESR_EL2.write(syndrome);
+    }
+
+    HCR_EL2.modify(HCR_EL2::VSE::SET);
+}
+
+/// Returns whether a virtual SError interrupt is still pending.
+#[inline]
+pub fn is_serror_pending() -> bool {
+    HCR_EL2.is_set(HCR_EL2::VSE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::virt::context::El1Context;
+
+    /// The guest registers, held in memory.
+    struct Guest(El1Context);
+
+    impl Backend for Guest {
+        fn read(&mut self, reg: ContextRegister) -> u64 {
+            self.0.get(reg)
+        }
+
+        fn write(&mut self, reg: ContextRegister, value: u64) {
+            self.0.set(reg, value);
+        }
+    }
+
+    const VBAR: u64 = 0xFFFF_0000_0008_0000;
+
+    /// `SCTLR_EL1.SPAN`, to leave `PSTATE.PAN` unchanged.
+    const SPAN: u64 = 1 << 23;
+
+    fn guest(sctlr: u64) -> Guest {
+        let mut guest = Guest(El1Context::default());
+        guest.0.vbar_el1 = VBAR;
+        guest.0.sctlr_el1 = sctlr;
+
+        guest
+    }
+
+    /// Returns the guest PSTATE on entry to EL1 for an exception taken from `spsr`.
+    fn entry(sctlr: u64, spsr: u32) -> u32 {
+        let mut ret = GuestReturn { elr: 0x8000, spsr };
+        exception(&mut guest(sctlr), &mut ret, ExceptionType::Irq);
+
+        ret.spsr
+    }
+
+    const EL0T: u32 = 0b0000;
+    const EL1T: u32 = 0b0100;
+    const EL1H: u32 = 0b0101;
+    const USR32: u32 = 0b1_0000;
+
+    #[test]
+    fn vector_offsets() {
+        let sources = [
+            (EL1T, Source::CurrentElSp0, 0x000),
+            (EL1H, Source::CurrentElSpx, 0x200),
+            (EL0T, Source::LowerElAArch64, 0x400),
+            (USR32, Source::LowerElAArch32, 0x600),
+        ];
+        let kinds = [
+            (ExceptionType::Synchronous, 0x000),
+            (ExceptionType::Irq, 0x080),
+            (ExceptionType::Fiq, 0x100),
+            (ExceptionType::SError, 0x180),
+        ];
+
+        for &(spsr, source, source_offset) in &sources {
+            assert_eq!(Source::from_spsr(spsr), source);
+
+            for &(kind, kind_offset) in &kinds {
+                let mut guest = guest(SPAN);
+                let mut ret = GuestReturn { elr: 0x8000, spsr };
+                exception(&mut guest, &mut ret, kind);
+
+                assert_eq!(
+                    ret.elr,
+                    VBAR + source_offset + kind_offset,
+                    "{:?} {:?}",
+                    source,
+                    kind
+                );
+                assert_eq!(ret.spsr & 0x3FF, 0x3C0 | EL1H);
+                assert_eq!(guest.0.elr_el1, 0x8000);
+                assert_eq!(guest.0.spsr_el1, spsr as u64);
+            }
+        }
+    }
+
+    #[test]
+    fn pan() {
+        let pan = SPSR_EL2::PAN::SET.value;
+
+        assert_eq!(entry(0, EL0T) & pan, pan);
+        assert_eq!(entry(SPAN, EL0T) & pan, 0);
+        assert_eq!(entry(SPAN, EL1H | pan) & pan, pan);
+    }
+
+    #[test]
+    fn ssbs() {
+        let dssbs = 1 << 44;
+        let ssbs = SPSR_EL2::SSBS::SET.value;
+
+        assert_eq!(entry(SPAN | dssbs, EL0T) & ssbs, ssbs);
+        assert_eq!(entry(SPAN, EL0T | ssbs) & ssbs, 0);
+    }
+
+    #[test]
+    fn pstate() {
+        let cleared =
+            SPSR_EL2::UAO::SET + SPSR_EL2::SS::SET + SPSR_EL2::IL::SET + SPSR_EL2::BTYPE.val(3);
+        let kept = SPSR_EL2::N::SET + SPSR_EL2::C::SET + SPSR_EL2::DIT::SET;
+
+        assert_eq!(
+            entry(SPAN, EL0T | cleared.value | kept.value),
+            kept.value | 0x3C0 | EL1H
+        );
+    }
+
+    #[test]
+    fn syndrome() {
+        let mut guest = guest(SPAN);
+        let mut ret = GuestReturn {
+            elr: 0x8000,
+            spsr: EL0T,
+        };
+        data_abort(&mut guest, &mut ret, 0x1234, 0x47);
+
+        let esr = ESR_EL1::EC::Value::DataAbortLowerEL as u64;
+        assert_eq!(guest.0.esr_el1, esr << 26 | 1 << 25 | 0x47);
+        assert_eq!(guest.0.far_el1, 0x1234);
+        assert_eq!(ret.elr, VBAR + 0x400);
+
+        let mut ret = GuestReturn {
+            elr: 0x8000,
+            spsr: EL1H,
+        };
+        instruction_abort(&mut guest, &mut ret, 0x5678, 0x0F);
+
+        let esr = ESR_EL1::EC::Value::InstrAbortCurrentEL as u64;
+        assert_eq!(guest.0.esr_el1, esr << 26 | 1 << 25 | 0x0F);
+        assert_eq!(guest.0.far_el1, 0x5678);
+        assert_eq!(ret.elr, VBAR + 0x200);
+
+        // A 16-bit T32 instruction.
+        let mut ret = GuestReturn {
+            elr: 0x8000,
+            spsr: USR32,
+        };
+        let iss = ISS::ISV::SET.value;
+        sync(
+            &mut guest,
+            &mut ret,
+            ESR_EL1::EC::Value::DataAbortLowerEL,
+            false,
+            iss,
+            None,
+        );
+
+        let esr = ESR_EL1::EC::Value::DataAbortLowerEL as u64;
+        assert_eq!(guest.0.esr_el1, esr << 26 | iss as u64);
+        assert_eq!(guest.0.far_el1, 0x5678);
+        assert_eq!(ret.elr, VBAR + 0x600);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "instruction length")]
+    fn aarch32_syndrome() {
+        let mut ret = GuestReturn {
+            elr: 0x8000,
+            spsr: USR32,
+        };
+        data_abort(&mut guest(SPAN), &mut ret, 0x1234, ISS::ISV::SET.value);
+    }
+}
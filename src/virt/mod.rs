@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Virtualization support
+//!
+//! Helpers for hypervisors running at EL2 that switch guest state on and off the physical cores.
+
+pub mod context;
+pub mod fault;
+pub mod inject;
+pub mod timer;
@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2020 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Guest virtual timer
+//!
+//! [`VcpuTimer`] holds the virtual timer of a vCPU while it is not running and keeps the guest's
+//! view of `CNTVCT_EL0` continuous across deschedules and migration between cores:
+//!
+//! ```ignore
+//! use cortex_a::virt::timer::{CounterPolicy, VcpuTimer};
+//!
+//! let mut timer = VcpuTimer::new(CounterPolicy::Paused);
+//!
+//! // Switching the vCPU in:
+//! timer.restore();
+//!
+//! // Switching the vCPU out:
+//! timer.save();
+//! if timer.is_pending() {
+//!     // Inject the virtual timer interrupt before the vCPU runs again.
+//! }
+//! ```
+//!
+//! The system counter is shared by all cores, so the saved offset stays valid when the vCPU is
+//! restored on a different core. All functions must be called at EL2.
+//!
+//! With `HCR_EL2.E2H` set, the `CNTV_*_EL0` names access the host's EL2 virtual timer, so the
+//! guest's timer is accessed through the `CNTV_*_EL02` aliases instead.
+
+use crate::regs::{
+    RegisterReadOnly, RegisterReadWrite, CNTPCT_EL0, CNTVOFF_EL2, CNTV_CTL_EL0, CNTV_CTL_EL02,
+    CNTV_CVAL_EL0, CNTV_CVAL_EL02, HCR_EL2,
+};
+use register::LocalRegisterCopy;
+
+/// Accesses to the guest's `CNTV_CTL_EL0` and `CNTV_CVAL_EL0`, through the `_EL02` aliases with
+/// `HCR_EL2.E2H` set.
+mod guest {
+    use super::*;
+
+    #[inline]
+    fn is_e2h() -> bool {
+        HCR_EL2.is_set(HCR_EL2::E2H)
+    }
+
+    #[inline]
+    pub fn ctl() -> u32 {
+        if is_e2h() {
+            CNTV_CTL_EL02.get()
+        } else {
+            CNTV_CTL_EL0.get()
+        }
+    }
+
+    #[inline]
+    pub fn set_ctl(value: u32) {
+        if is_e2h() {
+            CNTV_CTL_EL02.set(value)
+        } else {
+            CNTV_CTL_EL0.set(value)
+        }
+    }
+
+    #[inline]
+    pub fn cval() -> u64 {
+        if is_e2h() {
+            CNTV_CVAL_EL02.get()
+        } else {
+            CNTV_CVAL_EL0.get()
+        }
+    }
+
+    #[inline]
+    pub fn set_cval(value: u64) {
+        if is_e2h() {
+            CNTV_CVAL_EL02.set(value)
+        } else {
+            CNTV_CVAL_EL0.set(value)
+        }
+    }
+}
+
+/// Defines how the guest's virtual counter behaves while the vCPU is descheduled.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CounterPolicy {
+    /// The virtual counter keeps counting, `CNTVOFF_EL2` is never changed. Guest time follows
+    /// physical time, but the guest observes a jump after having been descheduled.
+    Running,
+
+    /// The virtual counter stops while the vCPU is descheduled. `CNTVOFF_EL2` is increased by the
+    /// time spent descheduled, so the guest does not observe a jump.
+    Paused,
+}
+
+/// Saved virtual timer state of a vCPU.
+#[derive(Copy, Clone, Debug)]
+pub struct VcpuTimer {
+    policy: CounterPolicy,
+    ctl: u32,
+    cval: u64,
+    offset: u64,
+
+    /// Physical count at the time of the last save, `None` while the vCPU is loaded.
+    saved_at: Option<u64>,
+}
+
+impl VcpuTimer {
+    /// Creates the timer state of a new vCPU, whose virtual counter starts at zero.
+    pub fn new(policy: CounterPolicy) -> VcpuTimer {
+        VcpuTimer::with_offset(policy, CNTPCT_EL0.get())
+    }
+
+    /// Creates the timer state of a vCPU with the given virtual offset, e.g. one migrated from
+    /// another system.
+    pub fn with_offset(policy: CounterPolicy, offset: u64) -> VcpuTimer {
+        VcpuTimer {
+            policy,
+            ctl: 0,
+            cval: 0,
+            offset,
+            saved_at: Some(CNTPCT_EL0.get()),
+        }
+    }
+
+    pub fn policy(&self) -> CounterPolicy {
+        self.policy
+    }
+
+    /// Returns the virtual offset that is, or will be, programmed into `CNTVOFF_EL2`.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns the saved `CNTV_CTL_EL0`, which is stale while the state is loaded.
+    pub fn ctl(&self) -> u32 {
+        self.ctl
+    }
+
+    /// Returns the saved `CNTV_CVAL_EL0`, which is stale while the state is loaded.
+    pub fn cval(&self) -> u64 {
+        self.cval
+    }
+
+    /// Returns whether the state is currently loaded into the hardware.
+    pub fn is_loaded(&self) -> bool {
+        self.saved_at.is_none()
+    }
+
+    /// Returns the value the guest currently reads from `CNTVCT_EL0`.
+    pub fn virtual_count(&self) -> u64 {
+        match (self.policy, self.saved_at) {
+            (CounterPolicy::Paused, Some(saved_at)) => saved_at.wrapping_sub(self.offset),
+            _ => CNTPCT_EL0.get().wrapping_sub(self.offset),
+        }
+    }
+
+    /// Saves the guest's virtual timer and disables it, so that it cannot fire while the host or
+    /// another vCPU is running.
+    pub fn save(&mut self) {
+        if self.is_loaded() {
+            self.ctl = guest::ctl();
+            self.cval = guest::cval();
+            guest::set_ctl(0);
+
+            self.saved_at = Some(CNTPCT_EL0.get());
+        }
+    }
+
+    /// Loads the guest's virtual timer and virtual offset into the executing core.
+    ///
+    /// With [`CounterPolicy::Paused`], the offset is first advanced by the time the vCPU spent
+    /// descheduled.
+    pub fn restore(&mut self) {
+        if let Some(saved_at) = self.saved_at.take() {
+            if self.policy == CounterPolicy::Paused {
+                let descheduled = CNTPCT_EL0.get().wrapping_sub(saved_at);
+                self.offset = self.offset.wrapping_add(descheduled);
+            }
+
+            CNTVOFF_EL2.set(self.offset);
+            guest::set_cval(self.cval);
+            guest::set_ctl(self.ctl);
+        }
+    }
+
+    /// Returns the guest's `CNTV_CTL_EL0` and `CNTV_CVAL_EL0`, read from the hardware while the
+    /// state is loaded.
+    fn current(&self) -> (LocalRegisterCopy<u32, CNTV_CTL_EL0::Register>, u64) {
+        if self.is_loaded() {
+            (LocalRegisterCopy::new(guest::ctl()), guest::cval())
+        } else {
+            (LocalRegisterCopy::new(self.ctl), self.cval)
+        }
+    }
+
+    /// Returns whether the timer is enabled and unmasked, and its deadline has passed, i.e.
+    /// whether the guest's timer interrupt is, or would be if the vCPU was running, asserted.
+    ///
+    /// The caller is expected to inject the virtual timer interrupt in that case.
+    pub fn is_pending(&self) -> bool {
+        let (ctl, cval) = self.current();
+
+        if !ctl.is_set(CNTV_CTL_EL0::ENABLE) || ctl.is_set(CNTV_CTL_EL0::IMASK) {
+            return false;
+        }
+
+        if self.is_loaded() {
+            ctl.is_set(CNTV_CTL_EL0::ISTATUS)
+        } else {
+            self.virtual_count() >= cval
+        }
+    }
+
+    /// Returns the physical count at which the timer fires, for arming a host timer that wakes up
+    /// the vCPU, or `None` if the timer is disabled or masked.
+    ///
+    /// With [`CounterPolicy::Paused`], the deadline is only valid if the vCPU is restored now.
+    pub fn physical_deadline(&self) -> Option<u64> {
+        let (ctl, cval) = self.current();
+
+        if !ctl.is_set(CNTV_CTL_EL0::ENABLE) || ctl.is_set(CNTV_CTL_EL0::IMASK) {
+            return None;
+        }
+
+        let remaining = cval.saturating_sub(self.virtual_count());
+
+        Some(CNTPCT_EL0.get().saturating_add(remaining))
+    }
+}